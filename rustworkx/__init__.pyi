@@ -280,6 +280,8 @@ from .rustworkx import BFSSuccessors as BFSSuccessors
 from .rustworkx import BFSPredecessors as BFSPredecessors
 from .rustworkx import EdgeIndexMap as EdgeIndexMap
 from .rustworkx import EdgeIndices as EdgeIndices
+from .rustworkx import LazyNodeIndices as LazyNodeIndices
+from .rustworkx import LazyEdgeIndices as LazyEdgeIndices
 from .rustworkx import Chains as Chains
 from .rustworkx import IndexPartitionBlock as IndexPartitionBlock
 from .rustworkx import RelationalCoarsestPartition as RelationalCoarsestPartition
@@ -16,6 +16,8 @@ from typing import (
     final,
     Any,
     Generic,
+    IO,
+    Literal,
     overload,
 )
 from collections.abc import (
@@ -47,6 +49,7 @@ else:
 
 _S = TypeVar("_S", default=Any)
 _T = TypeVar("_T", default=Any)
+_U = TypeVar("_U")
 
 class DAGHasCycle(Exception): ...
 class DAGWouldCycle(Exception): ...
@@ -1208,6 +1211,16 @@ class EdgeIndexMap(Generic[_T_co], _RustworkxCustomHashMapIter[int, tuple[int, i
 @final
 class EdgeIndices(_RustworkxCustomVecIter[int]): ...
 
+@final
+class LazyNodeIndices(Iterator[int]):
+    def __iter__(self) -> LazyNodeIndices: ...
+    def __next__(self) -> int: ...
+
+@final
+class LazyEdgeIndices(Iterator[int]):
+    def __iter__(self) -> LazyEdgeIndices: ...
+    def __next__(self) -> int: ...
+
 @final
 class Chains(_RustworkxCustomVecIter[EdgeIndices]): ...
 
@@ -1255,6 +1268,8 @@ class AllPairsMultiplePathMapping(_RustworkxCustomHashMapIter[int, MultiplePathM
 class PyGraph(Generic[_S, _T]):
     attrs: Any
     multigraph: bool = ...
+    @property
+    def connectivity_tracking(self) -> bool: ...
     def __init__(
         self,
         /,
@@ -1264,20 +1279,47 @@ class PyGraph(Generic[_S, _T]):
         node_count_hint: int | None = ...,
         edge_count_hint: int | None = ...,
     ) -> None: ...
-    def add_edge(self, node_a: int, node_b: int, edge: _T, /) -> int: ...
+    def adamic_adar_index(self, node_a: int, node_b: int, /) -> float: ...
+    @overload
+    def add_edge(
+        self, node_a: int, node_b: int, edge: _T, /, return_status: Literal[False] = ...
+    ) -> int: ...
+    @overload
+    def add_edge(
+        self, node_a: int, node_b: int, edge: _T, /, return_status: Literal[True]
+    ) -> tuple[int, bool]: ...
     def add_edges_from(
         self,
         obj_list: Iterable[tuple[int, int, _T]],
         /,
+        check_all_first: bool = ...,
     ) -> list[int]: ...
     def add_edges_from_no_data(
         self: PyGraph[_S, _T | None], obj_list: Iterable[tuple[int, int]], /
     ) -> list[int]: ...
     def add_node(self, obj: _S, /) -> int: ...
-    def add_nodes_from(self, obj_list: Iterable[_S], /) -> NodeIndices: ...
+    def add_nodes_from(
+        self, obj_list: Iterable[_S], /, count_hint: int | None = ...
+    ) -> NodeIndices: ...
     def adj(self, node: int, /) -> dict[int, _T]: ...
+    def are_connected(self, node_a: int, node_b: int, /) -> bool: ...
+    def average_clustering(self) -> float: ...
+    def bipartite_coloring(self) -> dict[int, int] | None: ...
+    def canonicalize_edges(self) -> None: ...
     def clear(self) -> None: ...
+    def clear_edge_data(self, /, value: _T | None = ...) -> None: ...
     def clear_edges(self) -> None: ...
+    def clear_edges_of_nodes(
+        self, nodes: Sequence[int], /, both_endpoints: bool = ...
+    ) -> None: ...
+    def clear_node_data(self, /, value: _S | None = ...) -> None: ...
+    @overload
+    def clustering_coefficient(self, node: int) -> float: ...
+    @overload
+    def clustering_coefficient(self, node: None = ...) -> dict[int, float]: ...
+    def common_neighbors(self, node_a: int, node_b: int, /) -> NodeIndices: ...
+    def compact(self) -> NodeMap: ...
+    def complement(self) -> PyGraph[_S, None]: ...
     def compose(
         self,
         other: PyGraph[_S, _T],
@@ -1286,81 +1328,270 @@ class PyGraph(Generic[_S, _T]):
         node_map_func: Callable[[_S], int] | None = ...,
         edge_map_func: Callable[[_T], int] | None = ...,
     ) -> dict[int, int]: ...
+    def compose_with_edge_map(
+        self,
+        other: PyGraph[_S, _T],
+        node_map: dict[int, tuple[int, _T]],
+        /,
+        node_map_func: Callable[[_S], int] | None = ...,
+        edge_map_func: Callable[[_T], int] | None = ...,
+    ) -> tuple[dict[int, int], dict[int, int], dict[int, int]]: ...
+    def contract_edge(
+        self,
+        edge_index: int,
+        obj: _S,
+        /,
+        weight_combo_fn: Callable[[_T, _T], _T] | None = ...,
+        keep_self_loops: bool = ...,
+    ) -> int: ...
     def contract_nodes(
         self,
-        nodes: Sequence[int],
+        nodes: Sequence[int] | npt.NDArray[np.int64] | npt.NDArray[np.uint64],
         obj: _S,
         /,
         weight_combo_fn: Callable[[_T, _T], _T] | None = ...,
+        keep_internal_edges_as_self_loops: bool = ...,
     ) -> int: ...
-    def copy(self) -> Self: ...
+    def contract_nodes_with_map(
+        self,
+        nodes: Sequence[int],
+        obj: _S,
+        /,
+        weight_combo_fn: Callable[[_T, _T], _T] | None = ...,
+    ) -> tuple[int, dict[int, int | list[int]]]: ...
+    def copy(self, /, deep: bool = ..., compact: bool = ...) -> Self: ...
+    def copy_with_nodemap(self, /, deep: bool = ...) -> tuple[Self, NodeMap]: ...
+    def core_number(self) -> dict[int, int]: ...
     def degree(self, node: int, /) -> int: ...
+    def degree_assortativity(self) -> float: ...
+    def degree_histogram(self) -> dict[int, int]: ...
+    def degree_sequence(self, /, descending: bool = ...) -> list[int]: ...
+    def density(self) -> float: ...
+    def edge_boundary(self, nodes: Sequence[int], /) -> EdgeIndices: ...
+    def edge_endpoint_map(self) -> dict[tuple[int, int], _T]: ...
+    def edge_endpoint_multimap(self) -> dict[tuple[int, int], list[_T]]: ...
+    def edge_endpoints_array(self) -> npt.NDArray[np.uint32]: ...
     def edge_index_map(self) -> EdgeIndexMap[_T]: ...
     def edge_indices(self) -> EdgeIndices: ...
     def edge_indices_from_endpoints(self, node_a: int, node_b: int) -> EdgeIndices: ...
+    def edge_items(self) -> list[tuple[int, int, int, _T]]: ...
     def edge_list(self) -> EdgeList: ...
-    def edges(self) -> list[_T]: ...
-    def edge_subgraph(self, edge_list: Sequence[tuple[int, int]], /) -> PyGraph[_S, _T]: ...
+    @overload
+    def edges(self, data: Literal[False] = ...) -> list[_T]: ...
+    @overload
+    def edges(self, data: Literal[True]) -> list[tuple[int, int, _T]]: ...
+    @overload
+    def edge_subgraph(
+        self, edge_list: Sequence[tuple[int, int]], /, by_index: Literal[False] = ...
+    ) -> PyGraph[_S, _T]: ...
+    @overload
+    def edge_subgraph(
+        self, edge_list: Sequence[int], /, by_index: Literal[True]
+    ) -> PyGraph[_S, _T]: ...
+    def edge_subgraph_by_filter(
+        self, filter_function: Callable[[_T], bool], /, preserve_attrs: bool = ...
+    ) -> PyGraph[_S, _T]: ...
+    def edge_subgraph_with_nodemap(
+        self, edge_list: Sequence[tuple[int, int]], /, preserve_attrs: bool = ...
+    ) -> tuple[PyGraph[_S, _T], NodeMap]: ...
+    def edge_weights_array(
+        self, weight_fn: Callable[[_T], float] | None = ..., default: float = ...
+    ) -> npt.NDArray[np.float64]: ...
+    def ego_graph(
+        self, node: int, /, radius: int = ..., center: bool = ...
+    ) -> tuple[PyGraph[_S, _T], NodeMap]: ...
+    def enable_connectivity_tracking(self) -> None: ...
     def extend_from_edge_list(
-        self: PyGraph[_S | None, _T | None], edge_list: Iterable[tuple[int, int]], /
+        self: PyGraph[_S | None, _T | None],
+        edge_list: Iterable[tuple[int, int]],
+        /,
+        node_count_hint: int | None = ...,
     ) -> None: ...
     def extend_from_weighted_edge_list(
         self: PyGraph[_S | None, _T],
         edge_list: Iterable[tuple[int, int, _T]],
         /,
+        node_count_hint: int | None = ...,
     ) -> None: ...
     def filter_edges(self, filter_function: Callable[[_T], bool]) -> EdgeIndices: ...
     def filter_nodes(self, filter_function: Callable[[_S], bool]) -> NodeIndices: ...
+    def find_edge_index(self, node_a: int, node_b: int, /) -> int | None: ...
     def find_node_by_weight(
         self,
         obj: _S,
         /,
     ) -> int | None: ...
+    def find_nodes_by_weight(self, obj: _S, /) -> NodeIndices: ...
     @staticmethod
     def from_adjacency_matrix(
-        matrix: npt.NDArray[np.float64], /, null_value: float = ...
+        matrix: npt.NDArray[np.float64],
+        /,
+        null_value: float = ...,
+        parallel_threshold: int = ...,
     ) -> PyGraph[int, float]: ...
     @staticmethod
     def from_complex_adjacency_matrix(
-        matrix: npt.NDArray[np.complex64], /, null_value: complex = ...
+        matrix: npt.NDArray[np.complex64],
+        /,
+        null_value: complex = ...,
+        parallel_threshold: int = ...,
     ) -> PyGraph[int, complex]: ...
+    @staticmethod
+    def from_int_adjacency_matrix(
+        matrix: npt.NDArray[np.int64],
+        /,
+        null_value: int = ...,
+        parallel_threshold: int = ...,
+    ) -> PyGraph[int, int]: ...
+    @staticmethod
+    def from_edge_list(
+        edge_list: Iterable[tuple[int, int] | tuple[int, int, Any]],
+        /,
+        weights: Iterable[Any] | None = ...,
+        num_nodes: int | None = ...,
+        multigraph: bool = ...,
+    ) -> PyGraph: ...
+    @staticmethod
+    def from_scipy_sparse_matrix(matrix: Any, /, null_value: float = ...) -> PyGraph[int, float]: ...
     def get_all_edge_data(self, node_a: int, node_b: int, /) -> list[_T]: ...
+    def get_all_edge_data_with_index(
+        self, node_a: int, node_b: int, /
+    ) -> list[tuple[int, _T]]: ...
+    @overload
     def get_edge_data(self, node_a: int, node_b: int, /) -> _T: ...
+    @overload
+    def get_edge_data(self, node_a: int, node_b: int, /, *, default: _U) -> _T | _U: ...
     def get_edge_data_by_index(self, edge_index: int, /) -> _T: ...
     def get_edge_endpoints_by_index(self, edge_index: int, /) -> tuple[int, int]: ...
     def get_node_data(self, node: int, /) -> _S: ...
+    def get_nodes_data(
+        self, indices: Iterable[int], /, ignore_missing: bool = ...
+    ) -> list[_S]: ...
     def has_node(self, node: int, /) -> bool: ...
     def has_edge(self, node_a: int, node_b: int, /) -> bool: ...
+    def has_edge_by_index(self, edge_index: int, /) -> bool: ...
     def has_parallel_edges(self) -> bool: ...
+    def has_parallel_edges_between(self, node_a: int, node_b: int, /) -> bool: ...
     def in_edges(self, node: int, /) -> WeightedEdgeList[_T]: ...
+    def in_edges_indexed(self, node: int, /) -> list[tuple[int, int, int, _T]]: ...
+    def incidence_matrix(self) -> npt.NDArray[np.float64]: ...
     def incident_edge_index_map(self, node: int, /) -> EdgeIndexMap: ...
-    def incident_edges(self, node: int, /) -> EdgeIndices: ...
+    def incident_edges(self, node: int, /, include_self_loops: bool = ...) -> EdgeIndices: ...
     def in_edge_indices(self, node: int, /) -> EdgeIndices: ...
+    def is_bipartite(self) -> bool: ...
+    def is_connected(self) -> bool: ...
+    def is_forest(self) -> bool: ...
+    def is_tree(self) -> bool: ...
+    def iter_edge_indices(self) -> LazyEdgeIndices: ...
+    def iter_node_indices(self) -> LazyNodeIndices: ...
+    def jaccard_coefficient(self, node_a: int, node_b: int, /) -> float: ...
+    def jaccard_coefficient_pairs(self, pairs: Sequence[tuple[int, int]], /) -> list[float]: ...
+    def k_core(self, k: int, /) -> tuple[PyGraph[_S, _T], NodeMap]: ...
+    def line_graph(self) -> tuple[PyGraph[_T, None], dict[int, int]]: ...
+    def merge_parallel_edges(
+        self, /, weight_combo_fn: Callable[[_T, _T], _T] | None = ...
+    ) -> None: ...
     def neighbors(self, node: int, /) -> NodeIndices: ...
+    def node_boundary(self, nodes: Sequence[int], /) -> NodeIndices: ...
+    def node_connected_component(self, node: int, /) -> NodeIndices: ...
     def node_indexes(self) -> NodeIndices: ...
     def node_indices(self) -> NodeIndices: ...
-    def nodes(self) -> list[_S]: ...
+    def node_items(self) -> list[tuple[int, _S]]: ...
+    @overload
+    def nodes(self, data: Literal[False] = ...) -> list[_S]: ...
+    @overload
+    def nodes(self, data: Literal[True]) -> list[tuple[int, _S]]: ...
+    def number_connected_components(self) -> int: ...
     def num_edges(self) -> int: ...
     def num_nodes(self) -> int: ...
+    def num_self_loops(self) -> int: ...
     def out_edges(self, node: int, /) -> WeightedEdgeList[_T]: ...
+    def out_edges_indexed(self, node: int, /) -> list[tuple[int, int, int, _T]]: ...
     def out_edge_indices(self, node: int, /) -> EdgeIndices: ...
+    def parallel_edge_counts(self) -> dict[tuple[int, int], int]: ...
+    def random_edge(self, /, seed: int | None = ...) -> int: ...
+    def random_node(self, /, seed: int | None = ...) -> int: ...
+    @staticmethod
+    def read_dimacs(path: str, /) -> PyGraph: ...
     @staticmethod
     def read_edge_list(
-        path: str,
+        path: str | IO[str] | IO[bytes],
+        /,
+        comment: str | None = ...,
+        deliminator: str | None = ...,
+        labels: bool = ...,
+        multigraph: bool = ...,
+        weight_fn: Callable[[str], Any] | None = ...,
+    ) -> PyGraph: ...
+    @staticmethod
+    def read_edge_list_from_string(
+        data: str,
         /,
         comment: str | None = ...,
         deliminator: str | None = ...,
         labels: bool = ...,
+        multigraph: bool = ...,
+        weight_fn: Callable[[str], Any] | None = ...,
     ) -> PyGraph: ...
+    @staticmethod
+    def read_graphml(path: str, /, compression: str | None = ...) -> PyGraph: ...
+    @staticmethod
+    def read_mtx(path: str, /) -> PyGraph: ...
+    @staticmethod
+    def read_node_link_json(path_or_str: str, /) -> PyGraph: ...
+    @staticmethod
+    def read_pajek(path: str, /) -> PyGraph: ...
     def remove_edge(self, node_a: int, node_b: int, /) -> None: ...
     def remove_edge_from_index(self, edge: int, /) -> None: ...
     def remove_edges_from(self, index_list: Iterable[tuple[int, int]], /) -> None: ...
     def remove_node(self, node: int, /) -> None: ...
+    def remove_node_retain_edges(
+        self,
+        node: int,
+        /,
+        weight_combo_fn: Callable[[_T, _T], _T] | None = ...,
+    ) -> None: ...
     def remove_nodes_from(self, index_list: Iterable[int], /) -> None: ...
-    def subgraph(self, nodes: Sequence[int], /, preserve_attrs: bool = ...) -> PyGraph[_S, _T]: ...
+    def remove_nodes_from_mask(self, mask: npt.NDArray[np.bool_], /) -> None: ...
+    def reverse_edge(self, edge_index: int, /) -> None: ...
+    def set_node_data_from(
+        self,
+        updates: Iterable[tuple[int, _S]],
+        /,
+        ignore_missing: bool = ...,
+    ) -> None: ...
+    def spanning_forest_edges(self) -> EdgeIndices: ...
+    def subdivide_edge(
+        self,
+        edge_index: int,
+        node_data: _S,
+        /,
+        edge_data_uw: _T = ...,
+        edge_data_wv: _T = ...,
+    ) -> int: ...
+    def subgraph(
+        self,
+        nodes: Sequence[int],
+        /,
+        preserve_attrs: bool = ...,
+        preserve_indices: bool = ...,
+        validate: bool = ...,
+    ) -> PyGraph[_S, _T]: ...
+    def subgraph_from_mask(
+        self, mask: npt.NDArray[np.bool_], /, preserve_attrs: bool = ...
+    ) -> tuple[PyGraph[_S, _T], NodeMap]: ...
+    @overload
     def subgraph_with_nodemap(
-        self, nodes: Sequence[int], /, preserve_attrs: bool = ...
+        self,
+        nodes: Sequence[int],
+        /,
+        preserve_attrs: bool = ...,
+        edge_map: Literal[False] = ...,
     ) -> tuple[PyGraph[_S, _T], NodeMap]: ...
+    @overload
+    def subgraph_with_nodemap(
+        self, nodes: Sequence[int], /, preserve_attrs: bool = ..., *, edge_map: Literal[True]
+    ) -> tuple[PyGraph[_S, _T], NodeMap, dict[int, int]]: ...
     def substitute_node_with_subgraph(
         self,
         node: int,
@@ -1369,6 +1600,7 @@ class PyGraph(Generic[_S, _T]):
         /,
         node_filter: Callable[[_S], bool] | None = ...,
         edge_weight_map: Callable[[_T], _T] | None = ...,
+        strict: bool = ...,
     ) -> NodeMap: ...
     @overload
     def to_dot(
@@ -1378,6 +1610,10 @@ class PyGraph(Generic[_S, _T]):
         edge_attr: Callable[[_T], dict[str, str]] | None = ...,
         graph_attr: dict[str, str] | None = ...,
         filename: None = ...,
+        use_attrs_as_graph_attr: bool = ...,
+        show_stats: bool = ...,
+        rank_groups: Sequence[Sequence[int]] | None = ...,
+        file: None = ...,
     ) -> str: ...
     @overload
     def to_dot(
@@ -1387,8 +1623,46 @@ class PyGraph(Generic[_S, _T]):
         edge_attr: Callable[[_T], dict[str, str]] | None = ...,
         graph_attr: dict[str, str] | None = ...,
         filename: str = ...,
+        use_attrs_as_graph_attr: bool = ...,
+        show_stats: bool = ...,
+        rank_groups: Sequence[Sequence[int]] | None = ...,
+        file: None = ...,
     ) -> None: ...
-    def to_directed(self) -> PyDiGraph[_S, _T]: ...
+    @overload
+    def to_dot(
+        self,
+        /,
+        node_attr: Callable[[_S], dict[str, str]] | None = ...,
+        edge_attr: Callable[[_T], dict[str, str]] | None = ...,
+        graph_attr: dict[str, str] | None = ...,
+        filename: None = ...,
+        use_attrs_as_graph_attr: bool = ...,
+        show_stats: bool = ...,
+        rank_groups: Sequence[Sequence[int]] | None = ...,
+        *,
+        file: IO[str],
+    ) -> None: ...
+    def to_csr(
+        self, /, weight_fn: Callable[[_T], float] | None = ..., default_weight: float = ...
+    ) -> tuple[
+        npt.NDArray[np.int64], npt.NDArray[np.int64], npt.NDArray[np.float64], NodeMap
+    ]: ...
+    @overload
+    def to_directed(
+        self, /, bidirectional: bool = ..., edge_map: Literal[False] = ...
+    ) -> PyDiGraph[_S, _T]: ...
+    @overload
+    def to_directed(
+        self, /, bidirectional: bool = ..., *, edge_map: Literal[True]
+    ) -> tuple[PyDiGraph[_S, _T], dict[int, int]]: ...
+    def to_simple_graph(
+        self,
+        /,
+        weight_combo_fn: Callable[[_T, _T], _T] | None = ...,
+        keep_self_loops: bool = ...,
+    ) -> PyGraph[_S, _T]: ...
+    def triangle_count(self) -> int: ...
+    def triangles_per_node(self) -> dict[int, int]: ...
     def update_edge(
         self,
         source: int,
@@ -1397,21 +1671,72 @@ class PyGraph(Generic[_S, _T]):
         /,
     ) -> None: ...
     def update_edge_by_index(self, edge_index: int, edge: _T, /) -> None: ...
-    def weighted_edge_list(self) -> WeightedEdgeList[_T]: ...
+    def update_edges_from(
+        self,
+        updates: Iterable[tuple[int, _T]],
+        /,
+        ignore_missing: bool = ...,
+    ) -> None: ...
+    @overload
+    def weighted_edge_list(
+        self, /, nodes: Iterable[int] | None = ..., weight_fn: None = ...
+    ) -> WeightedEdgeList[_T]: ...
+    @overload
+    def weighted_edge_list(
+        self, /, nodes: Iterable[int] | None = ..., *, weight_fn: Callable[[_T], _U]
+    ) -> WeightedEdgeList[_U]: ...
+    @overload
     def write_edge_list(
         self,
-        path: str,
+        path: None = ...,
         /,
         deliminator: str | None = ...,
         weight_fn: Callable[[_T], str] | None = ...,
+    ) -> str: ...
+    @overload
+    def write_edge_list(
+        self,
+        path: str | IO[str],
+        /,
+        deliminator: str | None = ...,
+        weight_fn: Callable[[_T], str] | None = ...,
+    ) -> None: ...
+    def write_gexf(
+        self,
+        path: str,
+        /,
+        node_attr_fn: Callable[[_S], dict[str, str]] | None = ...,
+        edge_attr_fn: Callable[[_T], dict[str, str]] | None = ...,
     ) -> None: ...
+    def write_graphml(
+        self,
+        path: str,
+        /,
+        node_attr_fn: Callable[[_S], dict[str, str]] | None = ...,
+        edge_attr_fn: Callable[[_T], dict[str, str]] | None = ...,
+        compression: str | None = ...,
+    ) -> None: ...
+    def write_mtx(self, path: str, /, weight_fn: Callable[[_T], float] | None = ...) -> None: ...
+    def write_node_link_json(
+        self,
+        /,
+        path: str | None = ...,
+        node_attr_fn: Callable[[_S], Any] | None = ...,
+        edge_attr_fn: Callable[[_T], Any] | None = ...,
+    ) -> str | None: ...
+    def write_pajek(self, path: str, /, label_fn: Callable[[_S], str] | None = ...) -> None: ...
+    def __contains__(self, idx: object, /) -> bool: ...
     def __delitem__(self, idx: int, /) -> None: ...
+    def __eq__(self, other: object) -> bool: ...
     def __getitem__(self, idx: int, /) -> _S: ...
     @classmethod
     def __class_getitem__(cls, key: Any, /) -> GenericAlias: ...
     def __getnewargs_ex__(self) -> tuple[tuple[Any, ...], dict[str, Any]]: ...
     def __getstate__(self) -> Any: ...
+    def __iter__(self) -> Iterator[int]: ...
     def __len__(self) -> int: ...
+    def __ne__(self, other: object) -> bool: ...
+    def __repr__(self) -> str: ...
     def __setitem__(self, idx: int, value: _S, /) -> None: ...
     def __setstate__(self, state: Any, /) -> None: ...
 
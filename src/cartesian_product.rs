@@ -114,6 +114,7 @@ pub fn graph_cartesian_product(
             multigraph: true,
             node_removed: false,
             attrs: py.None(),
+            connectivity: None,
         },
         out_node_map,
     ))
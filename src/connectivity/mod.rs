@@ -337,6 +337,7 @@ pub fn graph_condensation(py: Python, graph: graph::PyGraph) -> PyResult<graph::
         node_removed: false,
         multigraph: graph.multigraph,
         attrs: attrs.into_pyobject(py)?.into(),
+        connectivity: None,
     };
     Ok(result)
 }
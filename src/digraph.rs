@@ -2432,12 +2432,28 @@ impl PyDiGraph {
         match filename {
             Some(filename) => {
                 let mut file = File::create(filename)?;
-                build_dot(py, &self.graph, &mut file, graph_attr, node_attr, edge_attr)?;
+                build_dot(
+                    py,
+                    &self.graph,
+                    &mut file,
+                    graph_attr,
+                    node_attr,
+                    edge_attr,
+                    None,
+                )?;
                 Ok(None)
             }
             None => {
                 let mut file = Vec::<u8>::new();
-                build_dot(py, &self.graph, &mut file, graph_attr, node_attr, edge_attr)?;
+                build_dot(
+                    py,
+                    &self.graph,
+                    &mut file,
+                    graph_attr,
+                    node_attr,
+                    edge_attr,
+                    None,
+                )?;
                 Ok(Some(PyString::new(py, str::from_utf8(&file)?)))
             }
         }
@@ -3332,6 +3348,7 @@ impl PyDiGraph {
             node_removed: false,
             multigraph,
             attrs: py.None(),
+            connectivity: None,
         })
     }
 
@@ -29,6 +29,7 @@ pub fn build_dot<G, T>(
     graph_attrs: Option<BTreeMap<String, String>>,
     node_attrs: Option<PyObject>,
     edge_attrs: Option<PyObject>,
+    rank_groups: Option<Vec<Vec<usize>>>,
 ) -> PyResult<()>
 where
     T: Write,
@@ -47,17 +48,27 @@ where
             file,
             "{} {};",
             graph.to_index(node.id()),
-            attr_map_to_string(py, node_attrs.as_ref(), node.weight())?
+            attr_map_to_string(&attr_map(py, node_attrs.as_ref(), node.weight())?)
         )?;
     }
+    for group in rank_groups.unwrap_or_default() {
+        let nodes: String = group.iter().map(|node| format!(" {node};")).collect();
+        writeln!(file, "{{rank=same;{nodes}}}")?;
+    }
     for edge in graph.edge_references() {
+        let mut attrs = attr_map(py, edge_attrs.as_ref(), edge.weight())?;
+        // Ports are emitted as part of the endpoints instead of as attributes
+        let tail_port = port_specifier(attrs.remove("tailport"));
+        let head_port = port_specifier(attrs.remove("headport"));
         writeln!(
             file,
-            "{} {} {} {};",
+            "{}{} {} {}{} {};",
             graph.to_index(edge.source()),
+            tail_port,
             EDGE[graph.is_directed() as usize],
             graph.to_index(edge.target()),
-            attr_map_to_string(py, edge_attrs.as_ref(), edge.weight())?
+            head_port,
+            attr_map_to_string(&attrs)
         )?;
     }
     writeln!(file, "}}")?;
@@ -66,23 +77,27 @@ where
 
 static ATTRS_TO_ESCAPE: [&str; 2] = ["label", "tooltip"];
 
-/// Convert an attr map to an output string
-fn attr_map_to_string<'a>(
+/// Get the attr map of a node or edge from the attr callable, if any
+fn attr_map(
     py: Python,
-    attrs: Option<&'a PyObject>,
-    weight: &'a PyObject,
-) -> PyResult<String> {
-    if attrs.is_none() {
-        return Ok("".to_string());
+    attrs: Option<&PyObject>,
+    weight: &PyObject,
+) -> PyResult<BTreeMap<String, String>> {
+    match attrs {
+        Some(attrs) => attrs.call1(py, (weight,))?.extract(py),
+        None => Ok(BTreeMap::new()),
     }
-    let attr_callable = |node: &'a PyObject| -> PyResult<BTreeMap<String, String>> {
-        let res = attrs.unwrap().call1(py, (node,))?;
-        res.extract(py)
-    };
+}
 
-    let attrs = attr_callable(weight)?;
+/// Convert an optional port into a ``:port`` suffix for an edge endpoint
+fn port_specifier(port: Option<String>) -> String {
+    port.map(|port| format!(":{port}")).unwrap_or_default()
+}
+
+/// Convert an attr map to an output string
+fn attr_map_to_string(attrs: &BTreeMap<String, String>) -> String {
     if attrs.is_empty() {
-        return Ok("".to_string());
+        return "".to_string();
     }
     let attr_string = attrs
         .iter()
@@ -95,5 +110,5 @@ fn attr_map_to_string<'a>(
         })
         .collect::<Vec<String>>()
         .join(", ");
-    Ok(format!("[{attr_string}]"))
+    format!("[{attr_string}]")
 }
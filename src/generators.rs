@@ -73,6 +73,7 @@ pub fn cycle_graph(
         node_removed: false,
         multigraph,
         attrs: py.None(),
+        connectivity: None,
     })
 }
 
@@ -189,6 +190,7 @@ pub fn path_graph(
         node_removed: false,
         multigraph,
         attrs: py.None(),
+        connectivity: None,
     })
 }
 
@@ -307,6 +309,7 @@ pub fn star_graph(
         node_removed: false,
         multigraph,
         attrs: py.None(),
+        connectivity: None,
     })
 }
 
@@ -518,6 +521,7 @@ pub fn grid_graph(
         node_removed: false,
         multigraph,
         attrs: py.None(),
+        connectivity: None,
     })
 }
 
@@ -662,6 +666,7 @@ pub fn heavy_square_graph(py: Python, d: usize, multigraph: bool) -> PyResult<gr
         node_removed: false,
         multigraph,
         attrs: py.None(),
+        connectivity: None,
     })
 }
 
@@ -821,6 +826,7 @@ pub fn heavy_hex_graph(py: Python, d: usize, multigraph: bool) -> PyResult<graph
         node_removed: false,
         multigraph,
         attrs: py.None(),
+        connectivity: None,
     })
 }
 
@@ -973,6 +979,7 @@ pub fn binomial_tree_graph(
         node_removed: false,
         multigraph,
         attrs: py.None(),
+        connectivity: None,
     })
 }
 
@@ -1107,6 +1114,7 @@ pub fn full_rary_tree(
         node_removed: false,
         multigraph,
         attrs: py.None(),
+        connectivity: None,
     })
 }
 
@@ -1186,6 +1194,7 @@ pub fn hexagonal_lattice_graph(
         node_removed: false,
         multigraph,
         attrs: py.None(),
+        connectivity: None,
     })
 }
 
@@ -1347,6 +1356,7 @@ pub fn lollipop_graph(
         node_removed: false,
         multigraph,
         attrs: py.None(),
+        connectivity: None,
     })
 }
 
@@ -1422,6 +1432,7 @@ pub fn barbell_graph(
         node_removed: false,
         multigraph,
         attrs: py.None(),
+        connectivity: None,
     })
 }
 
@@ -1493,6 +1504,7 @@ pub fn generalized_petersen_graph(
         node_removed: false,
         multigraph,
         attrs: py.None(),
+        connectivity: None,
     })
 }
 
@@ -1525,6 +1537,7 @@ pub fn empty_graph(py: Python, n: usize, multigraph: bool) -> PyResult<graph::Py
         node_removed: false,
         multigraph,
         attrs: py.None(),
+        connectivity: None,
     })
 }
 
@@ -1622,6 +1635,7 @@ pub fn complete_graph(
         node_removed: false,
         multigraph,
         attrs: py.None(),
+        connectivity: None,
     })
 }
 
@@ -1723,6 +1737,7 @@ pub fn dorogovtsev_goltsev_mendes_graph(py: Python, n: usize) -> PyResult<graph:
         node_removed: false,
         multigraph: false,
         attrs: py.None(),
+        connectivity: None,
     })
 }
 
@@ -1775,6 +1790,7 @@ pub fn karate_club_graph(py: Python, multigraph: bool) -> PyResult<graph::PyGrap
         node_removed: false,
         multigraph,
         attrs: py.None(),
+        connectivity: None,
     })
 }
 
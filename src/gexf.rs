@@ -0,0 +1,260 @@
+// Licensed under the Apache License, Version 2.0 (the "License"); you may
+// not use this file except in compliance with the License. You may obtain
+// a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+
+use std::fs::File;
+use std::io::{BufWriter, Write};
+
+use indexmap::map::Entry;
+
+use quick_xml::events::{BytesDecl, BytesStart, Event};
+use quick_xml::Writer;
+
+use petgraph::visit::{EdgeRef, IntoEdgeReferences, IntoNodeReferences};
+
+use pyo3::exceptions::PyException;
+use pyo3::prelude::*;
+
+use rustworkx_core::dictmap::{DictMap, InitWithHasher};
+
+use crate::graph::PyGraph;
+
+/// A typed GEXF attribute value
+enum Value {
+    Boolean(bool),
+    Long(i64),
+    Double(f64),
+    String(String),
+}
+
+impl Value {
+    fn ty(&self) -> &'static str {
+        match self {
+            Value::Boolean(_) => "boolean",
+            Value::Long(_) => "long",
+            Value::Double(_) => "double",
+            Value::String(_) => "string",
+        }
+    }
+
+    fn serialize(&self) -> String {
+        match self {
+            Value::Boolean(val) => val.to_string(),
+            Value::Long(val) => val.to_string(),
+            Value::Double(val) => val.to_string(),
+            Value::String(val) => val.clone(),
+        }
+    }
+}
+
+impl<'py> FromPyObject<'py> for Value {
+    fn extract_bound(ob: &Bound<'py, PyAny>) -> PyResult<Self> {
+        if let Ok(value) = ob.extract::<bool>() {
+            return Ok(Value::Boolean(value));
+        }
+        if let Ok(value) = ob.extract::<i64>() {
+            return Ok(Value::Long(value));
+        }
+        if let Ok(value) = ob.extract::<f64>() {
+            return Ok(Value::Double(value));
+        }
+        Ok(Value::String(ob.extract::<String>()?))
+    }
+}
+
+/// Return the GEXF attribute values of a node or edge, either from the output
+/// of an attribute callback or from the payload itself if it is a dictionary.
+/// Payload values of an unsupported type are skipped.
+fn element_data(
+    py: Python<'_>,
+    weight: &PyObject,
+    attr_fn: Option<&PyObject>,
+) -> PyResult<DictMap<String, Value>> {
+    match attr_fn {
+        Some(attr_fn) => {
+            let attrs: DictMap<String, String> = attr_fn.call1(py, (weight,))?.extract(py)?;
+            Ok(attrs
+                .into_iter()
+                .map(|(name, value)| (name, Value::String(value)))
+                .collect())
+        }
+        None => {
+            let attrs: DictMap<String, PyObject> =
+                weight.extract(py).unwrap_or_else(|_| DictMap::new());
+            Ok(attrs
+                .into_iter()
+                .filter_map(|(name, value)| {
+                    value.extract::<Value>(py).ok().map(|value| (name, value))
+                })
+                .collect())
+        }
+    }
+}
+
+/// The attributes declared for a class of GEXF elements, mapping each
+/// attribute title to its id and type.
+struct Attributes {
+    class: &'static str,
+    attrs: DictMap<String, (usize, &'static str)>,
+}
+
+impl Attributes {
+    fn new(class: &'static str) -> Self {
+        Attributes {
+            class,
+            attrs: DictMap::new(),
+        }
+    }
+
+    /// Declare the attributes of the data of an element, checking that every
+    /// title is always used with the same type.
+    fn infer(&mut self, data: &DictMap<String, Value>) -> PyResult<()> {
+        for (title, value) in data {
+            let next_id = self.attrs.len();
+            match self.attrs.entry(title.clone()) {
+                Entry::Vacant(entry) => {
+                    entry.insert((next_id, value.ty()));
+                }
+                Entry::Occupied(entry) => {
+                    let (_, ty) = entry.get();
+                    if *ty != value.ty() {
+                        return Err(PyException::new_err(format!(
+                            "Mismatch type for {} attribute {}: {} and {}",
+                            self.class,
+                            title,
+                            value.ty(),
+                            ty
+                        )));
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn write<W: Write>(&self, writer: &mut Writer<W>) -> PyResult<()> {
+        if self.attrs.is_empty() {
+            return Ok(());
+        }
+        let mut elem = BytesStart::new("attributes");
+        elem.push_attribute(("class", self.class));
+        writer.write_event(Event::Start(elem.borrow()))?;
+        for (title, (id, ty)) in &self.attrs {
+            let mut attr = BytesStart::new("attribute");
+            attr.push_attribute(("id", id.to_string().as_str()));
+            attr.push_attribute(("title", title.as_str()));
+            attr.push_attribute(("type", *ty));
+            writer.write_event(Event::Empty(attr))?;
+        }
+        writer.write_event(Event::End(elem.to_end()))?;
+        Ok(())
+    }
+
+    /// Write an element, with an ``<attvalues>`` child if it has any data.
+    fn write_elem<W: Write>(
+        &self,
+        writer: &mut Writer<W>,
+        elem: BytesStart,
+        data: &DictMap<String, Value>,
+    ) -> PyResult<()> {
+        if data.is_empty() {
+            writer.write_event(Event::Empty(elem))?;
+            return Ok(());
+        }
+        writer.write_event(Event::Start(elem.borrow()))?;
+        let attvalues = BytesStart::new("attvalues");
+        writer.write_event(Event::Start(attvalues.borrow()))?;
+        for (title, value) in data {
+            let (id, _) = self.attrs[title];
+            let mut attvalue = BytesStart::new("attvalue");
+            attvalue.push_attribute(("for", id.to_string().as_str()));
+            attvalue.push_attribute(("value", value.serialize().as_str()));
+            writer.write_event(Event::Empty(attvalue))?;
+        }
+        writer.write_event(Event::End(attvalues.to_end()))?;
+        writer.write_event(Event::End(elem.to_end()))?;
+        Ok(())
+    }
+}
+
+/// Write a :class:`~rustworkx.PyGraph` as a static GEXF 1.3 document, using
+/// the node and edge indices as GEXF ids.
+pub fn write_pygraph_gexf(
+    py: Python<'_>,
+    graph: &PyGraph,
+    path: &str,
+    node_attr_fn: Option<PyObject>,
+    edge_attr_fn: Option<PyObject>,
+) -> PyResult<()> {
+    let mut node_attrs = Attributes::new("node");
+    let mut nodes = Vec::with_capacity(graph.graph.node_count());
+    for (index, weight) in graph.graph.node_references() {
+        let data = element_data(py, weight, node_attr_fn.as_ref())?;
+        node_attrs.infer(&data)?;
+        nodes.push((index.index(), data));
+    }
+    let mut edge_attrs = Attributes::new("edge");
+    let mut edges = Vec::with_capacity(graph.graph.edge_count());
+    for edge in graph.graph.edge_references() {
+        let data = element_data(py, edge.weight(), edge_attr_fn.as_ref())?;
+        edge_attrs.infer(&data)?;
+        edges.push((
+            edge.id().index(),
+            edge.source().index(),
+            edge.target().index(),
+            data,
+        ));
+    }
+
+    let file = File::create(path)?;
+    let mut writer = Writer::new(BufWriter::new(file));
+    writer.write_event(Event::Decl(BytesDecl::new("1.0", Some("UTF-8"), None)))?;
+    let mut gexf = BytesStart::new("gexf");
+    gexf.push_attribute(("xmlns", "http://gexf.net/1.3"));
+    gexf.push_attribute(("xmlns:xsi", "http://www.w3.org/2001/XMLSchema-instance"));
+    gexf.push_attribute((
+        "xsi:schemaLocation",
+        "http://gexf.net/1.3 http://gexf.net/1.3/gexf.xsd",
+    ));
+    gexf.push_attribute(("version", "1.3"));
+    writer.write_event(Event::Start(gexf.borrow()))?;
+    let mut graph_elem = BytesStart::new("graph");
+    graph_elem.push_attribute(("mode", "static"));
+    graph_elem.push_attribute(("defaultedgetype", "undirected"));
+    writer.write_event(Event::Start(graph_elem.borrow()))?;
+    node_attrs.write(&mut writer)?;
+    edge_attrs.write(&mut writer)?;
+
+    let nodes_elem = BytesStart::new("nodes");
+    writer.write_event(Event::Start(nodes_elem.borrow()))?;
+    for (index, data) in &nodes {
+        let mut elem = BytesStart::new("node");
+        elem.push_attribute(("id", index.to_string().as_str()));
+        node_attrs.write_elem(&mut writer, elem, data)?;
+    }
+    writer.write_event(Event::End(nodes_elem.to_end()))?;
+
+    let edges_elem = BytesStart::new("edges");
+    writer.write_event(Event::Start(edges_elem.borrow()))?;
+    for (index, source, target, data) in &edges {
+        let mut elem = BytesStart::new("edge");
+        elem.push_attribute(("id", index.to_string().as_str()));
+        elem.push_attribute(("source", source.to_string().as_str()));
+        elem.push_attribute(("target", target.to_string().as_str()));
+        edge_attrs.write_elem(&mut writer, elem, data)?;
+    }
+    writer.write_event(Event::End(edges_elem.to_end()))?;
+
+    writer.write_event(Event::End(graph_elem.to_end()))?;
+    writer.write_event(Event::End(gexf.to_end()))?;
+    writer.into_inner().flush()?;
+    Ok(())
+}
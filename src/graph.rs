@@ -19,14 +19,20 @@ use std::io::prelude::*;
 use std::io::{BufReader, BufWriter};
 use std::str;
 
+use fixedbitset::FixedBitSet;
 use hashbrown::{HashMap, HashSet};
+use rustworkx_core::coloring::two_color;
 use rustworkx_core::dictmap::*;
 use rustworkx_core::graph_ext::*;
 
-use pyo3::exceptions::PyIndexError;
+use pyo3::basic::CompareOp;
+use pyo3::exceptions::{PyIndexError, PyOverflowError, PyTypeError, PyValueError};
 use pyo3::gc::PyVisit;
 use pyo3::prelude::*;
-use pyo3::types::{IntoPyDict, PyBool, PyDict, PyGenericAlias, PyList, PyString, PyTuple, PyType};
+use pyo3::types::{
+    IntoPyDict, PyBool, PyBytes, PyDict, PyGenericAlias, PyIterator, PyList, PyString, PyTuple,
+    PyType,
+};
 use pyo3::IntoPyObjectExt;
 use pyo3::PyTraverseError;
 use pyo3::Python;
@@ -34,24 +40,79 @@ use pyo3::Python;
 use ndarray::prelude::*;
 use num_traits::Zero;
 use numpy::Complex64;
-use numpy::PyReadonlyArray2;
+use numpy::{IntoPyArray, PyArray1, PyArray2, PyReadonlyArray1, PyReadonlyArray2};
 
 use crate::iterators::NodeMap;
 
 use super::dot_utils::build_dot;
-use super::iterators::{EdgeIndexMap, EdgeIndices, EdgeList, NodeIndices, WeightedEdgeList};
+use super::gexf;
+use super::graphml;
+use super::iterators::{
+    EdgeIndexMap, EdgeIndices, EdgeList, LazyEdgeIndices, LazyNodeIndices, NodeIndices,
+    WeightedEdgeList,
+};
+use super::json;
+use super::pajek;
 use super::{
-    find_node_by_weight, weight_callable, IsNan, NoEdgeBetweenNodes, NodesRemoved, StablePyGraph,
+    find_node_by_weight, find_nodes_by_weight, weight_callable, IsNan, NoEdgeBetweenNodes,
+    NodesRemoved, NullGraph, StablePyGraph,
 };
 
 use crate::RxPyResult;
 use petgraph::algo;
 use petgraph::graph::{EdgeIndex, NodeIndex};
 use petgraph::prelude::*;
+use petgraph::stable_graph::EdgeReference;
+use petgraph::unionfind::UnionFind;
 use petgraph::visit::{
     EdgeIndexable, GraphBase, IntoEdgeReferences, IntoNodeReferences, NodeCount, NodeFiltered,
     NodeIndexable,
 };
+use rand::{Rng, SeedableRng};
+use rand_pcg::Pcg64;
+use rayon::prelude::*;
+
+/// A subgraph selecting at least ``1 / SUBGRAPH_BITSET_RATIO`` of the node
+/// index space filters its nodes with a bit set instead of a hash set.
+const SUBGRAPH_BITSET_RATIO: usize = 8;
+
+/// The most nodes or edges reserved up front for a count that may not be
+/// accurate, such as a count read from the header of a file or a size hint.
+/// Larger graphs grow as their nodes and edges are added.
+const MAX_UNCHECKED_RESERVATION: usize = 1 << 20;
+
+/// The mappings of the edges of a composed graph and of the keys of its
+/// ``node_map`` to the new edge indices.
+type ComposeEdgeMaps = (DictMap<usize, usize>, DictMap<usize, usize>);
+
+/// The mapping of the nodes of a composed graph to the new node indices,
+/// followed by its [`ComposeEdgeMaps`].
+type ComposeMaps = (
+    DictMap<usize, usize>,
+    DictMap<usize, usize>,
+    DictMap<usize, usize>,
+);
+
+/// The ``(indptr, indices, data, node_map)`` arrays of a graph in CSR form.
+type CsrArrays<'py> = (
+    Bound<'py, PyArray1<i64>>,
+    Bound<'py, PyArray1<i64>>,
+    Bound<'py, PyArray1<f64>>,
+    NodeMap,
+);
+
+/// An optional argument for which ``None`` is a meaningful value, so that an
+/// explicit ``None`` can be told apart from the argument not being passed.
+pub enum OptionalArg {
+    Unset,
+    Set(PyObject),
+}
+
+impl<'py> FromPyObject<'py> for OptionalArg {
+    fn extract_bound(ob: &Bound<'py, PyAny>) -> PyResult<Self> {
+        Ok(OptionalArg::Set(ob.clone().unbind()))
+    }
+}
 
 /// A class for creating undirected graphs
 ///
@@ -148,13 +209,30 @@ use petgraph::visit::{
 ///     only a potential performance optimization if the complete size of the graph is known in
 ///     advance.
 #[pyclass(mapping, module = "rustworkx", subclass)]
-#[derive(Clone)]
 pub struct PyGraph {
     pub graph: StablePyGraph<Undirected>,
     pub node_removed: bool,
     pub multigraph: bool,
     #[pyo3(get, set)]
     pub attrs: PyObject,
+    /// The connected components of the graph while connectivity tracking
+    /// is enabled, see :meth:`~rustworkx.PyGraph.enable_connectivity_tracking`
+    pub connectivity: Option<UnionFind<usize>>,
+}
+
+// Copies of the graph are often modified directly through the inner graph,
+// which would leave the connected components stale, so they don't keep the
+// connectivity tracking.
+impl Clone for PyGraph {
+    fn clone(&self) -> Self {
+        PyGraph {
+            graph: self.graph.clone(),
+            node_removed: self.node_removed,
+            multigraph: self.multigraph,
+            attrs: self.attrs.clone(),
+            connectivity: None,
+        }
+    }
 }
 
 impl GraphBase for PyGraph {
@@ -176,1177 +254,4950 @@ impl NodeCount for PyGraph {
 
 impl PyGraph {
     fn _add_edge(&mut self, u: NodeIndex, v: NodeIndex, edge: PyObject) -> usize {
+        self.add_edge_with_status(u, v, edge).0
+    }
+
+    /// Add an edge between two node indices given from Python, raising an
+    /// IndexError if either node is missing. Returns the edge index and
+    /// whether a new edge was created.
+    pub(crate) fn add_edge_checked(
+        &mut self,
+        node_a: usize,
+        node_b: usize,
+        edge: PyObject,
+    ) -> PyResult<(usize, bool)> {
+        let p_index = NodeIndex::new(node_a);
+        let c_index = NodeIndex::new(node_b);
+        if !self.graph.contains_node(p_index) || !self.graph.contains_node(c_index) {
+            return Err(PyIndexError::new_err(
+                "One of the endpoints of the edge does not exist in graph",
+            ));
+        }
+        Ok(self.add_edge_with_status(p_index, c_index, edge))
+    }
+
+    /// Add an edge, or update the weight of the existing edge between ``u``
+    /// and ``v`` if this isn't a multigraph. Returns the edge index and
+    /// whether a new edge was created.
+    fn add_edge_with_status(
+        &mut self,
+        u: NodeIndex,
+        v: NodeIndex,
+        edge: PyObject,
+    ) -> (usize, bool) {
         if !self.multigraph {
             let exists = self.graph.find_edge(u, v);
             if let Some(index) = exists {
                 let edge_weight = self.graph.edge_weight_mut(index).unwrap();
                 *edge_weight = edge;
-                return index.index();
+                return (index.index(), false);
             }
         }
         let edge = self.graph.add_edge(u, v, edge);
-        edge.index()
+        self.track_edge(u, v);
+        (edge.index(), true)
     }
-}
 
-#[pymethods]
-impl PyGraph {
-    #[new]
-    #[pyo3(signature=(multigraph=true, attrs=None, *, node_count_hint=None, edge_count_hint=None))]
-    fn new(
+    /// Join the components of the endpoints of a new edge if connectivity
+    /// tracking is enabled.
+    fn track_edge(&mut self, u: NodeIndex, v: NodeIndex) {
+        if let Some(components) = self.connectivity.as_mut() {
+            while components.len() <= cmp::max(u.index(), v.index()) {
+                components.new_set();
+            }
+            components.union(u.index(), v.index());
+        }
+    }
+
+    /// Build an edge induced subgraph from the selected edges and their
+    /// endpoints, with the nodes and edges added in index order. Returns the
+    /// subgraph and the mapping of its node indices to the node indices of
+    /// this graph.
+    fn build_edge_subgraph(
+        &self,
         py: Python,
-        multigraph: bool,
-        attrs: Option<PyObject>,
-        node_count_hint: Option<usize>,
-        edge_count_hint: Option<usize>,
-    ) -> Self {
-        PyGraph {
-            graph: StablePyGraph::<Undirected>::with_capacity(
-                node_count_hint.unwrap_or_default(),
-                edge_count_hint.unwrap_or_default(),
-            ),
+        mut nodes: Vec<NodeIndex>,
+        mut edges: Vec<EdgeIndex>,
+        preserve_attrs: bool,
+    ) -> (PyGraph, NodeMap) {
+        nodes.sort_unstable();
+        nodes.dedup();
+        edges.sort_unstable();
+        let mut out_graph = StablePyGraph::<Undirected>::with_capacity(nodes.len(), edges.len());
+        // mapping from original node index to new node index
+        let mut node_map: HashMap<NodeIndex, NodeIndex> = HashMap::with_capacity(nodes.len());
+        // mapping from new node index to original node index
+        let mut node_dict: DictMap<usize, usize> = DictMap::with_capacity(nodes.len());
+        for node in nodes {
+            let new_node = out_graph.add_node(self.graph[node].clone_ref(py));
+            node_map.insert(node, new_node);
+            node_dict.insert(new_node.index(), node.index());
+        }
+        for edge in edges {
+            let (source, target) = self.graph.edge_endpoints(edge).unwrap();
+            out_graph.add_edge(
+                node_map[&source],
+                node_map[&target],
+                self.graph[edge].clone_ref(py),
+            );
+        }
+        let attrs = if preserve_attrs {
+            self.attrs.clone_ref(py)
+        } else {
+            py.None()
+        };
+        let subgraph = PyGraph {
+            graph: out_graph,
             node_removed: false,
-            multigraph,
-            attrs: attrs.unwrap_or_else(|| py.None()),
+            multigraph: self.multigraph,
+            attrs,
+            connectivity: None,
+        };
+        (
+            subgraph,
+            NodeMap {
+                node_map: node_dict,
+            },
+        )
+    }
+
+    /// Copy this graph keeping only the nodes in ``nodes`` and the edges
+    /// accepted by ``keep_edge``, so node and edge indices are unchanged.
+    fn retained_edge_subgraph<F>(&self, nodes: &HashSet<NodeIndex>, keep_edge: F) -> PyGraph
+    where
+        F: Fn(EdgeReference<PyObject>) -> bool,
+    {
+        let mut out_graph = self.clone();
+        for node in self
+            .graph
+            .node_indices()
+            .filter(|node| !nodes.contains(node))
+        {
+            out_graph.graph.remove_node(node);
+            out_graph.node_removed = true;
+        }
+        for edge in self
+            .graph
+            .edge_references()
+            .filter(|edge| !keep_edge(*edge))
+        {
+            out_graph.graph.remove_edge(edge.id());
         }
+        out_graph
     }
 
-    fn __getnewargs_ex__<'py>(
+    /// Build the subgraph induced by the nodes accepted by ``node_filter``.
+    /// ``capacity`` is the expected number of nodes in the subgraph. If
+    /// ``with_edge_map`` is set the mapping of the edge indices of this graph
+    /// to the edge indices of the subgraph is returned too.
+    fn build_node_subgraph<F>(
         &self,
-        py: Python<'py>,
-    ) -> PyResult<(Bound<'py, PyTuple>, Bound<'py, PyDict>)> {
-        Ok((
-            (self.multigraph, self.attrs.clone_ref(py)).into_pyobject(py)?,
-            [
-                ("node_count_hint", self.graph.node_bound()),
-                ("edge_count_hint", self.graph.edge_bound()),
-            ]
-            .into_py_dict(py)?,
-        ))
+        py: Python,
+        node_filter: F,
+        capacity: usize,
+        preserve_attrs: bool,
+        with_edge_map: bool,
+    ) -> (PyGraph, NodeMap, Option<DictMap<usize, usize>>)
+    where
+        F: Fn(NodeIndex) -> bool,
+    {
+        // mapping from original node index to new node index
+        let mut node_map: HashMap<NodeIndex, NodeIndex> = HashMap::with_capacity(capacity);
+        // mapping from new node index to original node index
+        let mut node_dict: DictMap<usize, usize> = DictMap::with_capacity(capacity);
+        let mut out_graph = StablePyGraph::<Undirected>::default();
+        let filtered = NodeFiltered(&self.graph, node_filter);
+        for node in filtered.node_references() {
+            let new_node = out_graph.add_node(node.1.clone_ref(py));
+            node_map.insert(node.0, new_node);
+            node_dict.insert(new_node.index(), node.0.index());
+        }
+        let mut edge_map: Option<DictMap<usize, usize>> = with_edge_map.then(DictMap::new);
+        for edge in filtered.edge_references() {
+            let new_source = *node_map.get(&edge.source()).unwrap();
+            let new_target = *node_map.get(&edge.target()).unwrap();
+            let new_edge = out_graph.add_edge(new_source, new_target, edge.weight().clone_ref(py));
+            if let Some(edge_map) = edge_map.as_mut() {
+                edge_map.insert(edge.id().index(), new_edge.index());
+            }
+        }
+        let attrs = if preserve_attrs {
+            self.attrs.clone_ref(py)
+        } else {
+            py.None()
+        };
+        let node_map = NodeMap {
+            node_map: node_dict,
+        };
+        let subgraph = PyGraph {
+            graph: out_graph,
+            node_removed: false,
+            multigraph: self.multigraph,
+            attrs,
+            connectivity: None,
+        };
+        (subgraph, node_map, edge_map)
     }
 
-    fn __getstate__(&self, py: Python) -> PyResult<PyObject> {
-        let mut nodes: Vec<PyObject> = Vec::with_capacity(self.graph.node_bound());
-        let mut edges: Vec<PyObject> = Vec::with_capacity(self.graph.edge_bound());
+    /// The subgraph of every node of the graph, equal to the output of
+    /// ``build_node_subgraph`` with a filter selecting all nodes. The graph is
+    /// cloned directly if there are no holes in its node and edge indices.
+    fn full_node_subgraph(
+        &self,
+        py: Python,
+        preserve_attrs: bool,
+        with_edge_map: bool,
+    ) -> (PyGraph, NodeMap, Option<DictMap<usize, usize>>) {
+        let (out_graph, node_dict) = if self.graph.node_count() == self.graph.node_bound()
+            && self.graph.edge_count() == self.graph.edge_bound()
+        {
+            let node_dict = (0..self.graph.node_count())
+                .map(|node| (node, node))
+                .collect();
+            (self.graph.clone(), node_dict)
+        } else {
+            let (out_graph, node_map) = self.compacted_graph(py);
+            let node_dict = node_map
+                .into_iter()
+                .map(|(node, new_node)| (new_node, node))
+                .collect();
+            (out_graph, node_dict)
+        };
+        let edge_map = with_edge_map.then(|| {
+            self.graph
+                .edge_indices()
+                .enumerate()
+                .map(|(new_edge, edge)| (edge.index(), new_edge))
+                .collect()
+        });
+        let attrs = if preserve_attrs {
+            self.attrs.clone_ref(py)
+        } else {
+            py.None()
+        };
+        let subgraph = PyGraph {
+            graph: out_graph,
+            node_removed: false,
+            multigraph: self.multigraph,
+            attrs,
+            connectivity: None,
+        };
+        let node_map = NodeMap {
+            node_map: node_dict,
+        };
+        (subgraph, node_map, edge_map)
+    }
 
-        // save nodes to a list along with its index
-        for node_idx in self.graph.node_indices() {
-            let node_data = self.graph.node_weight(node_idx).unwrap();
-            nodes.push((node_idx.index(), node_data).into_py_any(py)?);
+    /// Whether ``nodes`` contains the index of every node in the graph.
+    fn selects_all_nodes(&self, nodes: &[usize]) -> bool {
+        let node_count = self.graph.node_count();
+        if nodes.len() < node_count {
+            return false;
         }
-
-        // edges are saved with none (deleted edges) instead of their index to save space
-        for i in 0..self.graph.edge_bound() {
-            let idx = EdgeIndex::new(i);
-            let edge = match self.graph.edge_weight(idx) {
-                Some(edge_w) => {
-                    let endpoints = self.graph.edge_endpoints(idx).unwrap();
-                    (endpoints.0.index(), endpoints.1.index(), edge_w).into_py_any(py)?
-                }
-                None => py.None(),
-            };
-            edges.push(edge);
+        let mut node_bits = FixedBitSet::with_capacity(self.graph.node_bound());
+        for node in nodes {
+            if self.graph.contains_node(NodeIndex::new(*node)) {
+                node_bits.insert(*node);
+            }
         }
-
-        let out_dict = PyDict::new(py);
-        let nodes_lst: PyObject = PyList::new(py, nodes)?.into_any().unbind();
-        let edges_lst: PyObject = PyList::new(py, edges)?.into_any().unbind();
-        out_dict.set_item("nodes", nodes_lst)?;
-        out_dict.set_item("edges", edges_lst)?;
-        out_dict.set_item("nodes_removed", self.node_removed)?;
-        Ok(out_dict.into())
+        node_bits.count_ones(..) == node_count
     }
 
-    fn __setstate__(&mut self, py: Python, state: PyObject) -> PyResult<()> {
-        let dict_state = state.downcast_bound::<PyDict>(py)?;
-        let binding = dict_state.get_item("nodes")?.unwrap();
-        let nodes_lst = binding.downcast::<PyList>()?;
-        let binding = dict_state.get_item("edges")?.unwrap();
-        let edges_lst = binding.downcast::<PyList>()?;
+    /// The number of connected components of the graph, found with a single
+    /// union-find pass over the edges.
+    fn count_connected_components(&self) -> usize {
+        let mut components = UnionFind::new(self.graph.node_bound());
+        let mut count = self.graph.node_count();
+        for edge in self.graph.edge_references() {
+            if components.union(edge.source().index(), edge.target().index()) {
+                count -= 1;
+            }
+        }
+        count
+    }
 
-        self.node_removed = dict_state
-            .get_item("nodes_removed")?
-            .unwrap()
-            .downcast::<PyBool>()?
-            .extract()?;
-        // graph is empty, stop early
-        if nodes_lst.is_empty() {
-            return Ok(());
+    /// The degree of every node, indexed by node index, with a self loop
+    /// counting twice. Entries for removed node indices are 0.
+    fn node_degrees(&self) -> Vec<usize> {
+        let mut degrees = vec![0; self.graph.node_bound()];
+        for edge in self.graph.edge_references() {
+            degrees[edge.source().index()] += 1;
+            degrees[edge.target().index()] += 1;
         }
+        degrees
+    }
 
-        if !self.node_removed {
-            for item in nodes_lst.iter() {
-                let node_w = item
-                    .downcast::<PyTuple>()
-                    .unwrap()
-                    .get_item(1)
-                    .unwrap()
-                    .extract()
-                    .unwrap();
-                self.graph.add_node(node_w);
-            }
-        } else if nodes_lst.len() == 1 {
-            // graph has only one node, handle logic here to save one if in the loop later
-            let binding = nodes_lst.get_item(0).unwrap();
-            let item = binding.downcast::<PyTuple>().unwrap();
-            let node_idx: usize = item.get_item(0).unwrap().extract().unwrap();
-            let node_w = item.get_item(1).unwrap().extract().unwrap();
+    /// The sorted distinct neighbors of every node other than itself, indexed
+    /// by node index, i.e. the adjacency of the graph with parallel edges
+    /// collapsed and self loops removed. Entries for removed node indices are
+    /// empty.
+    fn distinct_neighbors(&self) -> Vec<Vec<usize>> {
+        (0..self.graph.node_bound())
+            .map(|index| self.node_distinct_neighbors(NodeIndex::new(index)))
+            .collect()
+    }
 
-            for _i in 0..node_idx {
-                self.graph.add_node(py.None());
-            }
-            self.graph.add_node(node_w);
-            for i in 0..node_idx {
-                self.graph.remove_node(NodeIndex::new(i));
-            }
-        } else {
-            let binding = nodes_lst.get_item(nodes_lst.len() - 1).unwrap();
-            let last_item = binding.downcast::<PyTuple>().unwrap();
+    /// The sorted distinct neighbors of a node other than itself, empty if
+    /// the node is not in the graph.
+    fn node_distinct_neighbors(&self, node: NodeIndex) -> Vec<usize> {
+        let mut neighbors: Vec<usize> = self
+            .graph
+            .neighbors(node)
+            .filter(|neighbor| *neighbor != node)
+            .map(|neighbor| neighbor.index())
+            .collect();
+        neighbors.sort_unstable();
+        neighbors.dedup();
+        neighbors
+    }
 
-            // list of temporary nodes that will be removed later to re-create holes
-            let node_bound_1: usize = last_item.get_item(0).unwrap().extract().unwrap();
-            let mut tmp_nodes: Vec<NodeIndex> =
-                Vec::with_capacity(node_bound_1 + 1 - nodes_lst.len());
+    /// The sorted distinct neighbors of a node other than itself, raising an
+    /// IndexError if the node is not in the graph.
+    fn checked_distinct_neighbors(&self, node: usize) -> PyResult<Vec<usize>> {
+        let index = NodeIndex::new(node);
+        if !self.graph.contains_node(index) {
+            return Err(PyIndexError::new_err(format!(
+                "No node found for index: {node}"
+            )));
+        }
+        Ok(self.node_distinct_neighbors(index))
+    }
 
-            for item in nodes_lst {
-                let item = item.downcast::<PyTuple>().unwrap();
-                let next_index: usize = item.get_item(0).unwrap().extract().unwrap();
-                let weight: PyObject = item.get_item(1).unwrap().extract().unwrap();
-                while next_index > self.graph.node_bound() {
-                    // node does not exist
-                    let tmp_node = self.graph.add_node(py.None());
-                    tmp_nodes.push(tmp_node);
-                }
-                // add node to the graph, and update the next available node index
-                self.graph.add_node(weight);
-            }
-            // Remove any temporary nodes we added
-            for tmp_node in tmp_nodes {
-                self.graph.remove_node(tmp_node);
+    /// The common elements of two sorted lists of distinct node indices.
+    fn sorted_intersection(first: &[usize], second: &[usize]) -> Vec<usize> {
+        let mut common = Vec::new();
+        let mut first = first.iter().peekable();
+        let mut second = second.iter().peekable();
+        while let (Some(&&a), Some(&&b)) = (first.peek(), second.peek()) {
+            if a < b {
+                first.next();
+            } else if b < a {
+                second.next();
+            } else {
+                common.push(a);
+                first.next();
+                second.next();
             }
         }
+        common
+    }
 
-        // to ensure O(1) on edge deletion, use a temporary node to store missing edges
-        let tmp_node = self.graph.add_node(py.None());
+    /// The Jaccard coefficient of two sorted lists of distinct neighbors,
+    /// ``0.0`` if both are empty.
+    fn jaccard_from_neighbors(first: &[usize], second: &[usize]) -> f64 {
+        let common = Self::sorted_intersection(first, second).len();
+        let union = first.len() + second.len() - common;
+        if union == 0 {
+            return 0.0;
+        }
+        common as f64 / union as f64
+    }
 
-        for item in edges_lst {
-            if item.is_none() {
-                // add a temporary edge that will be deleted later to re-create the hole
-                self.graph.add_edge(tmp_node, tmp_node, py.None());
-            } else {
-                let triple = item.downcast::<PyTuple>().unwrap();
-                let edge_p: usize = triple.get_item(0).unwrap().extract().unwrap();
-                let edge_c: usize = triple.get_item(1).unwrap().extract().unwrap();
-                let edge_w = triple.get_item(2).unwrap().extract().unwrap();
-                self.graph
-                    .add_edge(NodeIndex::new(edge_p), NodeIndex::new(edge_c), edge_w);
-            }
+    /// The local clustering coefficient of a node with ``degree`` distinct
+    /// neighbors that is part of ``triangles`` triangles.
+    fn clustering_from_triangles(triangles: usize, degree: usize) -> f64 {
+        if degree < 2 {
+            return 0.0;
         }
+        (2 * triangles) as f64 / (degree * (degree - 1)) as f64
+    }
 
-        // remove the temporary node will remove all deleted edges in bulk,
-        // the cost is equal to the number of edges
-        self.graph.remove_node(tmp_node);
+    /// The local clustering coefficient of every node, indexed by node index.
+    fn node_clustering(&self) -> Vec<f64> {
+        let neighbors = self.distinct_neighbors();
+        Self::count_triangles(&neighbors)
+            .into_iter()
+            .zip(&neighbors)
+            .map(|(triangles, neighbors)| {
+                Self::clustering_from_triangles(triangles, neighbors.len())
+            })
+            .collect()
+    }
 
-        Ok(())
+    /// The number of triangles every node is part of, indexed by node index,
+    /// with parallel edges collapsed and self loops ignored.
+    fn node_triangles(&self) -> Vec<usize> {
+        Self::count_triangles(&self.distinct_neighbors())
     }
 
-    /// Whether the graph is a multigraph (allows multiple edges between
-    /// nodes) or not
-    ///
-    /// If set to ``False`` multiple edges between nodes are not allowed and
-    /// calls that would add a parallel edge will instead update the existing
-    /// edge
-    #[getter]
-    fn multigraph(&self) -> bool {
-        self.multigraph
+    /// The number of triangles every node is part of, given the sorted
+    /// distinct neighbors of every node. Each triangle is found once, from
+    /// its two lowest nodes, by intersecting their neighbor lists.
+    fn count_triangles(neighbors: &[Vec<usize>]) -> Vec<usize> {
+        let mut triangles = vec![0; neighbors.len()];
+        for (node, node_neighbors) in neighbors.iter().enumerate() {
+            for &neighbor in node_neighbors.iter().filter(|neighbor| **neighbor > node) {
+                // Common neighbors higher than neighbor
+                let mut first = node_neighbors.iter().peekable();
+                let mut second = neighbors[neighbor].iter().peekable();
+                while let (Some(&&a), Some(&&b)) = (first.peek(), second.peek()) {
+                    if a < b {
+                        first.next();
+                    } else if b < a {
+                        second.next();
+                    } else {
+                        if a > neighbor {
+                            triangles[node] += 1;
+                            triangles[neighbor] += 1;
+                            triangles[a] += 1;
+                        }
+                        first.next();
+                        second.next();
+                    }
+                }
+            }
+        }
+        triangles
     }
 
-    /// Detect if the graph has parallel edges or not
-    ///
-    /// :returns: ``True`` if the graph has parallel edges, ``False`` otherwise
-    /// :rtype: bool
-    #[pyo3(text_signature = "(self)")]
-    fn has_parallel_edges(&self) -> bool {
-        if !self.multigraph {
-            return false;
+    /// The core number of every node, indexed by node index, computed by
+    /// peeling nodes in order of degree. The degree of a node is its number
+    /// of distinct neighbors, so parallel edges count once and self loops are
+    /// ignored. Entries for removed node indices are 0.
+    fn core_numbers(&self) -> Vec<usize> {
+        let node_bound = self.graph.node_bound();
+        let neighbors = self.distinct_neighbors();
+        let mut cores: Vec<usize> = neighbors.iter().map(|nbrs| nbrs.len()).collect();
+        let max_degree = cores.iter().copied().max().unwrap_or(0);
+        // Bucket sort the nodes by degree, bin_starts[d] is the position of
+        // the first node of degree d in sorted_nodes
+        let mut bin_starts = vec![0; max_degree + 2];
+        for node in self.graph.node_indices() {
+            bin_starts[cores[node.index()] + 1] += 1;
         }
-        self.graph.has_parallel_edges()
+        for degree in 1..bin_starts.len() {
+            bin_starts[degree] += bin_starts[degree - 1];
+        }
+        let mut sorted_nodes = vec![0; self.graph.node_count()];
+        let mut positions = vec![0; node_bound];
+        let mut next_positions = bin_starts.clone();
+        for node in self.graph.node_indices() {
+            let position = &mut next_positions[cores[node.index()]];
+            sorted_nodes[*position] = node.index();
+            positions[node.index()] = *position;
+            *position += 1;
+        }
+        for i in 0..sorted_nodes.len() {
+            let node = sorted_nodes[i];
+            for &neighbor in &neighbors[node] {
+                if cores[neighbor] > cores[node] {
+                    // Move the neighbor to the start of its bin and shrink
+                    // the bin, which lowers its degree by one
+                    let degree = cores[neighbor];
+                    let position = positions[neighbor];
+                    let bin_start = bin_starts[degree];
+                    let first = sorted_nodes[bin_start];
+                    sorted_nodes.swap(position, bin_start);
+                    positions[neighbor] = bin_start;
+                    positions[first] = position;
+                    bin_starts[degree] += 1;
+                    cores[neighbor] -= 1;
+                }
+            }
+        }
+        cores
     }
 
-    /// Clears all nodes and edges
-    #[pyo3(text_signature = "(self)")]
-    pub fn clear(&mut self) {
-        self.graph.clear();
-        self.node_removed = true;
+    /// Build a copy of the graph structure with contiguous node and edge
+    /// indices, preserving the relative order of nodes and edges. Returns the
+    /// new graph and the mapping of old node indices to new node indices.
+    fn compacted_graph(&self, py: Python) -> (StablePyGraph<Undirected>, DictMap<usize, usize>) {
+        let mut graph = StablePyGraph::<Undirected>::with_capacity(
+            self.graph.node_count(),
+            self.graph.edge_count(),
+        );
+        let mut node_map = DictMap::with_capacity(self.graph.node_count());
+        for (node, weight) in self.graph.node_references() {
+            let new_node = graph.add_node(weight.clone_ref(py));
+            node_map.insert(node.index(), new_node.index());
+        }
+        for edge in self.graph.edge_references() {
+            graph.add_edge(
+                NodeIndex::new(node_map[&edge.source().index()]),
+                NodeIndex::new(node_map[&edge.target().index()]),
+                edge.weight().clone_ref(py),
+            );
+        }
+        (graph, node_map)
     }
 
-    /// Clears all edges, leaves nodes intact
-    #[pyo3(text_signature = "(self)")]
-    pub fn clear_edges(&mut self) {
-        self.graph.clear_edges();
+    /// Replace every node and edge payload and the attrs of the graph with a
+    /// deep copy, sharing a single memo dictionary.
+    fn deepcopy_payloads(&mut self, py: Python) -> PyResult<()> {
+        let deepcopy = py.import("copy")?.getattr("deepcopy")?;
+        let memo = PyDict::new(py);
+        for weight in self.graph.node_weights_mut() {
+            *weight = deepcopy.call1((&*weight, &memo))?.unbind();
+        }
+        for weight in self.graph.edge_weights_mut() {
+            *weight = deepcopy.call1((&*weight, &memo))?.unbind();
+        }
+        self.attrs = deepcopy.call1((&self.attrs, &memo))?.unbind();
+        Ok(())
     }
 
-    /// Return the number of nodes in the graph
-    #[pyo3(text_signature = "(self)")]
-    pub fn num_nodes(&self) -> usize {
-        self.graph.node_count()
+    /// Add the nodes and edges of ``other`` to this graph, plus an edge for
+    /// every entry of ``node_map``. Returns the new index of every node of
+    /// ``other``, indexed by its index in ``other``, and, if ``with_edge_map``
+    /// is set, the mappings of the edges of ``other`` and of the keys of
+    /// ``node_map`` to the new edge indices.
+    fn compose_graph(
+        &mut self,
+        py: Python,
+        other: &PyGraph,
+        node_map: HashMap<usize, (usize, PyObject)>,
+        node_map_func: Option<PyObject>,
+        edge_map_func: Option<PyObject>,
+        with_edge_map: bool,
+    ) -> PyResult<(Vec<NodeIndex>, Option<ComposeEdgeMaps>)> {
+        self.graph.reserve_nodes(other.graph.node_count());
+        self.graph
+            .reserve_edges(other.graph.edge_count() + node_map.len());
+        // New node index of each node of other, indexed by its index in other
+        let mut new_indices: Vec<NodeIndex> = vec![NodeIndex::end(); other.graph.node_bound()];
+        for (node, weight) in other.graph.node_references() {
+            let weight = weight_transform_callable(py, &node_map_func, weight)?;
+            new_indices[node.index()] = self.graph.add_node(weight);
+        }
+        let (mut new_edge_map, mut node_map_edges) = if with_edge_map {
+            (
+                DictMap::with_capacity(other.graph.edge_count()),
+                DictMap::with_capacity(node_map.len()),
+            )
+        } else {
+            (DictMap::new(), DictMap::new())
+        };
+        for edge in other.graph.edge_references() {
+            let source = new_indices[edge.source().index()];
+            let target = new_indices[edge.target().index()];
+            let weight = weight_transform_callable(py, &edge_map_func, edge.weight())?;
+            let new_edge = self.graph.add_edge(source, target, weight);
+            self.track_edge(source, target);
+            if with_edge_map {
+                new_edge_map.insert(edge.id().index(), new_edge.index());
+            }
+        }
+        for (this_index, (index, weight)) in node_map {
+            let source = NodeIndex::new(this_index);
+            let target = new_indices[index];
+            let new_edge = self.graph.add_edge(source, target, weight);
+            self.track_edge(source, target);
+            if with_edge_map {
+                node_map_edges.insert(this_index, new_edge.index());
+            }
+        }
+        let edge_maps = with_edge_map.then_some((new_edge_map, node_map_edges));
+        Ok((new_indices, edge_maps))
     }
 
-    /// Return the number of edges in the graph
-    #[pyo3(text_signature = "(self)")]
-    pub fn num_edges(&self) -> usize {
-        self.graph.edge_count()
+    /// Swap the stored endpoints of an edge. Removing the edge pushes its
+    /// index on the free list, so the edge added back reuses the same index.
+    fn reverse_edge_endpoints(&mut self, index: EdgeIndex, source: NodeIndex, target: NodeIndex) {
+        let weight = self.graph.remove_edge(index).unwrap();
+        let new_index = self.graph.add_edge(target, source, weight);
+        debug_assert_eq!(index, new_index);
     }
 
-    /// Return a list of all edge data.
-    ///
-    /// :returns: A list of all the edge data objects in the graph
-    /// :rtype: list[T]
-    #[pyo3(text_signature = "(self)")]
-    pub fn edges(&self) -> Vec<&PyObject> {
-        self.graph
-            .edge_indices()
-            .map(|edge| self.graph.edge_weight(edge).unwrap())
-            .collect()
+    /// The sorted distinct indices in ``nodes`` that are present in the graph.
+    fn present_node_set(&self, nodes: Vec<usize>) -> Vec<NodeIndex> {
+        let mut nodes: Vec<NodeIndex> = nodes
+            .into_iter()
+            .map(NodeIndex::new)
+            .filter(|node| self.graph.contains_node(*node))
+            .collect();
+        nodes.sort_unstable();
+        nodes.dedup();
+        nodes
     }
 
-    /// Return a list of all edge indices.
-    ///
-    /// :returns: A list of all the edge indices in the graph
-    /// :rtype: EdgeIndices
-    #[pyo3(text_signature = "(self)")]
-    pub fn edge_indices(&self) -> EdgeIndices {
-        EdgeIndices {
-            edges: self.graph.edge_indices().map(|edge| edge.index()).collect(),
+    /// Build a membership filter for the nodes of a subgraph. A selection
+    /// covering a large fraction of the node index space is stored in a bit
+    /// set, which is much cheaper to build and query than a hash set of the
+    /// same size.
+    fn node_selection_filter(
+        &self,
+        nodes: impl ExactSizeIterator<Item = usize>,
+    ) -> Box<dyn Fn(NodeIndex) -> bool> {
+        let node_bound = self.graph.node_bound();
+        if nodes.len().saturating_mul(SUBGRAPH_BITSET_RATIO) >= node_bound {
+            let mut node_bits = FixedBitSet::with_capacity(node_bound);
+            for node in nodes.filter(|node| *node < node_bound) {
+                node_bits.insert(node);
+            }
+            Box::new(move |node: NodeIndex| node_bits.contains(node.index()))
+        } else {
+            let node_set: HashSet<usize> = nodes.collect();
+            Box::new(move |node: NodeIndex| node_set.contains(&node.index()))
         }
     }
 
-    /// Return a list of indices of all edges between specified nodes
-    ///
-    /// :param int node_a: The index of the first node
-    /// :param int node_b: The index of the second node
-    ///
-    /// :returns: A list of all the edge indices connecting the specified start and end node
-    /// :rtype: EdgeIndices
-    pub fn edge_indices_from_endpoints(&self, node_a: usize, node_b: usize) -> EdgeIndices {
-        let node_a_index = NodeIndex::new(node_a);
-        let node_b_index = NodeIndex::new(node_b);
+    /// Restore the graph from the packed state created by ``__getstate__``.
+    /// Nodes and edges are added in index order, with temporary nodes and
+    /// edges filling the holes left by removals which are then removed in
+    /// bulk.
+    fn set_packed_state(
+        &mut self,
+        py: Python,
+        dict_state: &Bound<'_, PyDict>,
+        node_indices: &Bound<'_, PyAny>,
+    ) -> PyResult<()> {
+        let get_item = |key: &str| -> PyResult<Bound<'_, PyAny>> {
+            dict_state
+                .get_item(key)?
+                .ok_or_else(|| PyValueError::new_err(format!("Missing {key} in pickled state")))
+        };
+        self.node_removed = get_item("nodes_removed")?.extract()?;
+        let node_indices: PyReadonlyArray1<u32> = node_indices.extract()?;
+        let node_weights = get_item("node_weights")?;
+        let node_weights = node_weights.downcast::<PyList>()?;
+        let edge_endpoints: PyReadonlyArray2<u32> = get_item("edge_endpoints")?.extract()?;
+        let edge_weights = get_item("edge_weights")?;
+        let edge_weights = edge_weights.downcast::<PyList>()?;
 
-        EdgeIndices {
-            edges: self
-                .graph
-                .edges_directed(node_a_index, petgraph::Direction::Outgoing)
-                .filter(|edge| edge.target() == node_b_index)
-                .map(|edge| edge.id().index())
-                .collect(),
+        let node_indices = node_indices.as_array();
+        if node_indices.len() != node_weights.len() {
+            return Err(PyValueError::new_err(
+                "Pickled state has a different number of node indices and node weights",
+            ));
+        }
+        let edge_endpoints = edge_endpoints.as_array();
+        if edge_endpoints.ncols() != 2 {
+            return Err(PyValueError::new_err(
+                "Pickled edge endpoints must have two columns",
+            ));
+        }
+        let packed_edge_count = edge_endpoints
+            .column(0)
+            .iter()
+            .filter(|source| **source != u32::MAX)
+            .count();
+        if packed_edge_count != edge_weights.len() {
+            return Err(PyValueError::new_err(
+                "Pickled state has a different number of edges and edge weights",
+            ));
         }
-    }
-
-    /// Return a list of all node data.
-    ///
-    /// :returns: A list of all the node data objects in the graph
-    /// :rtype: list[S]
-    #[pyo3(text_signature = "(self)")]
-    pub fn nodes(&self) -> Vec<&PyObject> {
-        self.graph
-            .node_indices()
-            .map(|node| self.graph.node_weight(node).unwrap())
-            .collect()
-    }
 
-    /// Return a list of all node indices.
-    ///
-    /// :returns: A list of all the node indices in the graph
-    /// :rtype: NodeIndices
-    #[pyo3(text_signature = "(self)")]
-    pub fn node_indices(&self) -> NodeIndices {
-        NodeIndices {
-            nodes: self.graph.node_indices().map(|node| node.index()).collect(),
+        let node_bound = node_indices.iter().max().map_or(0, |max| *max as usize + 1);
+        self.graph.reserve_exact_nodes(node_bound + 1);
+        let mut tmp_nodes: Vec<NodeIndex> =
+            Vec::with_capacity(node_bound.saturating_sub(node_indices.len()));
+        for (next_index, weight) in node_indices.iter().zip(node_weights.iter()) {
+            let next_index = *next_index as usize;
+            if next_index < self.graph.node_bound() {
+                return Err(PyValueError::new_err(
+                    "Pickled node indices must be unique and sorted",
+                ));
+            }
+            while next_index > self.graph.node_bound() {
+                tmp_nodes.push(self.graph.add_node(py.None()));
+            }
+            self.graph.add_node(weight.unbind());
+        }
+        for tmp_node in tmp_nodes {
+            self.graph.remove_node(tmp_node);
         }
-    }
 
-    /// Return a list of all node indices.
-    ///
-    /// .. note::
-    ///
-    ///     This is identical to :meth:`.node_indices()`, which is the
-    ///     preferred method to get the node indices in the graph. This
-    ///     exists for backwards compatibility with earlier releases.
-    ///
-    /// :returns: A list of all the node indices in the graph
-    /// :rtype: NodeIndices
-    #[pyo3(text_signature = "(self)")]
-    pub fn node_indexes(&self) -> NodeIndices {
-        self.node_indices()
+        // removing the temporary node removes all the temporary edges with it
+        let tmp_node = self.graph.add_node(py.None());
+        self.graph.reserve_exact_edges(edge_endpoints.nrows());
+        let mut edge_weights = edge_weights.iter();
+        for endpoints in edge_endpoints.rows() {
+            if endpoints[0] == u32::MAX {
+                self.graph.add_edge(tmp_node, tmp_node, py.None());
+                continue;
+            }
+            let source = NodeIndex::new(endpoints[0] as usize);
+            let target = NodeIndex::new(endpoints[1] as usize);
+            if source == tmp_node
+                || target == tmp_node
+                || !self.graph.contains_node(source)
+                || !self.graph.contains_node(target)
+            {
+                return Err(PyValueError::new_err(
+                    "Pickled edge endpoints refer to a node not in the graph",
+                ));
+            }
+            // the number of edge weights was checked against the packed edges
+            let weight = edge_weights.next().unwrap();
+            self.graph.add_edge(source, target, weight.unbind());
+        }
+        self.graph.remove_node(tmp_node);
+        Ok(())
     }
 
-    /// Check if the node exists in the graph.
-    ///
-    /// :param int node: The index of the node
-    ///
-    /// :returns: ``True`` if the node exists, ``False`` otherwise
-    /// :rtype: bool
-    #[pyo3(text_signature = "(self, node, /)")]
-    pub fn has_node(&self, node: usize) -> bool {
-        let index = NodeIndex::new(node);
-        self.graph.contains_node(index)
+    /// Reserve capacity ahead of extending the graph from an edge list. The
+    /// edge capacity is only reserved if the input exposes a length.
+    fn reserve_for_edge_list(
+        &mut self,
+        edge_list: &Bound<'_, PyAny>,
+        node_count_hint: Option<usize>,
+    ) {
+        if let Some(node_count_hint) = node_count_hint {
+            self.graph.reserve_nodes(
+                node_count_hint
+                    .saturating_sub(self.graph.node_count())
+                    .min(MAX_UNCHECKED_RESERVATION),
+            );
+        }
+        if let Ok(edge_count) = edge_list.len() {
+            self.graph
+                .reserve_edges(edge_count.min(MAX_UNCHECKED_RESERVATION));
+        }
     }
 
-    /// Check if there is any undirected edge between ``node_a`` and ``node_b``.
-    ///
-    /// :param int node_a: The index of the first node
-    /// :param int node_b: The index of the second node
-    ///
-    /// :returns: ``True`` if the edge exists, ``False`` otherwise
-    /// :rtype: bool
-    #[pyo3(text_signature = "(self, node_a, node_b, /)")]
-    pub fn has_edge(&self, node_a: usize, node_b: usize) -> bool {
-        let index_a = NodeIndex::new(node_a);
-        let index_b = NodeIndex::new(node_b);
-        self.graph.find_edge(index_a, index_b).is_some()
+    /// Add an edge, merging it into an existing edge between the same nodes
+    /// with ``weight_combo_fn`` if there is one. Without ``weight_combo_fn``
+    /// parallel edges are added to multigraphs and otherwise the weight of the
+    /// existing edge is kept.
+    fn add_or_merge_edge(
+        &mut self,
+        py: Python,
+        u: NodeIndex,
+        v: NodeIndex,
+        weight: PyObject,
+        weight_combo_fn: Option<&PyObject>,
+    ) -> PyResult<()> {
+        let existing = if self.multigraph && weight_combo_fn.is_none() {
+            None
+        } else {
+            self.graph.find_edge(u, v)
+        };
+        match existing {
+            Some(edge) => {
+                if let Some(weight_combo_fn) = weight_combo_fn {
+                    let merged = weight_combo_fn.call1(py, (&self.graph[edge], weight))?;
+                    self.graph[edge] = merged;
+                }
+            }
+            None => {
+                self.graph.add_edge(u, v, weight);
+                self.track_edge(u, v);
+            }
+        }
+        Ok(())
     }
 
-    ///  Return the edge data for the edge between 2 nodes.
-    ///
-    ///  Note if there are multiple edges between the nodes only one will be
-    ///  returned. To get all edge data objects use
-    ///  :meth:`~rustworkx.PyGraph.get_all_edge_data`
-    ///
-    /// :param int node_a: The index of the first node
-    /// :param int node_b: The index of the second node
-    ///
-    /// :returns: The data object set for the edge
-    /// :rtype: S
-    /// :raises NoEdgeBetweenNodes: when there is no edge between the provided
-    ///     nodes
-    #[pyo3(text_signature = "(self, node_a, node_b, /)")]
-    pub fn get_edge_data(&self, node_a: usize, node_b: usize) -> PyResult<&PyObject> {
-        let index_a = NodeIndex::new(node_a);
-        let index_b = NodeIndex::new(node_b);
-        let edge_index = match self.graph.find_edge(index_a, index_b) {
-            Some(edge_index) => edge_index,
-            None => return Err(NoEdgeBetweenNodes::new_err("No edge found between nodes")),
+    /// Substitute a set of nodes with a single new node, see
+    /// :meth:`~rustworkx.PyGraph.contract_nodes`.
+    fn contract_node_list(
+        &mut self,
+        py: Python,
+        nodes: Vec<usize>,
+        obj: PyObject,
+        weight_combo_fn: Option<PyObject>,
+        keep_internal_edges_as_self_loops: bool,
+    ) -> RxPyResult<usize> {
+        let internal_weights: Vec<PyObject> = if keep_internal_edges_as_self_loops {
+            let node_set: HashSet<NodeIndex> = nodes
+                .iter()
+                .map(|node| NodeIndex::new(*node))
+                .filter(|node| self.graph.contains_node(*node))
+                .collect();
+            let mut internal_edges: Vec<EdgeIndex> = node_set
+                .iter()
+                .flat_map(|node| self.graph.edges(*node))
+                .filter(|edge| node_set.contains(&edge.target()))
+                .map(|edge| edge.id())
+                .collect();
+            internal_edges.sort_unstable();
+            internal_edges.dedup();
+            internal_edges
+                .into_iter()
+                .map(|edge| self.graph[edge].clone_ref(py))
+                .collect()
+        } else {
+            Vec::new()
         };
-
-        let data = self.graph.edge_weight(edge_index).unwrap();
-        Ok(data)
+        self.connectivity = None;
+        let nodes = nodes.into_iter().map(|i| NodeIndex::new(i));
+        let res = match (&weight_combo_fn, &self.multigraph) {
+            (Some(user_callback), _) => {
+                self.graph
+                    .contract_nodes_simple(nodes, obj, |w1, w2| user_callback.call1(py, (w1, w2)))?
+            }
+            (None, false) => {
+                // By default, just take first edge.
+                self.graph.contract_nodes_simple(nodes, obj, move |w1, _| {
+                    Ok::<_, PyErr>(w1.clone_ref(py))
+                })?
+            }
+            (None, true) => self.graph.contract_nodes(nodes, obj),
+        };
+        for weight in internal_weights {
+            self.add_or_merge_edge(py, res, res, weight, weight_combo_fn.as_ref())?;
+        }
+        Ok(res.index())
     }
 
-    /// Return the list of edge indices incident to a provided node
-    ///
-    /// You can later retrieve the data payload of this edge with
-    /// :meth:`~rustworkx.PyGraph.get_edge_data_by_index` or its
-    /// endpoints with :meth:`~rustworkx.PyGraph.get_edge_endpoints_by_index`.
-    ///
-    /// :param int node: The node index to get incident edges from. If
-    ///     this node index is not present in the graph this method will
-    ///     return an empty list and not error.
-    ///
-    /// :returns: A list of the edge indices incident to a node in the graph
-    /// :rtype: EdgeIndices
-    #[pyo3(text_signature = "(self, node, /)")]
-    pub fn incident_edges(&self, node: usize) -> EdgeIndices {
-        EdgeIndices {
-            edges: self
-                .graph
-                .edges(NodeIndex::new(node))
-                .map(|e| e.id().index())
-                .collect(),
+    /// Parse the contents of an edge list, see
+    /// :meth:`~rustworkx.PyGraph.read_edge_list` for the format.
+    fn parse_edge_list<R: BufRead>(
+        py: Python,
+        reader: R,
+        comment: Option<String>,
+        deliminator: Option<String>,
+        labels: bool,
+        multigraph: bool,
+        weight_fn: Option<PyObject>,
+    ) -> PyResult<PyGraph> {
+        let mut out_graph = PyGraph {
+            graph: StablePyGraph::<Undirected>::default(),
+            node_removed: false,
+            multigraph,
+            attrs: py.None(),
+            connectivity: None,
+        };
+        let mut label_map: HashMap<String, usize> = HashMap::new();
+        for (line_index, line_raw) in reader.lines().enumerate() {
+            let line_number = line_index + 1;
+            let line = line_raw?;
+            let skip = match &comment {
+                Some(comm) => line.trim().is_empty() || line.trim().starts_with(comm),
+                None => line.trim().is_empty(),
+            };
+            if skip {
+                continue;
+            }
+            let line_no_comments = match &comment {
+                Some(comm) => line
+                    .find(comm)
+                    .map(|idx| &line[..idx])
+                    .unwrap_or(&line)
+                    .trim()
+                    .to_string(),
+                None => line,
+            };
+            let pieces: Vec<&str> = match &deliminator {
+                Some(del) => line_no_comments.split(del).collect(),
+                None => line_no_comments.split_whitespace().collect(),
+            };
+            if pieces.len() < 2 {
+                return Err(PyValueError::new_err(format!(
+                    "Line {line_number} of the edge list has fewer than 2 fields: {line_no_comments:?}"
+                )));
+            }
+            let parse_node = |field: &str, position: &str| -> PyResult<usize> {
+                field.parse::<usize>().map_err(|err| {
+                    PyValueError::new_err(format!(
+                        "Invalid {position} node index {field:?} on line {line_number} of the edge list: {err}"
+                    ))
+                })
+            };
+            let src: usize;
+            let target: usize;
+            if labels {
+                let src_str = pieces[0];
+                let target_str = pieces[1];
+                src = match label_map.get(src_str) {
+                    Some(index) => *index,
+                    None => {
+                        let index = out_graph.graph.add_node(src_str.into_py_any(py)?).index();
+                        label_map.insert(src_str.to_string(), index);
+                        index
+                    }
+                };
+                target = match label_map.get(target_str) {
+                    Some(index) => *index,
+                    None => {
+                        let index = out_graph
+                            .graph
+                            .add_node(target_str.into_py_any(py)?)
+                            .index();
+                        label_map.insert(target_str.to_string(), index);
+                        index
+                    }
+                };
+            } else {
+                src = parse_node(pieces[0], "source")?;
+                target = parse_node(pieces[1], "target")?;
+                let max_index = cmp::max(src, target);
+                // Add nodes to graph
+                while max_index >= out_graph.graph.node_count() {
+                    out_graph.graph.add_node(py.None());
+                }
+            }
+            // Add edges tp graph
+            let weight = if pieces.len() > 2 {
+                let weight_str = match &deliminator {
+                    Some(del) => pieces[2..].join(del),
+                    None if pieces[2].starts_with('"') => {
+                        skip_whitespace_fields(&line_no_comments, 2).to_string()
+                    }
+                    None => pieces[2..].join(&' '.to_string()),
+                };
+                let weight_str = if weight_str.starts_with('"') {
+                    unquote_edge_list_weight(&weight_str).ok_or_else(|| {
+                        PyValueError::new_err(format!(
+                            "Invalid quoted weight {weight_str:?} on line {line_number} of the edge list"
+                        ))
+                    })?
+                } else {
+                    weight_str
+                };
+                match &weight_fn {
+                    Some(weight_fn) => weight_fn.call1(py, (weight_str,))?,
+                    None => PyString::new(py, &weight_str).into_any().unbind(),
+                }
+            } else {
+                py.None()
+            };
+            out_graph._add_edge(NodeIndex::new(src), NodeIndex::new(target), weight);
+        }
+        Ok(out_graph)
+    }
+
+    /// Write the edges of the graph as an edge list, see
+    /// :meth:`~rustworkx.PyGraph.write_edge_list` for the format.
+    fn write_edge_list_to<W: Write>(
+        &self,
+        py: Python,
+        writer: &mut W,
+        deliminator: Option<char>,
+        weight_fn: &Option<PyObject>,
+    ) -> PyResult<()> {
+        let delim = match deliminator {
+            Some(delim) => delim.to_string(),
+            None => " ".to_string(),
+        };
+
+        let weight_fn = match weight_fn {
+            Some(weight_fn) => weight_fn,
+            None => {
+                // Without a weight_fn no weight is written, so the payloads
+                // don't need to be touched at all
+                for edge in self.graph.edge_references() {
+                    writeln!(
+                        writer,
+                        "{}{}{}",
+                        edge.source().index(),
+                        delim,
+                        edge.target().index()
+                    )?;
+                }
+                return Ok(());
+            }
+        };
+        for edge in self.graph.edge_references() {
+            write!(
+                writer,
+                "{}{}{}",
+                edge.source().index(),
+                delim,
+                edge.target().index()
+            )?;
+            let weight: Option<String> = weight_fn.call1(py, (edge.weight(),))?.extract(py)?;
+            match weight {
+                Some(weight) => {
+                    writeln!(writer, "{delim}{}", quote_edge_list_weight(&weight, &delim))
+                }
+                None => writeln!(writer),
+            }?;
+        }
+        Ok(())
+    }
+
+    /// Index aligned structural comparison used by ``__eq__``. Nodes are
+    /// compared by index and payload, edges as a multiset of endpoints and
+    /// payloads.
+    fn structurally_equal(&self, py: Python, other: &PyGraph) -> PyResult<bool> {
+        let payload_eq = |a: &PyObject, b: &PyObject| -> PyResult<bool> {
+            a.bind(py).rich_compare(b, CompareOp::Eq)?.is_truthy()
+        };
+        if self.multigraph != other.multigraph
+            || self.graph.node_count() != other.graph.node_count()
+            || self.graph.edge_count() != other.graph.edge_count()
+        {
+            return Ok(false);
+        }
+        for (node, weight) in self.graph.node_references() {
+            match other.graph.node_weight(node) {
+                Some(other_weight) if payload_eq(weight, other_weight)? => (),
+                _ => return Ok(false),
+            }
+        }
+        let endpoints = |a: NodeIndex, b: NodeIndex| (a.min(b), a.max(b));
+        let mut edges: HashMap<(NodeIndex, NodeIndex), Vec<&PyObject>> =
+            HashMap::with_capacity(self.graph.edge_count());
+        for edge in self.graph.edge_references() {
+            edges
+                .entry(endpoints(edge.source(), edge.target()))
+                .or_default()
+                .push(edge.weight());
+        }
+        for edge in other.graph.edge_references() {
+            let weights = match edges.get_mut(&endpoints(edge.source(), edge.target())) {
+                Some(weights) => weights,
+                None => return Ok(false),
+            };
+            let mut position = None;
+            for (i, weight) in weights.iter().enumerate() {
+                if payload_eq(weight, edge.weight())? {
+                    position = Some(i);
+                    break;
+                }
+            }
+            match position {
+                Some(i) => {
+                    weights.swap_remove(i);
+                }
+                None => return Ok(false),
+            }
+        }
+        Ok(true)
+    }
+}
+
+#[pymethods]
+impl PyGraph {
+    #[new]
+    #[pyo3(signature=(multigraph=true, attrs=None, *, node_count_hint=None, edge_count_hint=None))]
+    fn new(
+        py: Python,
+        multigraph: bool,
+        attrs: Option<PyObject>,
+        node_count_hint: Option<usize>,
+        edge_count_hint: Option<usize>,
+    ) -> Self {
+        PyGraph {
+            graph: StablePyGraph::<Undirected>::with_capacity(
+                node_count_hint.unwrap_or_default(),
+                edge_count_hint.unwrap_or_default(),
+            ),
+            node_removed: false,
+            multigraph,
+            attrs: attrs.unwrap_or_else(|| py.None()),
+            connectivity: None,
+        }
+    }
+
+    fn __getnewargs_ex__<'py>(
+        &self,
+        py: Python<'py>,
+    ) -> PyResult<(Bound<'py, PyTuple>, Bound<'py, PyDict>)> {
+        Ok((
+            (self.multigraph, self.attrs.clone_ref(py)).into_pyobject(py)?,
+            [
+                ("node_count_hint", self.graph.node_bound()),
+                ("edge_count_hint", self.graph.edge_bound()),
+            ]
+            .into_py_dict(py)?,
+        ))
+    }
+
+    fn __getstate__(slf: &Bound<'_, Self>) -> PyResult<PyObject> {
+        let py = slf.py();
+        let this = slf.borrow();
+        let mut node_indices: Vec<u32> = Vec::with_capacity(this.graph.node_count());
+        let mut node_weights: Vec<&PyObject> = Vec::with_capacity(this.graph.node_count());
+        for (node_idx, node_data) in this.graph.node_references() {
+            node_indices.push(node_idx.index() as u32);
+            node_weights.push(node_data);
+        }
+
+        // edge endpoints are packed into a single (edge_bound, 2) array, deleted
+        // edges are marked with u32::MAX so the holes can be re-created on load
+        let edge_bound = this.graph.edge_bound();
+        let mut edge_endpoints = Array2::<u32>::from_elem((edge_bound, 2), u32::MAX);
+        let mut edge_weights: Vec<&PyObject> = Vec::with_capacity(this.graph.edge_count());
+        for edge in this.graph.edge_references() {
+            let i = edge.id().index();
+            edge_endpoints[[i, 0]] = edge.source().index() as u32;
+            edge_endpoints[[i, 1]] = edge.target().index() as u32;
+            edge_weights.push(edge.weight());
+        }
+
+        let out_dict = PyDict::new(py);
+        out_dict.set_item("node_indices", node_indices.into_pyarray(py))?;
+        out_dict.set_item("node_weights", PyList::new(py, node_weights)?)?;
+        out_dict.set_item("edge_endpoints", edge_endpoints.into_pyarray(py))?;
+        out_dict.set_item("edge_weights", PyList::new(py, edge_weights)?)?;
+        out_dict.set_item("nodes_removed", this.node_removed)?;
+        // instances of python subclasses carry their own attributes
+        if let Ok(instance_dict) = slf.getattr("__dict__") {
+            out_dict.set_item("__dict__", instance_dict)?;
+        }
+        Ok(out_dict.into())
+    }
+
+    fn __setstate__(slf: &Bound<'_, Self>, state: PyObject) -> PyResult<()> {
+        let py = slf.py();
+        let dict_state = state.downcast_bound::<PyDict>(py)?;
+        if let Some(instance_dict) = dict_state.get_item("__dict__")? {
+            slf.getattr("__dict__")?
+                .call_method1("update", (instance_dict,))?;
+        }
+        let mut this = slf.borrow_mut();
+        this.connectivity = None;
+        if let Some(node_indices) = dict_state.get_item("node_indices")? {
+            return this.set_packed_state(py, dict_state, &node_indices);
+        }
+        // state pickled by older versions stores a list of tuples per node and edge
+        let binding = dict_state.get_item("nodes")?.unwrap();
+        let nodes_lst = binding.downcast::<PyList>()?;
+        let binding = dict_state.get_item("edges")?.unwrap();
+        let edges_lst = binding.downcast::<PyList>()?;
+
+        this.node_removed = dict_state
+            .get_item("nodes_removed")?
+            .unwrap()
+            .downcast::<PyBool>()?
+            .extract()?;
+        // graph is empty, stop early
+        if nodes_lst.is_empty() {
+            return Ok(());
+        }
+
+        if !this.node_removed {
+            for item in nodes_lst.iter() {
+                let node_w = item
+                    .downcast::<PyTuple>()
+                    .unwrap()
+                    .get_item(1)
+                    .unwrap()
+                    .extract()
+                    .unwrap();
+                this.graph.add_node(node_w);
+            }
+        } else if nodes_lst.len() == 1 {
+            // graph has only one node, handle logic here to save one if in the loop later
+            let binding = nodes_lst.get_item(0).unwrap();
+            let item = binding.downcast::<PyTuple>().unwrap();
+            let node_idx: usize = item.get_item(0).unwrap().extract().unwrap();
+            let node_w = item.get_item(1).unwrap().extract().unwrap();
+
+            for _i in 0..node_idx {
+                this.graph.add_node(py.None());
+            }
+            this.graph.add_node(node_w);
+            for i in 0..node_idx {
+                this.graph.remove_node(NodeIndex::new(i));
+            }
+        } else {
+            let binding = nodes_lst.get_item(nodes_lst.len() - 1).unwrap();
+            let last_item = binding.downcast::<PyTuple>().unwrap();
+
+            // list of temporary nodes that will be removed later to re-create holes
+            let node_bound_1: usize = last_item.get_item(0).unwrap().extract().unwrap();
+            let mut tmp_nodes: Vec<NodeIndex> =
+                Vec::with_capacity(node_bound_1 + 1 - nodes_lst.len());
+
+            for item in nodes_lst {
+                let item = item.downcast::<PyTuple>().unwrap();
+                let next_index: usize = item.get_item(0).unwrap().extract().unwrap();
+                let weight: PyObject = item.get_item(1).unwrap().extract().unwrap();
+                while next_index > this.graph.node_bound() {
+                    // node does not exist
+                    let tmp_node = this.graph.add_node(py.None());
+                    tmp_nodes.push(tmp_node);
+                }
+                // add node to the graph, and update the next available node index
+                this.graph.add_node(weight);
+            }
+            // Remove any temporary nodes we added
+            for tmp_node in tmp_nodes {
+                this.graph.remove_node(tmp_node);
+            }
+        }
+
+        // to ensure O(1) on edge deletion, use a temporary node to store missing edges
+        let tmp_node = this.graph.add_node(py.None());
+
+        for item in edges_lst {
+            if item.is_none() {
+                // add a temporary edge that will be deleted later to re-create the hole
+                this.graph.add_edge(tmp_node, tmp_node, py.None());
+            } else {
+                let triple = item.downcast::<PyTuple>().unwrap();
+                let edge_p: usize = triple.get_item(0).unwrap().extract().unwrap();
+                let edge_c: usize = triple.get_item(1).unwrap().extract().unwrap();
+                let edge_w = triple.get_item(2).unwrap().extract().unwrap();
+                this.graph
+                    .add_edge(NodeIndex::new(edge_p), NodeIndex::new(edge_c), edge_w);
+            }
+        }
+
+        // remove the temporary node will remove all deleted edges in bulk,
+        // the cost is equal to the number of edges
+        this.graph.remove_node(tmp_node);
+
+        Ok(())
+    }
+
+    /// Whether the graph is a multigraph (allows multiple edges between
+    /// nodes) or not
+    ///
+    /// If set to ``False`` multiple edges between nodes are not allowed and
+    /// calls that would add a parallel edge will instead update the existing
+    /// edge
+    #[getter]
+    fn multigraph(&self) -> bool {
+        self.multigraph
+    }
+
+    /// Detect if the graph has parallel edges or not
+    ///
+    /// :returns: ``True`` if the graph has parallel edges, ``False`` otherwise
+    /// :rtype: bool
+    #[pyo3(text_signature = "(self)")]
+    fn has_parallel_edges(&self) -> bool {
+        if !self.multigraph {
+            return false;
+        }
+        self.graph.has_parallel_edges()
+    }
+
+    /// Detect if there are parallel edges between two nodes
+    ///
+    /// This stops as soon as a second edge between the nodes is found, so
+    /// it is cheaper than counting the edges between them.
+    ///
+    /// :param int node_a: The index of the first node
+    /// :param int node_b: The index of the second node
+    ///
+    /// :returns: ``True`` if there is more than one edge between the nodes,
+    ///     ``False`` otherwise
+    /// :rtype: bool
+    #[pyo3(text_signature = "(self, node_a, node_b, /)")]
+    fn has_parallel_edges_between(&self, node_a: usize, node_b: usize) -> bool {
+        if !self.multigraph {
+            return false;
+        }
+        self.graph
+            .edges_connecting(NodeIndex::new(node_a), NodeIndex::new(node_b))
+            .nth(1)
+            .is_some()
+    }
+
+    /// Clears all nodes and edges
+    #[pyo3(text_signature = "(self)")]
+    pub fn clear(&mut self) {
+        self.graph.clear();
+        self.node_removed = true;
+        self.connectivity = None;
+    }
+
+    /// Clears all edges, leaves nodes intact
+    #[pyo3(text_signature = "(self)")]
+    pub fn clear_edges(&mut self) {
+        self.graph.clear_edges();
+        self.connectivity = None;
+    }
+
+    /// Set the data payload of every node in the graph to ``value``
+    ///
+    /// The structure of the graph, the edge data and
+    /// :attr:`~rustworkx.PyGraph.attrs` are left untouched.
+    ///
+    /// :param value: The new data payload for all the nodes. Every node
+    ///     references the same object, so if it is mutable updating it
+    ///     updates the payload of every node. Defaults to ``None``.
+    #[pyo3(signature=(value=None), text_signature = "(self, /, value=None)")]
+    pub fn clear_node_data(&mut self, py: Python, value: Option<PyObject>) {
+        let value = value.unwrap_or_else(|| py.None());
+        for weight in self.graph.node_weights_mut() {
+            *weight = value.clone_ref(py);
+        }
+    }
+
+    /// Set the data payload of every edge in the graph to ``value``
+    ///
+    /// The structure of the graph, the node data and
+    /// :attr:`~rustworkx.PyGraph.attrs` are left untouched.
+    ///
+    /// :param value: The new data payload for all the edges. Every edge
+    ///     references the same object, so if it is mutable updating it
+    ///     updates the payload of every edge. Defaults to ``None``.
+    #[pyo3(signature=(value=None), text_signature = "(self, /, value=None)")]
+    pub fn clear_edge_data(&mut self, py: Python, value: Option<PyObject>) {
+        let value = value.unwrap_or_else(|| py.None());
+        for weight in self.graph.edge_weights_mut() {
+            *weight = value.clone_ref(py);
+        }
+    }
+
+    /// Clear the edges between a set of nodes, leaving the nodes intact
+    ///
+    /// :param list[int] nodes: The indices of the nodes to clear the edges of.
+    ///     Node indices that are not present in the graph are ignored.
+    /// :param bool both_endpoints: If set to ``True`` (the default) only the
+    ///     edges with both endpoints in ``nodes`` are removed, which are the
+    ///     edges of the subgraph induced by ``nodes``. If set to ``False``
+    ///     every edge with at least one endpoint in ``nodes`` is removed.
+    #[pyo3(
+        signature=(nodes, /, both_endpoints=true),
+        text_signature = "(self, nodes, /, both_endpoints=True)"
+    )]
+    pub fn clear_edges_of_nodes(&mut self, nodes: Vec<usize>, both_endpoints: bool) {
+        let node_filter = self.node_selection_filter(nodes.into_iter());
+        let edges: Vec<EdgeIndex> = self
+            .graph
+            .edge_references()
+            .filter(|edge| {
+                if both_endpoints {
+                    node_filter(edge.source()) && node_filter(edge.target())
+                } else {
+                    node_filter(edge.source()) || node_filter(edge.target())
+                }
+            })
+            .map(|edge| edge.id())
+            .collect();
+        if !edges.is_empty() {
+            self.connectivity = None;
+        }
+        for edge in edges {
+            self.graph.remove_edge(edge);
+        }
+    }
+
+    /// Compact the graph so that node indices are contiguous
+    ///
+    /// Removing nodes leaves holes in the index space of the graph, which
+    /// this method removes by rebuilding the graph with the node indices
+    /// ``0..len(graph)`` and the edge indices ``0..num_edges()``. The relative
+    /// order of nodes and of edges is preserved and their data payloads are
+    /// moved to the new indices by reference. After this any indices obtained
+    /// before calling this method are invalid and must be translated with the
+    /// returned mapping.
+    ///
+    /// :returns: A mapping of the old node indices to the new node indices.
+    ///     Nodes whose index didn't change are included too.
+    /// :rtype: NodeMap
+    #[pyo3(text_signature = "(self)")]
+    pub fn compact(&mut self, py: Python) -> NodeMap {
+        let (graph, node_map) = self.compacted_graph(py);
+        self.graph = graph;
+        self.node_removed = false;
+        self.connectivity = None;
+        NodeMap { node_map }
+    }
+
+    /// Return the line graph of the graph
+    ///
+    /// The line graph :math:`L(G)` has a node for every edge of the graph,
+    /// whose data payload is the data payload of the edge (by reference), and
+    /// an edge between 2 nodes if the corresponding edges share an endpoint.
+    /// The nodes of :math:`L(G)` are created in the order of the edge
+    /// indices of the graph and the edges of :math:`L(G)` have ``None`` as
+    /// their data payload.
+    ///
+    /// :math:`L(G)` is a simple graph (its :attr:`~rustworkx.PyGraph.multigraph`
+    /// attribute is ``False``): parallel edges of the graph, which share both
+    /// their endpoints, are connected by a single edge, and a self loop is
+    /// adjacent to every other edge incident to its node but not to itself.
+    ///
+    /// :returns: A tuple of the line graph and a dictionary mapping the node
+    ///     indices of the line graph to the corresponding edge indices of the
+    ///     graph
+    /// :rtype: tuple[PyGraph, dict[int, int]]
+    #[pyo3(text_signature = "(self)")]
+    pub fn line_graph(&self, py: Python) -> (PyGraph, DictMap<usize, usize>) {
+        let mut out_graph = StablePyGraph::<Undirected>::with_capacity(self.graph.edge_count(), 0);
+        let mut edge_to_node: HashMap<EdgeIndex, NodeIndex> =
+            HashMap::with_capacity(self.graph.edge_count());
+        let mut node_to_edge: DictMap<usize, usize> =
+            DictMap::with_capacity(self.graph.edge_count());
+        for edge in self.graph.edge_references() {
+            let node = out_graph.add_node(edge.weight().clone_ref(py));
+            edge_to_node.insert(edge.id(), node);
+            node_to_edge.insert(node.index(), edge.id().index());
+        }
+        let mut out_edges: HashSet<[NodeIndex; 2]> = HashSet::new();
+        for node in self.graph.node_indices() {
+            let mut incident: Vec<NodeIndex> = self
+                .graph
+                .edges(node)
+                .map(|edge| edge_to_node[&edge.id()])
+                .collect();
+            incident.sort_unstable();
+            incident.dedup();
+            for (i, node_a) in incident.iter().enumerate() {
+                for node_b in incident[i + 1..].iter() {
+                    if out_edges.insert([*node_a, *node_b]) {
+                        out_graph.add_edge(*node_a, *node_b, py.None());
+                    }
+                }
+            }
+        }
+        (
+            PyGraph {
+                graph: out_graph,
+                node_removed: false,
+                multigraph: false,
+                attrs: py.None(),
+                connectivity: None,
+            },
+            node_to_edge,
+        )
+    }
+
+    /// Return the complement of the graph
+    ///
+    /// The complement has the same nodes as the graph, with the same indices
+    /// and node data payloads (by reference), and an edge between every pair
+    /// of distinct nodes that are not adjacent in the graph. Self loops are
+    /// never created and the data payload of every edge is ``None``. The
+    /// output has the same :attr:`~rustworkx.PyGraph.multigraph` flag and
+    /// :attr:`~rustworkx.PyGraph.attrs` (by reference) as the graph.
+    ///
+    /// The complement is only well defined for simple graphs so this raises
+    /// if the graph has parallel edges or self loops. Unlike
+    /// :func:`~rustworkx.complement` each pair of nodes is only visited
+    /// once, with a time complexity of :math:`\mathcal O(n^2)` for a graph
+    /// with :math:`n` nodes.
+    ///
+    /// :returns: The complement of the graph
+    /// :rtype: PyGraph
+    /// :raises ValueError: If the graph has parallel edges or self loops
+    #[pyo3(text_signature = "(self)")]
+    pub fn complement(&self, py: Python) -> PyResult<PyGraph> {
+        if self
+            .graph
+            .edge_references()
+            .any(|edge| edge.source() == edge.target())
+        {
+            return Err(PyValueError::new_err(
+                "The complement is only defined for graphs without self loops",
+            ));
+        }
+        if self.graph.has_parallel_edges() {
+            return Err(PyValueError::new_err(
+                "The complement is only defined for graphs without parallel edges",
+            ));
+        }
+        let mut out = self.clone();
+        out.graph.clear_edges();
+        let nodes: Vec<NodeIndex> = self.graph.node_indices().collect();
+        for (i, node_a) in nodes.iter().enumerate() {
+            let neighbors: HashSet<NodeIndex> = self.graph.neighbors(*node_a).collect();
+            for node_b in nodes[i + 1..].iter() {
+                if !neighbors.contains(node_b) {
+                    out.graph.add_edge(*node_a, *node_b, py.None());
+                }
+            }
+        }
+        Ok(out)
+    }
+
+    /// Return a simple graph copy of the graph
+    ///
+    /// The returned graph has the same nodes as the graph, with the same
+    /// indices and node data payloads (by reference), and at most one edge
+    /// between every pair of nodes. Parallel edges are collapsed into a
+    /// single edge and self loops are dropped unless ``keep_self_loops`` is
+    /// set. The output's :attr:`~rustworkx.PyGraph.multigraph` attribute is
+    /// ``False`` and its :attr:`~rustworkx.PyGraph.attrs` is the same object
+    /// as the graph's. Edges are added in the order of the edge indices of
+    /// the graph, so edge indices are not preserved.
+    ///
+    /// :param weight_combo_fn: An optional python callable that will take
+    ///     the weight of the edge already in the output graph and the weight
+    ///     of a parallel edge and return the weight to use for the combined
+    ///     edge. Parallel edges are combined in the order of their edge
+    ///     indices. If not specified the weight of the parallel edge with the
+    ///     lowest index is kept.
+    /// :param bool keep_self_loops: If set to ``True`` self loops are kept
+    ///     (and parallel self loops collapsed like any other parallel edges).
+    ///     By default this is ``False``.
+    ///
+    /// :returns: A simple graph with the nodes and collapsed edges of the
+    ///     graph
+    /// :rtype: PyGraph
+    #[pyo3(
+        signature=(weight_combo_fn=None, keep_self_loops=false),
+        text_signature = "(self, /, weight_combo_fn=None, keep_self_loops=False)"
+    )]
+    pub fn to_simple_graph(
+        &self,
+        py: Python,
+        weight_combo_fn: Option<PyObject>,
+        keep_self_loops: bool,
+    ) -> PyResult<PyGraph> {
+        let mut out = self.clone();
+        out.graph.clear_edges();
+        out.multigraph = false;
+        for edge in self.graph.edge_references() {
+            if !keep_self_loops && edge.source() == edge.target() {
+                continue;
+            }
+            out.add_or_merge_edge(
+                py,
+                edge.source(),
+                edge.target(),
+                edge.weight().clone_ref(py),
+                weight_combo_fn.as_ref(),
+            )?;
+        }
+        Ok(out)
+    }
+
+    /// Collapse the parallel edges of the graph in place
+    ///
+    /// For every pair of distinct nodes with more than one edge between
+    /// them, the edge with the lowest index is kept and the other edges are
+    /// removed. Self loops and edges without parallel edges are left
+    /// untouched.
+    ///
+    /// :param weight_combo_fn: An optional python callable that will take
+    ///     the weight of the kept edge and the weight of a parallel edge and
+    ///     return the new weight of the kept edge. Parallel edges are
+    ///     combined in the order of their edge indices. If not specified the
+    ///     weight of the kept edge is not changed. If the callable raises an
+    ///     exception the graph is left unmodified.
+    #[pyo3(
+        signature=(weight_combo_fn=None),
+        text_signature = "(self, /, weight_combo_fn=None)"
+    )]
+    pub fn merge_parallel_edges(
+        &mut self,
+        py: Python,
+        weight_combo_fn: Option<PyObject>,
+    ) -> PyResult<()> {
+        // maps each pair of nodes to its kept edge and the merged weight
+        let mut kept: HashMap<(NodeIndex, NodeIndex), (EdgeIndex, Option<PyObject>)> =
+            HashMap::new();
+        let mut removed: Vec<EdgeIndex> = Vec::new();
+        for edge in self.graph.edge_references() {
+            let (source, target) = (edge.source(), edge.target());
+            if source == target {
+                continue;
+            }
+            let key = (cmp::min(source, target), cmp::max(source, target));
+            match kept.get_mut(&key) {
+                Some((kept_edge, merged)) => {
+                    if let Some(weight_combo_fn) = &weight_combo_fn {
+                        let current = merged.as_ref().unwrap_or(&self.graph[*kept_edge]);
+                        *merged = Some(weight_combo_fn.call1(py, (current, edge.weight()))?);
+                    }
+                    removed.push(edge.id());
+                }
+                None => {
+                    kept.insert(key, (edge.id(), None));
+                }
+            }
+        }
+        for (edge, merged) in kept.into_values() {
+            if let Some(merged) = merged {
+                self.graph[edge] = merged;
+            }
+        }
+        for edge in removed {
+            self.graph.remove_edge(edge);
+        }
+        Ok(())
+    }
+
+    /// Return the number of nodes in the graph
+    #[pyo3(text_signature = "(self)")]
+    pub fn num_nodes(&self) -> usize {
+        self.graph.node_count()
+    }
+
+    /// Return the number of edges in the graph
+    ///
+    /// Every edge is counted once, including self loops and parallel edges.
+    /// Note that :meth:`~rustworkx.PyGraph.degree` counts a self loop twice,
+    /// so the sum of the degrees of all nodes is ``2 * num_edges()``.
+    #[pyo3(text_signature = "(self)")]
+    pub fn num_edges(&self) -> usize {
+        self.graph.edge_count()
+    }
+
+    /// Return the number of self loops in the graph
+    ///
+    /// A self loop is an edge whose two endpoints are the same node. Each
+    /// self loop is counted once here and by
+    /// :meth:`~rustworkx.PyGraph.num_edges`, but contributes ``2`` to the
+    /// :meth:`~rustworkx.PyGraph.degree` of its node.
+    ///
+    /// :returns: The number of self loops
+    /// :rtype: int
+    #[pyo3(text_signature = "(self)")]
+    pub fn num_self_loops(&self) -> usize {
+        self.graph
+            .edge_references()
+            .filter(|edge| edge.source() == edge.target())
+            .count()
+    }
+
+    /// Return the density of the graph
+    ///
+    /// The density of an undirected graph with :math:`n` nodes and :math:`m`
+    /// edges is :math:`\frac{2m}{n(n-1)}`, which is ``0.0`` for a graph with
+    /// no edges and ``1.0`` for a complete simple graph. All edges count
+    /// towards :math:`m`, including parallel edges and self loops, so the
+    /// density of a multigraph or of a graph with self loops can be greater
+    /// than ``1.0``.
+    ///
+    /// :returns: The density of the graph, or ``0.0`` if the graph has fewer
+    ///     than two nodes
+    /// :rtype: float
+    #[pyo3(text_signature = "(self)")]
+    pub fn density(&self) -> f64 {
+        let num_nodes = self.graph.node_count();
+        if num_nodes < 2 {
+            return 0.0;
+        }
+        let num_nodes = num_nodes as f64;
+        2.0 * self.graph.edge_count() as f64 / (num_nodes * (num_nodes - 1.0))
+    }
+
+    /// Return a list of all edge data.
+    ///
+    /// To ease porting code from NetworkX, ``data=True`` returns the edges in
+    /// the shape of NetworkX's ``G.edges(data=True)`` instead. Use
+    /// :meth:`~rustworkx.PyGraph.edge_list` for the endpoints of the edges
+    /// without their data.
+    ///
+    /// :param bool data: If set to ``True`` return a list of
+    ///     ``(node_a, node_b, data)`` tuples instead of only the edge data
+    ///     objects. Defaults to ``False``.
+    ///
+    /// :returns: A list of all the edge data objects in the graph, or of
+    ///     ``(node_a, node_b, data)`` tuples if ``data`` is ``True``, in the
+    ///     order of their edge indices
+    /// :rtype: list[T] | list[tuple[int, int, T]]
+    #[pyo3(signature=(data=false), text_signature = "(self, data=False)")]
+    pub fn edges(&self, py: Python, data: bool) -> PyResult<PyObject> {
+        if data {
+            self.graph
+                .edge_references()
+                .map(|edge| (edge.source().index(), edge.target().index(), edge.weight()))
+                .collect::<Vec<_>>()
+                .into_py_any(py)
+        } else {
+            self.graph
+                .edge_weights()
+                .collect::<Vec<_>>()
+                .into_py_any(py)
+        }
+    }
+
+    /// Return a list of all edge indices.
+    ///
+    /// :returns: A list of all the edge indices in the graph
+    /// :rtype: EdgeIndices
+    #[pyo3(text_signature = "(self)")]
+    pub fn edge_indices(&self) -> EdgeIndices {
+        EdgeIndices {
+            edges: self.graph.edge_indices().map(|edge| edge.index()).collect(),
+        }
+    }
+
+    /// Return a lazy iterator over the edge indices of the graph.
+    ///
+    /// Unlike :meth:`~rustworkx.PyGraph.edge_indices` the indices are not
+    /// collected into a list up front; each one is looked up when it is
+    /// requested, so stopping early avoids scanning the whole graph. The
+    /// indices are yielded in ascending order. The iterator reads the graph
+    /// as it is at each step, so edges added or removed while iterating may
+    /// or may not be yielded.
+    ///
+    /// :returns: An iterator over the edge indices in the graph
+    /// :rtype: Iterator[int]
+    #[pyo3(text_signature = "(self)")]
+    pub fn iter_edge_indices(slf: PyRef<Self>) -> LazyEdgeIndices {
+        LazyEdgeIndices::new(slf.into())
+    }
+
+    /// Return the index of a node chosen uniformly at random
+    ///
+    /// :param int seed: An optional seed for the random number generator, to
+    ///     make the choice reproducible
+    ///
+    /// :returns: The index of a node in the graph
+    /// :rtype: int
+    /// :raises IndexError: If the graph has no nodes
+    #[pyo3(signature=(seed=None), text_signature = "(self, /, seed=None)")]
+    pub fn random_node(&self, seed: Option<u64>) -> PyResult<usize> {
+        if self.graph.node_count() == 0 {
+            return Err(PyIndexError::new_err(
+                "Cannot choose a node from an empty graph",
+            ));
+        }
+        Ok(sample_present_index(
+            seed,
+            self.graph.node_bound(),
+            self.graph.node_count(),
+            |index| self.graph.contains_node(NodeIndex::new(index)),
+            |n| self.graph.node_indices().nth(n).unwrap().index(),
+        ))
+    }
+
+    /// Return the index of an edge chosen uniformly at random
+    ///
+    /// :param int seed: An optional seed for the random number generator, to
+    ///     make the choice reproducible
+    ///
+    /// :returns: The index of an edge in the graph
+    /// :rtype: int
+    /// :raises IndexError: If the graph has no edges
+    #[pyo3(signature=(seed=None), text_signature = "(self, /, seed=None)")]
+    pub fn random_edge(&self, seed: Option<u64>) -> PyResult<usize> {
+        if self.graph.edge_count() == 0 {
+            return Err(PyIndexError::new_err(
+                "Cannot choose an edge from a graph without edges",
+            ));
+        }
+        Ok(sample_present_index(
+            seed,
+            self.graph.edge_bound(),
+            self.graph.edge_count(),
+            |index| self.graph.edge_weight(EdgeIndex::new(index)).is_some(),
+            |n| self.graph.edge_indices().nth(n).unwrap().index(),
+        ))
+    }
+
+    /// Return a list of indices of all edges between specified nodes
+    ///
+    /// As the graph is undirected the order of the nodes doesn't matter, an
+    /// edge added as ``(node_b, node_a)`` is returned for both
+    /// ``(node_a, node_b)`` and ``(node_b, node_a)``.
+    ///
+    /// :param int node_a: The index of the first node
+    /// :param int node_b: The index of the second node
+    ///
+    /// :returns: A list of all the edge indices connecting the specified start and end node
+    /// :rtype: EdgeIndices
+    pub fn edge_indices_from_endpoints(&self, node_a: usize, node_b: usize) -> EdgeIndices {
+        let node_a_index = NodeIndex::new(node_a);
+        let node_b_index = NodeIndex::new(node_b);
+
+        EdgeIndices {
+            edges: self
+                .graph
+                .edges_directed(node_a_index, petgraph::Direction::Outgoing)
+                .filter(|edge| edge.target() == node_b_index)
+                .map(|edge| edge.id().index())
+                .collect(),
+        }
+    }
+
+    /// Return a list of all node data.
+    ///
+    /// :param bool data: If set to ``True`` return a list of
+    ///     ``(node_index, data)`` tuples instead, matching the shape of
+    ///     NetworkX's ``G.nodes(data=True)``. Defaults to ``False``.
+    ///
+    /// :returns: A list of all the node data objects in the graph, or of
+    ///     ``(node_index, data)`` tuples if ``data`` is ``True``
+    /// :rtype: list[S] | list[tuple[int, S]]
+    #[pyo3(signature=(data=false), text_signature = "(self, data=False)")]
+    pub fn nodes(&self, py: Python, data: bool) -> PyResult<PyObject> {
+        if data {
+            self.graph
+                .node_references()
+                .map(|(node, weight)| (node.index(), weight))
+                .collect::<Vec<_>>()
+                .into_py_any(py)
+        } else {
+            self.graph
+                .node_weights()
+                .collect::<Vec<_>>()
+                .into_py_any(py)
+        }
+    }
+
+    /// Return a list of all node indices.
+    ///
+    /// :returns: A list of all the node indices in the graph
+    /// :rtype: NodeIndices
+    #[pyo3(text_signature = "(self)")]
+    pub fn node_indices(&self) -> NodeIndices {
+        NodeIndices {
+            nodes: self.graph.node_indices().map(|node| node.index()).collect(),
+        }
+    }
+
+    /// Return a lazy iterator over the node indices of the graph.
+    ///
+    /// Unlike :meth:`~rustworkx.PyGraph.node_indices` the indices are not
+    /// collected into a list up front; each one is looked up when it is
+    /// requested, so stopping early avoids scanning the whole graph. The
+    /// indices are yielded in ascending order. The iterator reads the graph
+    /// as it is at each step, so nodes added or removed while iterating may
+    /// or may not be yielded.
+    ///
+    /// :returns: An iterator over the node indices in the graph
+    /// :rtype: Iterator[int]
+    #[pyo3(text_signature = "(self)")]
+    pub fn iter_node_indices(slf: PyRef<Self>) -> LazyNodeIndices {
+        LazyNodeIndices::new(slf.into())
+    }
+
+    /// Return a list of all node indices.
+    ///
+    /// .. note::
+    ///
+    ///     This is identical to :meth:`.node_indices()`, which is the
+    ///     preferred method to get the node indices in the graph. This
+    ///     exists for backwards compatibility with earlier releases.
+    ///
+    /// :returns: A list of all the node indices in the graph
+    /// :rtype: NodeIndices
+    #[pyo3(text_signature = "(self)")]
+    pub fn node_indexes(&self) -> NodeIndices {
+        self.node_indices()
+    }
+
+    /// Return a list of all node indices and their data.
+    ///
+    /// This is equivalent to ``list(zip(graph.node_indices(), graph.nodes()))``
+    /// but only traverses the graph once.
+    ///
+    /// :returns: A list of tuples of the form ``(index, data)`` for every
+    ///     node in the graph, in ascending order of node index
+    /// :rtype: list[tuple[int, S]]
+    #[pyo3(text_signature = "(self)")]
+    pub fn node_items(&self) -> Vec<(usize, &PyObject)> {
+        self.graph
+            .node_references()
+            .map(|(node, weight)| (node.index(), weight))
+            .collect()
+    }
+
+    /// Check if the node exists in the graph.
+    ///
+    /// :param int node: The index of the node
+    ///
+    /// :returns: ``True`` if the node exists, ``False`` otherwise
+    /// :rtype: bool
+    #[pyo3(text_signature = "(self, node, /)")]
+    pub fn has_node(&self, node: usize) -> bool {
+        let index = NodeIndex::new(node);
+        self.graph.contains_node(index)
+    }
+
+    /// Check if there is any undirected edge between ``node_a`` and ``node_b``.
+    ///
+    /// :param int node_a: The index of the first node
+    /// :param int node_b: The index of the second node
+    ///
+    /// :returns: ``True`` if the edge exists, ``False`` otherwise
+    /// :rtype: bool
+    #[pyo3(text_signature = "(self, node_a, node_b, /)")]
+    pub fn has_edge(&self, node_a: usize, node_b: usize) -> bool {
+        let index_a = NodeIndex::new(node_a);
+        let index_b = NodeIndex::new(node_b);
+        self.graph.find_edge(index_a, index_b).is_some()
+    }
+
+    /// Check if an edge with the given index exists in the graph.
+    ///
+    /// :param int edge_index: The index of the edge
+    ///
+    /// :returns: ``True`` if the edge exists, ``False`` otherwise
+    /// :rtype: bool
+    #[pyo3(text_signature = "(self, edge_index, /)")]
+    pub fn has_edge_by_index(&self, edge_index: usize) -> bool {
+        self.graph.edge_weight(EdgeIndex::new(edge_index)).is_some()
+    }
+
+    /// Return the index of an edge between ``node_a`` and ``node_b``.
+    ///
+    /// Note if there are multiple edges between the nodes only the index of
+    /// one of them will be returned. To get the indices of all the edges use
+    /// :meth:`~rustworkx.PyGraph.edge_indices_from_endpoints`.
+    ///
+    /// :param int node_a: The index of the first node
+    /// :param int node_b: The index of the second node
+    ///
+    /// :returns: The index of an edge between the nodes or ``None`` if there
+    ///     is no edge between them
+    /// :rtype: int | None
+    #[pyo3(text_signature = "(self, node_a, node_b, /)")]
+    pub fn find_edge_index(&self, node_a: usize, node_b: usize) -> Option<usize> {
+        let index_a = NodeIndex::new(node_a);
+        let index_b = NodeIndex::new(node_b);
+        self.graph
+            .find_edge(index_a, index_b)
+            .map(|edge| edge.index())
+    }
+
+    ///  Return the edge data for the edge between 2 nodes.
+    ///
+    ///  Note if there are multiple edges between the nodes only one will be
+    ///  returned. To get all edge data objects use
+    ///  :meth:`~rustworkx.PyGraph.get_all_edge_data`
+    ///
+    /// :param int node_a: The index of the first node
+    /// :param int node_b: The index of the second node
+    /// :param default: An optional keyword-only value to return if there is
+    ///     no edge between the nodes (like :meth:`dict.get`), this can be
+    ///     any Python object including ``None``. If it is not specified
+    ///     :class:`~rustworkx.NoEdgeBetweenNodes` is raised instead.
+    ///
+    /// :returns: The data object set for the edge
+    /// :rtype: S
+    /// :raises NoEdgeBetweenNodes: when there is no edge between the provided
+    ///     nodes and ``default`` is not specified
+    #[pyo3(
+        signature = (node_a, node_b, /, *, default=OptionalArg::Unset),
+        text_signature = "(self, node_a, node_b, /, *, default=...)"
+    )]
+    pub fn get_edge_data(
+        &self,
+        py: Python,
+        node_a: usize,
+        node_b: usize,
+        default: OptionalArg,
+    ) -> PyResult<PyObject> {
+        let index_a = NodeIndex::new(node_a);
+        let index_b = NodeIndex::new(node_b);
+        match (self.graph.find_edge(index_a, index_b), default) {
+            (Some(edge_index), _) => Ok(self.graph.edge_weight(edge_index).unwrap().clone_ref(py)),
+            (None, OptionalArg::Set(default)) => Ok(default),
+            (None, OptionalArg::Unset) => {
+                Err(NoEdgeBetweenNodes::new_err("No edge found between nodes"))
+            }
+        }
+    }
+
+    /// Return the list of edge indices incident to a provided node
+    ///
+    /// You can later retrieve the data payload of this edge with
+    /// :meth:`~rustworkx.PyGraph.get_edge_data_by_index` or its
+    /// endpoints with :meth:`~rustworkx.PyGraph.get_edge_endpoints_by_index`.
+    ///
+    /// .. note::
+    ///     As there is no distinction between incoming and outgoing edges in
+    ///     an undirected graph, :meth:`~rustworkx.PyGraph.in_edge_indices`
+    ///     and :meth:`~rustworkx.PyGraph.out_edge_indices` are aliases of this
+    ///     method with ``include_self_loops=True``.
+    ///
+    /// :param int node: The node index to get incident edges from. If
+    ///     this node index is not present in the graph this method will
+    ///     return an empty list and not error.
+    /// :param bool include_self_loops: If set to ``False`` the indices of
+    ///     self loop edges on ``node`` are not included in the output. By
+    ///     default this is ``True``.
+    ///
+    /// :returns: A list of the edge indices incident to a node in the graph
+    /// :rtype: EdgeIndices
+    #[pyo3(
+        signature=(node, /, include_self_loops=true),
+        text_signature = "(self, node, /, include_self_loops=True)"
+    )]
+    pub fn incident_edges(&self, node: usize, include_self_loops: bool) -> EdgeIndices {
+        let node = NodeIndex::new(node);
+        EdgeIndices {
+            edges: self
+                .graph
+                .edges(node)
+                .filter(|e| include_self_loops || e.source() != e.target())
+                .map(|e| e.id().index())
+                .collect(),
+        }
+    }
+
+    /// Return the list of edge indices incident to a provided node.
+    ///
+    /// This method returns the indices of all edges connected to the provided
+    /// ``node``. In undirected graphs, all edges connected to the node are
+    /// returned as there is no distinction between incoming and outgoing edges,
+    /// so this is an alias of :meth:`~rustworkx.PyGraph.incident_edges`.
+    ///
+    /// :param int node: The node index to get incident edges from. If
+    ///     this node index is not present in the graph this method will
+    ///     return an empty list and not error.
+    ///
+    /// :returns: A list of the edge indices incident to the node
+    /// :rtype: EdgeIndices
+    #[pyo3(text_signature = "(self, node, /)")]
+    pub fn in_edge_indices(&self, node: usize) -> EdgeIndices {
+        self.incident_edges(node, true)
+    }
+
+    /// Return the list of edge indices incident to a provided node.
+    ///
+    /// This method returns the indices of all edges connected to the provided
+    /// ``node``. In undirected graphs, all edges connected to the node are
+    /// returned as there is no distinction between incoming and outgoing edges,
+    /// so this is an alias of :meth:`~rustworkx.PyGraph.incident_edges`.
+    ///
+    /// :param int node: The node index to get incident edges from. If
+    ///     this node index is not present in the graph this method will
+    ///     return an empty list and not error.
+    ///
+    /// :returns: A list of the edge indices incident to the node
+    /// :rtype: EdgeIndices
+    #[pyo3(text_signature = "(self, node, /)")]
+    pub fn out_edge_indices(&self, node: usize) -> EdgeIndices {
+        self.incident_edges(node, true)
+    }
+
+    /// Return the index map of edges incident to a provided node
+    ///
+    /// :param int node: The node index to get incident edges from. If
+    ///     this node index is not present in the graph this method will
+    ///     return an empty mapping and not error.
+    ///
+    /// :returns: A mapping of incident edge indices to the tuple
+    ///     ``(source, target, data)``. The source endpoint node index in
+    ///     this tuple will always be ``node`` to ensure you can easily
+    ///     identify that the neighbor node is the second element in the
+    ///     tuple for a given edge index.
+    /// :rtype: EdgeIndexMap
+    #[pyo3(text_signature = "(self, node, /)")]
+    pub fn incident_edge_index_map(&self, py: Python, node: usize) -> EdgeIndexMap {
+        let node_index = NodeIndex::new(node);
+        EdgeIndexMap {
+            edge_map: self
+                .graph
+                .edges_directed(node_index, petgraph::Direction::Outgoing)
+                .map(|edge| {
+                    (
+                        edge.id().index(),
+                        (
+                            edge.source().index(),
+                            edge.target().index(),
+                            edge.weight().clone_ref(py),
+                        ),
+                    )
+                })
+                .collect(),
+        }
+    }
+
+    /// Get the endpoint indices and edge data for all edges of a node.
+    ///
+    /// This will return a list of tuples with the parent index, the node index
+    /// and the edge data. This can be used to recreate add_edge() calls. As
+    /// :class:`~rustworkx.PyGraph` is undirected this will return all edges
+    /// with the second endpoint node index always being ``node``.
+    ///
+    /// :param int node: The index of the node to get the edges for
+    ///
+    /// :returns: A list of tuples of the form:
+    ///     ``(parent_index, node_index, edge_data)```
+    /// :rtype: WeightedEdgeList
+    #[pyo3(text_signature = "(self, node, /)")]
+    pub fn in_edges(&self, py: Python, node: usize) -> WeightedEdgeList {
+        let index = NodeIndex::new(node);
+        let dir = petgraph::Direction::Incoming;
+        let raw_edges = self.graph.edges_directed(index, dir);
+        let out_list: Vec<(usize, usize, PyObject)> = raw_edges
+            .map(|x| (x.source().index(), node, x.weight().clone_ref(py)))
+            .collect();
+        WeightedEdgeList { edges: out_list }
+    }
+
+    /// Get the endpoint indices and edge data for all edges of a node.
+    ///
+    /// This will return a list of tuples with the child index, the node index
+    /// and the edge data. This can be used to recreate add_edge() calls. As
+    /// :class:`~rustworkx.PyGraph` is undirected this will return all edges
+    /// with the first endpoint node index always being ``node``.
+    ///
+    /// :param int node: The index of the node to get the edges for
+    ///
+    /// :returns out_edges: A list of tuples of the form:
+    ///     ```(node_index, child_index, edge_data)```
+    /// :rtype: WeightedEdgeList
+    #[pyo3(text_signature = "(self, node, /)")]
+    pub fn out_edges(&self, py: Python, node: usize) -> WeightedEdgeList {
+        let index = NodeIndex::new(node);
+        let dir = petgraph::Direction::Outgoing;
+        let raw_edges = self.graph.edges_directed(index, dir);
+        let out_list: Vec<(usize, usize, PyObject)> = raw_edges
+            .map(|x| (node, x.target().index(), x.weight().clone_ref(py)))
+            .collect();
+        WeightedEdgeList { edges: out_list }
+    }
+
+    /// Get the endpoint indices, edge index and edge data for all edges of a
+    /// node.
+    ///
+    /// This is the same as :meth:`~rustworkx.PyGraph.in_edges` but also
+    /// includes the index of each edge, which identifies the exact edge
+    /// when there are parallel edges.
+    ///
+    /// :param int node: The index of the node to get the edges for
+    ///
+    /// :returns: A list of tuples of the form:
+    ///     ``(parent_index, node_index, edge_index, edge_data)``
+    /// :rtype: list[tuple[int, int, int, T]]
+    #[pyo3(text_signature = "(self, node, /)")]
+    pub fn in_edges_indexed(&self, node: usize) -> Vec<(usize, usize, usize, &PyObject)> {
+        self.graph
+            .edges_directed(NodeIndex::new(node), petgraph::Direction::Incoming)
+            .map(|edge| {
+                (
+                    edge.source().index(),
+                    node,
+                    edge.id().index(),
+                    edge.weight(),
+                )
+            })
+            .collect()
+    }
+
+    /// Get the endpoint indices, edge index and edge data for all edges of a
+    /// node.
+    ///
+    /// This is the same as :meth:`~rustworkx.PyGraph.out_edges` but also
+    /// includes the index of each edge, which identifies the exact edge
+    /// when there are parallel edges.
+    ///
+    /// :param int node: The index of the node to get the edges for
+    ///
+    /// :returns: A list of tuples of the form:
+    ///     ``(node_index, child_index, edge_index, edge_data)``
+    /// :rtype: list[tuple[int, int, int, T]]
+    #[pyo3(text_signature = "(self, node, /)")]
+    pub fn out_edges_indexed(&self, node: usize) -> Vec<(usize, usize, usize, &PyObject)> {
+        self.graph
+            .edges_directed(NodeIndex::new(node), petgraph::Direction::Outgoing)
+            .map(|edge| {
+                (
+                    node,
+                    edge.target().index(),
+                    edge.id().index(),
+                    edge.weight(),
+                )
+            })
+            .collect()
+    }
+
+    /// Return the edge data for the edge by its given index
+    ///
+    /// :param int edge_index: The edge index to get the data for
+    ///
+    /// :returns: The data object for the edge
+    /// :rtype: T
+    /// :raises IndexError: when there is no edge present with the provided
+    ///     index
+    #[pyo3(text_signature = "(self, edge_index, /)")]
+    pub fn get_edge_data_by_index(&self, edge_index: usize) -> PyResult<&PyObject> {
+        let data = match self.graph.edge_weight(EdgeIndex::new(edge_index)) {
+            Some(data) => data,
+            None => {
+                return Err(PyIndexError::new_err(format!(
+                    "Provided edge index {edge_index} is not present in the graph"
+                )));
+            }
+        };
+        Ok(data)
+    }
+
+    /// Return the edge endpoints for the edge by its given index
+    ///
+    /// :param int edge_index: The edge index to get the endpoints for
+    ///
+    /// :returns: The endpoint tuple for the edge
+    /// :rtype: tuple[int, int]
+    /// :raises IndexError: when there is no edge present with the provided
+    ///     index
+    #[pyo3(text_signature = "(self, edge_index, /)")]
+    pub fn get_edge_endpoints_by_index(&self, edge_index: usize) -> PyResult<(usize, usize)> {
+        let endpoints = match self.graph.edge_endpoints(EdgeIndex::new(edge_index)) {
+            Some(endpoints) => (endpoints.0.index(), endpoints.1.index()),
+            None => {
+                return Err(PyIndexError::new_err(format!(
+                    "Provided edge index {edge_index} is not present in the graph"
+                )));
+            }
+        };
+        Ok(endpoints)
+    }
+
+    /// Reverse the stored endpoint order of an edge
+    ///
+    /// As the graph is undirected this doesn't change the structure of the
+    /// graph, only the order in which the edge endpoints are reported by
+    /// methods such as :meth:`~rustworkx.PyGraph.edge_list` and
+    /// :meth:`~rustworkx.PyGraph.get_edge_endpoints_by_index`. The edge keeps
+    /// its index and payload.
+    ///
+    /// :param int edge_index: The index of the edge to reverse
+    ///
+    /// :raises IndexError: when there is no edge present with the provided
+    ///     index
+    #[pyo3(text_signature = "(self, edge_index, /)")]
+    pub fn reverse_edge(&mut self, edge_index: usize) -> PyResult<()> {
+        let index = EdgeIndex::new(edge_index);
+        match self.graph.edge_endpoints(index) {
+            Some((source, target)) => {
+                self.reverse_edge_endpoints(index, source, target);
+                Ok(())
+            }
+            None => Err(PyIndexError::new_err(format!(
+                "Provided edge index {edge_index} is not present in the graph"
+            ))),
+        }
+    }
+
+    /// Reorder the stored endpoints of every edge so the lower node index
+    /// comes first
+    ///
+    /// After calling this, every ``(source, target)`` tuple returned by
+    /// methods such as :meth:`~rustworkx.PyGraph.edge_list` and
+    /// :meth:`~rustworkx.PyGraph.weighted_edge_list` satisfies
+    /// ``source <= target``. Edge indices and payloads are unchanged.
+    #[pyo3(text_signature = "(self)")]
+    pub fn canonicalize_edges(&mut self) {
+        let reversed: Vec<(EdgeIndex, NodeIndex, NodeIndex)> = self
+            .graph
+            .edge_references()
+            .filter(|edge| edge.source() > edge.target())
+            .map(|edge| (edge.id(), edge.source(), edge.target()))
+            .collect();
+        for (index, source, target) in reversed {
+            self.reverse_edge_endpoints(index, source, target);
+        }
+    }
+
+    /// Update an edge's weight/payload in place
+    ///
+    /// If there are parallel edges in the graph only one edge will be updated.
+    /// if you need to update a specific edge or need to ensure all parallel
+    /// edges get updated you should use
+    /// :meth:`~rustworkx.PyGraph.update_edge_by_index` instead.
+    ///
+    /// :param int source: The index of the first node
+    /// :param int target: The index of the second node
+    ///
+    /// :raises NoEdgeBetweenNodes: When there is no edge between nodes
+    #[pyo3(text_signature = "(self, source, target, edge, /)")]
+    pub fn update_edge(&mut self, source: usize, target: usize, edge: PyObject) -> PyResult<()> {
+        let index_a = NodeIndex::new(source);
+        let index_b = NodeIndex::new(target);
+        let edge_index = match self.graph.find_edge(index_a, index_b) {
+            Some(edge_index) => edge_index,
+            None => return Err(NoEdgeBetweenNodes::new_err("No edge found between nodes")),
+        };
+        let data = self.graph.edge_weight_mut(edge_index).unwrap();
+        *data = edge;
+        Ok(())
+    }
+
+    /// Update an edge's weight/data payload in place by the edge index
+    ///
+    /// :param int edge_index: The index of the edge
+    /// :param T edge: The python object to attach to the edge
+    ///
+    /// :raises IndexError: when there is no edge present with the provided
+    ///     index
+    #[pyo3(text_signature = "(self, edge_index, edge, /)")]
+    pub fn update_edge_by_index(&mut self, edge_index: usize, edge: PyObject) -> PyResult<()> {
+        match self.graph.edge_weight_mut(EdgeIndex::new(edge_index)) {
+            Some(data) => *data = edge,
+            None => return Err(PyIndexError::new_err("No edge found for index")),
+        };
+        Ok(())
+    }
+
+    /// Update the weight/data payloads of many edges in place by edge index
+    ///
+    /// This is equivalent to calling
+    /// :meth:`~rustworkx.PyGraph.update_edge_by_index` for every update, but
+    /// applies all of them in a single call. Updates are applied in order, so
+    /// if an edge index is repeated the last payload for it is kept.
+    ///
+    /// :param iterable[tuple[int, T]] updates: An iterable of tuples of the
+    ///     form ``(edge_index, edge)`` with the index of an edge and the python
+    ///     object to attach to it
+    /// :param bool ignore_missing: If set to ``True`` updates for edge indices
+    ///     that are not present in the graph are skipped. By default this is
+    ///     ``False``.
+    ///
+    /// :raises IndexError: when ``ignore_missing`` is ``False`` and any of the
+    ///     edge indices is not present in the graph. All the updates for
+    ///     edges that are present are still applied and the error message
+    ///     lists every missing index.
+    #[pyo3(
+        signature=(updates, ignore_missing=false),
+        text_signature = "(self, updates, /, ignore_missing=False)"
+    )]
+    pub fn update_edges_from(
+        &mut self,
+        updates: Bound<'_, PyAny>,
+        ignore_missing: bool,
+    ) -> PyResult<()> {
+        let mut missing: Vec<usize> = Vec::new();
+        for py_obj in updates.try_iter()? {
+            let (edge_index, edge) = py_obj?.extract::<(usize, PyObject)>()?;
+            match self.graph.edge_weight_mut(EdgeIndex::new(edge_index)) {
+                Some(data) => *data = edge,
+                None => missing.push(edge_index),
+            }
+        }
+        if !ignore_missing && !missing.is_empty() {
+            return Err(PyIndexError::new_err(format!(
+                "No edges found for indices: {:?}",
+                missing
+            )));
+        }
+        Ok(())
+    }
+
+    /// Return the node data for a given node index
+    ///
+    /// :param int node: The index of the node
+    ///
+    /// :returns: The data object set for that node
+    /// :rtype: S
+    /// :raises IndexError: when an invalid node index is provided
+    #[pyo3(text_signature = "(self, node, /)")]
+    pub fn get_node_data(&self, node: usize) -> PyResult<&PyObject> {
+        let index = NodeIndex::new(node);
+        let node = match self.graph.node_weight(index) {
+            Some(node) => node,
+            None => return Err(PyIndexError::new_err("No node found for index")),
+        };
+        Ok(node)
+    }
+
+    /// Return the node data for many node indices
+    ///
+    /// This is equivalent to calling :meth:`~rustworkx.PyGraph.get_node_data`
+    /// for every index, but gathers all of the payloads in a single call.
+    ///
+    /// :param iterable[int] indices: An iterable of node indices
+    /// :param bool ignore_missing: If set to ``True`` node indices that are
+    ///     not present in the graph are skipped. By default this is ``False``.
+    ///
+    /// :returns: A list of the data objects of the nodes, in the order of
+    ///     ``indices``
+    /// :rtype: list[S]
+    /// :raises IndexError: when ``ignore_missing`` is ``False`` and a node
+    ///     index is not present in the graph. The error is raised for the
+    ///     first missing index.
+    #[pyo3(
+        signature=(indices, ignore_missing=false),
+        text_signature = "(self, indices, /, ignore_missing=False)"
+    )]
+    pub fn get_nodes_data(
+        &self,
+        py: Python,
+        indices: Bound<'_, PyAny>,
+        ignore_missing: bool,
+    ) -> PyResult<Vec<PyObject>> {
+        let mut out_list: Vec<PyObject> = Vec::with_capacity(indices.len().unwrap_or(0));
+        for py_obj in indices.try_iter()? {
+            let node_index = py_obj?.extract::<usize>()?;
+            match self.graph.node_weight(NodeIndex::new(node_index)) {
+                Some(data) => out_list.push(data.clone_ref(py)),
+                None if ignore_missing => (),
+                None => {
+                    return Err(PyIndexError::new_err(format!(
+                        "No node found for index: {node_index}"
+                    )))
+                }
+            }
+        }
+        Ok(out_list)
+    }
+
+    /// Set the data payloads of many nodes in place by node index
+    ///
+    /// This is equivalent to assigning ``graph[node_index] = node`` for every
+    /// update, but applies all of them in a single call. Updates are applied
+    /// in order, so if a node index is repeated the last payload for it is
+    /// kept.
+    ///
+    /// :param iterable[tuple[int, S]] updates: An iterable of tuples of the
+    ///     form ``(node_index, node)`` with the index of a node and the python
+    ///     object to attach to it
+    /// :param bool ignore_missing: If set to ``True`` updates for node indices
+    ///     that are not present in the graph are skipped. By default this is
+    ///     ``False``.
+    ///
+    /// :raises IndexError: when ``ignore_missing`` is ``False`` and any of the
+    ///     node indices is not present in the graph. All the updates for
+    ///     nodes that are present are still applied and the error message
+    ///     lists every missing index.
+    #[pyo3(
+        signature=(updates, ignore_missing=false),
+        text_signature = "(self, updates, /, ignore_missing=False)"
+    )]
+    pub fn set_node_data_from(
+        &mut self,
+        updates: Bound<'_, PyAny>,
+        ignore_missing: bool,
+    ) -> PyResult<()> {
+        let mut missing: Vec<usize> = Vec::new();
+        for py_obj in updates.try_iter()? {
+            let (node_index, node) = py_obj?.extract::<(usize, PyObject)>()?;
+            match self.graph.node_weight_mut(NodeIndex::new(node_index)) {
+                Some(data) => *data = node,
+                None => missing.push(node_index),
+            }
+        }
+        if !ignore_missing && !missing.is_empty() {
+            return Err(PyIndexError::new_err(format!(
+                "No nodes found for indices: {:?}",
+                missing
+            )));
+        }
+        Ok(())
+    }
+
+    /// Return the edge data for all the edges between 2 nodes.
+    ///
+    /// :param int node_a: The index of the first node
+    /// :param int node_b: The index of the second node
+    ///
+    /// :returns: A list with all the data objects for the edges between nodes
+    /// :rtype: list[T]
+    /// :raises NoEdgeBetweenNodes: When there is no edge between nodes
+    #[pyo3(text_signature = "(self, node_a, node_b, /)")]
+    pub fn get_all_edge_data(&self, node_a: usize, node_b: usize) -> PyResult<Vec<&PyObject>> {
+        let index_a = NodeIndex::new(node_a);
+        let index_b = NodeIndex::new(node_b);
+        let out: Vec<&PyObject> = self
+            .graph
+            .edges(index_a)
+            .filter(|edge| edge.target() == index_b)
+            .map(|edge| edge.weight())
+            .collect();
+        if out.is_empty() {
+            Err(NoEdgeBetweenNodes::new_err("No edge found between nodes"))
+        } else {
+            Ok(out)
+        }
+    }
+
+    /// Return the edge indices and edge data for all the edges between 2 nodes.
+    ///
+    /// This can be used to tell parallel edges apart, for example to update
+    /// or remove a specific one of them by index.
+    ///
+    /// :param int node_a: The index of the first node
+    /// :param int node_b: The index of the second node
+    ///
+    /// :returns: A list of ``(edge_index, data)`` tuples for the edges
+    ///     between the nodes, in the same order as
+    ///     :meth:`~.PyGraph.get_all_edge_data`
+    /// :rtype: list[tuple[int, T]]
+    /// :raises NoEdgeBetweenNodes: When there is no edge between nodes
+    #[pyo3(text_signature = "(self, node_a, node_b, /)")]
+    pub fn get_all_edge_data_with_index(
+        &self,
+        node_a: usize,
+        node_b: usize,
+    ) -> PyResult<Vec<(usize, &PyObject)>> {
+        let index_a = NodeIndex::new(node_a);
+        let index_b = NodeIndex::new(node_b);
+        let out: Vec<(usize, &PyObject)> = self
+            .graph
+            .edges(index_a)
+            .filter(|edge| edge.target() == index_b)
+            .map(|edge| (edge.id().index(), edge.weight()))
+            .collect();
+        if out.is_empty() {
+            Err(NoEdgeBetweenNodes::new_err("No edge found between nodes"))
+        } else {
+            Ok(out)
+        }
+    }
+
+    /// Get edge list
+    ///
+    /// Returns a list of tuples of the form ``(source, target)`` where
+    /// ``source`` and ``target`` are the node indices.
+    ///
+    /// :returns: An edge list without weights
+    /// :rtype: EdgeList
+    #[pyo3(text_signature = "(self)")]
+    pub fn edge_list(&self) -> EdgeList {
+        EdgeList {
+            edges: self
+                .graph
+                .edge_references()
+                .map(|edge| (edge.source().index(), edge.target().index()))
+                .collect(),
+        }
+    }
+
+    /// Get edge list with weights
+    ///
+    /// Returns a list of tuples of the form ``(source, target, weight)`` where
+    /// ``source`` and ``target`` are the node indices and ``weight`` is the
+    /// payload of the edge, or the output of ``weight_fn`` for it.
+    ///
+    /// :param iterable[int] nodes: An optional iterable of node indices. If
+    ///     specified only the edges with both endpoints in ``nodes`` are
+    ///     returned. Node indices that are not present in the graph are
+    ///     ignored.
+    /// :param Callable weight_fn: An optional callable that will be passed
+    ///     the data/weight of each edge and return the object to use as the
+    ///     weight of that edge in the output, for example to extract a float
+    ///     from a dictionary payload. If not specified the payload is used.
+    ///
+    /// :returns: An edge list with weights
+    /// :rtype: WeightedEdgeList
+    #[pyo3(
+        signature = (nodes=None, weight_fn=None),
+        text_signature = "(self, /, nodes=None, weight_fn=None)"
+    )]
+    pub fn weighted_edge_list(
+        &self,
+        py: Python,
+        nodes: Option<Bound<'_, PyAny>>,
+        weight_fn: Option<PyObject>,
+    ) -> PyResult<WeightedEdgeList> {
+        let weighted_edge = |edge: EdgeReference<PyObject>| -> PyResult<(usize, usize, PyObject)> {
+            let weight =
+                weight_callable(py, &weight_fn, edge.weight(), edge.weight().clone_ref(py))?;
+            Ok((edge.source().index(), edge.target().index(), weight))
+        };
+        let edges = match nodes {
+            Some(nodes) => {
+                let nodes = nodes
+                    .try_iter()?
+                    .map(|node| node?.extract::<usize>())
+                    .collect::<PyResult<Vec<usize>>>()?;
+                let node_filter = self.node_selection_filter(nodes.into_iter());
+                self.graph
+                    .edge_references()
+                    .filter(|edge| node_filter(edge.source()) && node_filter(edge.target()))
+                    .map(weighted_edge)
+                    .collect::<PyResult<_>>()?
+            }
+            None => self
+                .graph
+                .edge_references()
+                .map(weighted_edge)
+                .collect::<PyResult<_>>()?,
+        };
+        Ok(WeightedEdgeList { edges })
+    }
+
+    /// Get an edge index map
+    ///
+    /// Returns a read only mapping from edge indices to the weighted edge
+    /// tuple in the form: ``{0: (0, 1, "weight"), 1: (2, 3, 2.3)}``
+    ///
+    /// :returns: An edge index map
+    /// :rtype: EdgeIndexMap
+    #[pyo3(text_signature = "(self)")]
+    pub fn edge_index_map(&self, py: Python) -> EdgeIndexMap {
+        EdgeIndexMap {
+            edge_map: self
+                .graph
+                .edge_references()
+                .map(|edge| {
+                    (
+                        edge.id().index(),
+                        (
+                            edge.source().index(),
+                            edge.target().index(),
+                            edge.weight().clone_ref(py),
+                        ),
+                    )
+                })
+                .collect(),
+        }
+    }
+
+    /// Get a mapping of edge endpoints to edge data
+    ///
+    /// Returns a dictionary mapping each ``(node_a, node_b)`` tuple of edge
+    /// endpoints to the payload of the edge between them. As the graph is
+    /// undirected, the endpoints in each key are ordered with the lower node
+    /// index first. If there are parallel edges between two nodes only the
+    /// data of the edge with the highest index is kept; use
+    /// :meth:`~rustworkx.PyGraph.edge_endpoint_multimap` to keep all of them.
+    ///
+    /// :returns: A dictionary of endpoint tuples to edge data
+    /// :rtype: dict[tuple[int, int], T]
+    #[pyo3(text_signature = "(self)")]
+    pub fn edge_endpoint_map(&self, py: Python) -> DictMap<(usize, usize), PyObject> {
+        let mut out_map = DictMap::with_capacity(self.graph.edge_count());
+        for edge in self.graph.edge_references() {
+            out_map.insert(
+                sorted_endpoints(edge.source(), edge.target()),
+                edge.weight().clone_ref(py),
+            );
+        }
+        out_map
+    }
+
+    /// Get a mapping of edge endpoints to the data of all edges between them
+    ///
+    /// Like :meth:`~rustworkx.PyGraph.edge_endpoint_map`, but each value is a
+    /// list of the payloads of all the (parallel) edges between the two
+    /// nodes, in ascending order of edge index.
+    ///
+    /// :returns: A dictionary of endpoint tuples to lists of edge data
+    /// :rtype: dict[tuple[int, int], list[T]]
+    #[pyo3(text_signature = "(self)")]
+    pub fn edge_endpoint_multimap(&self, py: Python) -> DictMap<(usize, usize), Vec<PyObject>> {
+        let mut out_map: DictMap<(usize, usize), Vec<PyObject>> =
+            DictMap::with_capacity(self.graph.edge_count());
+        for edge in self.graph.edge_references() {
+            out_map
+                .entry(sorted_endpoints(edge.source(), edge.target()))
+                .or_default()
+                .push(edge.weight().clone_ref(py));
+        }
+        out_map
+    }
+
+    /// Get the number of parallel edges between every pair of adjacent nodes
+    ///
+    /// Only pairs of nodes with at least one edge between them are included.
+    /// As the graph is undirected, the endpoints in each key are ordered with
+    /// the lower node index first, matching
+    /// :meth:`~rustworkx.PyGraph.edge_endpoint_map`. Any pair with a count
+    /// greater than 1 is a pair whose edges would be merged by
+    /// :meth:`~rustworkx.PyGraph.to_simple_graph`.
+    ///
+    /// :returns: A dictionary of endpoint tuples to the number of edges
+    ///     between them
+    /// :rtype: dict[tuple[int, int], int]
+    #[pyo3(text_signature = "(self)")]
+    pub fn parallel_edge_counts(&self) -> DictMap<(usize, usize), usize> {
+        let mut out_map: DictMap<(usize, usize), usize> =
+            DictMap::with_capacity(self.graph.edge_count());
+        for edge in self.graph.edge_references() {
+            *out_map
+                .entry(sorted_endpoints(edge.source(), edge.target()))
+                .or_insert(0) += 1;
+        }
+        out_map
+    }
+
+    /// Return a list of all edges with their index, endpoints and data.
+    ///
+    /// Unlike :meth:`~rustworkx.PyGraph.edge_index_map` this returns a plain
+    /// list, in ascending order of edge index.
+    ///
+    /// :returns: A list of tuples of the form
+    ///     ``(edge_index, source, target, data)`` for every edge in the graph
+    /// :rtype: list[tuple[int, int, int, T]]
+    #[pyo3(text_signature = "(self)")]
+    pub fn edge_items(&self) -> Vec<(usize, usize, usize, &PyObject)> {
+        self.graph
+            .edge_references()
+            .map(|edge| {
+                (
+                    edge.id().index(),
+                    edge.source().index(),
+                    edge.target().index(),
+                    edge.weight(),
+                )
+            })
+            .collect()
+    }
+
+    /// Remove a node from the graph.
+    ///
+    /// :param int node: The index of the node to remove. If the index is not
+    ///     present in the graph it will be ignored and this function will
+    ///     have no effect.
+    #[pyo3(text_signature = "(self, node, /)")]
+    pub fn remove_node(&mut self, node: usize) -> PyResult<()> {
+        let index = NodeIndex::new(node);
+        if self.graph.remove_node(index).is_some() {
+            self.connectivity = None;
+        }
+        self.node_removed = true;
+        Ok(())
+    }
+
+    /// Remove a node from the graph and add edges between all pairs of its
+    /// neighbors
+    ///
+    /// This is also known as smoothing the node: removing a node with
+    /// degree 2 on a path joins its 2 neighbors with a new edge so the path
+    /// is retained. For every pair of distinct edges incident to ``node``
+    /// that have different endpoints an edge is added between those
+    /// endpoints. The data/weight of the new edge is the one of the edge in
+    /// the pair with the lowest edge index. Self loops on ``node`` are
+    /// ignored.
+    ///
+    /// :param int node: The index of the node to remove. If the index is not
+    ///     present in the graph it will be ignored and this function will
+    ///     have no effect.
+    /// :param Callable weight_combo_fn: An optional python callable that, when
+    ///     specified, is used to merge a new edge with an edge that already
+    ///     exists between the same endpoints (including another new edge).
+    ///     It will be passed the weight/data of the existing edge and of the
+    ///     new edge and its return value is used as the weight/data of the
+    ///     merged edge. If this instance of :class:`~rustworkx.PyGraph` is a
+    ///     multigraph, leave this unspecified to add parallel edges instead.
+    ///     If unspecified when not a multigraph, the weight/data of the
+    ///     existing edge is kept.
+    #[pyo3(text_signature = "(self, node, /, weight_combo_fn=None)", signature = (node, weight_combo_fn=None))]
+    pub fn remove_node_retain_edges(
+        &mut self,
+        py: Python,
+        node: usize,
+        weight_combo_fn: Option<PyObject>,
+    ) -> PyResult<()> {
+        let index = NodeIndex::new(node);
+        let mut incident: Vec<(EdgeIndex, NodeIndex)> = self
+            .graph
+            .edges(index)
+            .filter(|edge| edge.source() != edge.target())
+            .map(|edge| {
+                let other = if edge.source() == index {
+                    edge.target()
+                } else {
+                    edge.source()
+                };
+                (edge.id(), other)
+            })
+            .collect();
+        incident.sort_unstable();
+        let mut edge_list: Vec<(NodeIndex, NodeIndex, PyObject)> = Vec::new();
+        for (i, (edge, node_a)) in incident.iter().enumerate() {
+            for (_, node_b) in incident[i + 1..].iter() {
+                if node_a != node_b {
+                    edge_list.push((*node_a, *node_b, self.graph[*edge].clone_ref(py)));
+                }
+            }
+        }
+        if self.graph.remove_node(index).is_none() {
+            return Ok(());
+        }
+        self.node_removed = true;
+        self.connectivity = None;
+        for (node_a, node_b, weight) in edge_list {
+            self.add_or_merge_edge(py, node_a, node_b, weight, weight_combo_fn.as_ref())?;
+        }
+        Ok(())
+    }
+
+    /// Add an edge between 2 nodes.
+    ///
+    /// If :attr:`~rustworkx.PyGraph.multigraph` is ``False`` and an edge already
+    /// exists between ``node_a`` and ``node_b`` the weight/payload of that
+    /// existing edge will be updated to be ``edge``.
+    ///
+    /// :param int node_a: The index of the parent node
+    /// :param int node_b: The index of the child node
+    /// :param T edge: The python object to attach to the edge
+    /// :param bool return_status: If set to ``True`` a tuple of the edge
+    ///     index and a boolean that is ``True`` if a new edge was created
+    ///     and ``False`` if an existing edge was updated is returned instead
+    ///     of only the edge index. By default this is ``False``.
+    ///
+    /// :returns: The index of the newly created (or updated in the case
+    ///     of an existing edge with ``multigraph=False``) edge, or a tuple
+    ///     of that index and whether the edge was created if
+    ///     ``return_status`` is ``True``.
+    /// :rtype: int | tuple[int, bool]
+    #[pyo3(
+        signature=(node_a, node_b, edge, return_status=false),
+        text_signature = "(self, node_a, node_b, edge, /, return_status=False)"
+    )]
+    pub fn add_edge(
+        &mut self,
+        py: Python,
+        node_a: usize,
+        node_b: usize,
+        edge: PyObject,
+        return_status: bool,
+    ) -> PyResult<PyObject> {
+        let (index, created) = self.add_edge_checked(node_a, node_b, edge)?;
+        if return_status {
+            (index, created).into_py_any(py)
+        } else {
+            index.into_py_any(py)
+        }
+    }
+
+    /// Add new edges to the graph.
+    ///
+    /// :param iterable[tuple[int, int, T]] obj_list: An iterable of tuples of the form
+    ///     ``(node_a, node_b, T)`` to attach to the graph. ``node_a`` and
+    ///     ``node_b`` are integer indices describing where an edge should be
+    ///     added, and ``T`` is the python object for the edge data.
+    ///
+    /// If :attr:`~rustworkx.PyGraph.multigraph` is ``False`` and an edge already
+    /// exists between ``node_a`` and ``node_b`` the weight/payload of that
+    /// existing edge will be updated to be ``edge``. This will occur in order
+    /// from ``obj_list`` so if there are multiple parallel edges in ``obj_list``
+    /// the last entry will be used.
+    ///
+    /// :param bool check_all_first: If set to ``True`` every tuple in
+    ///     ``obj_list`` is read and both endpoints of every edge are checked
+    ///     to exist before any edge is added, so if an endpoint is missing
+    ///     the graph is left unmodified. By default this is ``False`` and
+    ///     edges are added one at a time, so if an endpoint is missing the
+    ///     edges before it in ``obj_list`` will have been added when the
+    ///     error is raised.
+    ///
+    /// :returns: A list of indices of the newly created edges
+    /// :rtype: EdgeIndices
+    /// :raises IndexError: If an endpoint of an edge does not exist in the
+    ///     graph
+    #[pyo3(
+        signature=(obj_list, check_all_first=false),
+        text_signature = "(self, obj_list, /, check_all_first=False)"
+    )]
+    pub fn add_edges_from(
+        &mut self,
+        obj_list: Bound<'_, PyAny>,
+        check_all_first: bool,
+    ) -> PyResult<EdgeIndices> {
+        if check_all_first {
+            let edges = obj_list
+                .try_iter()?
+                .map(|py_obj| py_obj?.extract::<(usize, usize, PyObject)>())
+                .collect::<PyResult<Vec<_>>>()?;
+            if edges.iter().any(|(node_a, node_b, _)| {
+                !self.graph.contains_node(NodeIndex::new(*node_a))
+                    || !self.graph.contains_node(NodeIndex::new(*node_b))
+            }) {
+                return Err(PyIndexError::new_err(
+                    "One of the endpoints of the edge does not exist in graph",
+                ));
+            }
+            self.graph.reserve_edges(edges.len());
+            let out_list = edges
+                .into_iter()
+                .map(|(node_a, node_b, edge)| {
+                    self._add_edge(NodeIndex::new(node_a), NodeIndex::new(node_b), edge)
+                })
+                .collect();
+            return Ok(EdgeIndices { edges: out_list });
+        }
+        let mut out_list = Vec::new();
+        for py_obj in obj_list.try_iter()? {
+            let obj = py_obj?.extract::<(usize, usize, PyObject)>()?;
+            out_list.push(self.add_edge_checked(obj.0, obj.1, obj.2)?.0);
+        }
+        Ok(EdgeIndices { edges: out_list })
+    }
+
+    /// Add new edges to the graph without python data.
+    ///
+    /// :param iterable[tuple[int, int]] obj_list: An iterable of tuples of the form
+    ///     ``(parent, child)`` to attach to the graph. ``parent`` and
+    ///     ``child`` are integer indices describing where an edge should be
+    ///     added. Unlike :meth:`add_edges_from` there is no data payload and
+    ///     when the edge is created None will be used.
+    ///
+    /// If :attr:`~rustworkx.PyGraph.multigraph` is ``False`` and an edge already
+    /// exists between ``node_a`` and ``node_b`` the weight/payload of that
+    /// existing edge will be updated to be ``None``.
+    ///
+    /// :returns: A list of indices of the newly created edges
+    /// :rtype: EdgeIndices
+    #[pyo3(text_signature = "(self, obj_list, /)")]
+    pub fn add_edges_from_no_data(
+        &mut self,
+        py: Python,
+        obj_list: Bound<'_, PyAny>,
+    ) -> PyResult<EdgeIndices> {
+        let mut out_list: Vec<usize> = Vec::new();
+        for py_obj in obj_list.try_iter()? {
+            let obj = py_obj?.extract::<(usize, usize)>()?;
+            out_list.push(self.add_edge_checked(obj.0, obj.1, py.None())?.0);
+        }
+        Ok(EdgeIndices { edges: out_list })
+    }
+
+    /// Extend graph from an edge list
+    ///
+    /// This method differs from :meth:`add_edges_from_no_data` in that it will
+    /// add nodes if a node index is not present in the edge list.
+    ///
+    /// If :attr:`~rustworkx.PyGraph.multigraph` is ``False`` and an edge already
+    /// exists between ``node_a`` and ``node_b`` the weight/payload of that
+    /// existing edge will be updated to be ``None``.
+    ///
+    /// :param iterable[tuple[int, int]] edge_list: An iterable of tuples
+    ///     in the form ``(source, target)`` where ``source`` and ``target``
+    ///     are integer node indices. If the node index
+    ///     is not present in the graph, nodes will be added (with a node
+    ///     weight of ``None``) to that index.
+    /// :param int node_count_hint: An optional hint for the number of nodes
+    ///     the graph will contain after the edge list is added. If specified
+    ///     enough capacity will be allocated up front to store this many
+    ///     nodes. This does not add any nodes, it is only a potential
+    ///     performance optimization for edge lists referencing large node
+    ///     indices.
+    #[pyo3(
+        signature = (edge_list, /, node_count_hint=None),
+        text_signature = "(self, edge_list, /, node_count_hint=None)"
+    )]
+    pub fn extend_from_edge_list(
+        &mut self,
+        py: Python,
+        edge_list: Bound<'_, PyAny>,
+        node_count_hint: Option<usize>,
+    ) -> PyResult<()> {
+        self.reserve_for_edge_list(&edge_list, node_count_hint);
+        for py_obj in edge_list.try_iter()? {
+            let (source, target) = py_obj?.extract::<(usize, usize)>()?;
+            let max_index = cmp::max(source, target);
+            if max_index >= self.node_count() {
+                self.graph
+                    .reserve_nodes(max_index + 1 - self.graph.node_count());
+            }
+            while max_index >= self.node_count() {
+                self.graph.add_node(py.None());
+            }
+            let source_index = NodeIndex::new(source);
+            let target_index = NodeIndex::new(target);
+            self._add_edge(source_index, target_index, py.None());
+        }
+        Ok(())
+    }
+
+    /// Extend graph from a weighted edge list
+    ///
+    /// This method differs from :meth:`add_edges_from` in that it will
+    /// add nodes if a node index is not present in the edge list.
+    ///
+    /// If :attr:`~rustworkx.PyGraph.multigraph` is ``False`` and an edge already
+    /// exists between ``node_a`` and ``node_b`` the weight/payload of that
+    /// existing edge will be updated to be ``edge``. This will occur in order
+    /// from ``obj_list`` so if there are multiple parallel edges in ``obj_list``
+    /// the last entry will be used.
+    ///
+    /// :param iterable[tuple[int, int, T]] edge_list: An iterable of tuples in the form
+    ///     ``(source, target, weight)`` where source and target are integer
+    ///     node indices. If the node index is not present in the graph,
+    ///     nodes will be added (with a node weight of ``None``) to that index.
+    /// :param int node_count_hint: An optional hint for the number of nodes
+    ///     the graph will contain after the edge list is added. If specified
+    ///     enough capacity will be allocated up front to store this many
+    ///     nodes. This does not add any nodes, it is only a potential
+    ///     performance optimization for edge lists referencing large node
+    ///     indices.
+    #[pyo3(
+        signature = (edge_list, /, node_count_hint=None),
+        text_signature = "(self, edge_list, /, node_count_hint=None)"
+    )]
+    pub fn extend_from_weighted_edge_list(
+        &mut self,
+        py: Python,
+        edge_list: Bound<'_, PyAny>,
+        node_count_hint: Option<usize>,
+    ) -> PyResult<()> {
+        self.reserve_for_edge_list(&edge_list, node_count_hint);
+        for py_obj in edge_list.try_iter()? {
+            let (source, target, weight) = py_obj?.extract::<(usize, usize, PyObject)>()?;
+            let max_index = cmp::max(source, target);
+            if max_index >= self.node_count() {
+                self.graph
+                    .reserve_nodes(max_index + 1 - self.graph.node_count());
+            }
+            while max_index >= self.node_count() {
+                self.graph.add_node(py.None());
+            }
+            let source_index = NodeIndex::new(source);
+            let target_index = NodeIndex::new(target);
+            self._add_edge(source_index, target_index, weight);
+        }
+        Ok(())
+    }
+
+    /// Remove an edge between 2 nodes.
+    ///
+    /// Note if there are multiple edges between the specified nodes only one
+    /// will be removed.
+    ///
+    /// :param int parent: The index of the parent node
+    /// :param int child: The index of the child node
+    ///
+    /// :raises NoEdgeBetweenNodes: If there is no edge between the nodes
+    ///     specified
+    #[pyo3(text_signature = "(self, node_a, node_b, /)")]
+    pub fn remove_edge(&mut self, node_a: usize, node_b: usize) -> PyResult<()> {
+        let p_index = NodeIndex::new(node_a);
+        let c_index = NodeIndex::new(node_b);
+        let edge_index = match self.graph.find_edge(p_index, c_index) {
+            Some(edge_index) => edge_index,
+            None => return Err(NoEdgeBetweenNodes::new_err("No edge found between nodes")),
+        };
+        self.graph.remove_edge(edge_index);
+        self.connectivity = None;
+        Ok(())
+    }
+
+    /// Remove an edge identified by the provided index
+    ///
+    /// :param int edge: The index of the edge to remove
+    #[pyo3(text_signature = "(self, edge, /)")]
+    pub fn remove_edge_from_index(&mut self, edge: usize) -> PyResult<()> {
+        let edge_index = EdgeIndex::new(edge);
+        if self.graph.remove_edge(edge_index).is_some() {
+            self.connectivity = None;
+        }
+        Ok(())
+    }
+
+    /// Subdivide an edge by inserting a new node on it
+    ///
+    /// The edge ``(u, v)`` is removed and replaced by a new node ``w`` and the
+    /// two edges ``(u, w)`` and ``(w, v)``, where ``u`` and ``v`` are the
+    /// endpoints of the edge as returned by
+    /// :meth:`~rustworkx.PyGraph.get_edge_endpoints_by_index`.
+    ///
+    /// :param int edge_index: The index of the edge to subdivide
+    /// :param S node_data: The data/weight of the new node
+    /// :param T edge_data_uw: The data/weight of the new edge ``(u, w)``. If
+    ///     not specified the data/weight of the original edge is used, by
+    ///     reference. An explicit ``None`` is used as the data/weight.
+    /// :param T edge_data_wv: The data/weight of the new edge ``(w, v)``. If
+    ///     not specified the data/weight of the original edge is used, by
+    ///     reference. An explicit ``None`` is used as the data/weight.
+    ///
+    /// :returns: The index of the new node
+    /// :rtype: int
+    /// :raises IndexError: If the edge index is not present in the graph
+    #[pyo3(
+        text_signature = "(self, edge_index, node_data, /, edge_data_uw=..., edge_data_wv=...)",
+        signature = (edge_index, node_data, edge_data_uw=OptionalArg::Unset, edge_data_wv=OptionalArg::Unset)
+    )]
+    pub fn subdivide_edge(
+        &mut self,
+        py: Python,
+        edge_index: usize,
+        node_data: PyObject,
+        edge_data_uw: OptionalArg,
+        edge_data_wv: OptionalArg,
+    ) -> PyResult<usize> {
+        let edge_index = EdgeIndex::new(edge_index);
+        let (node_u, node_v) = match self.graph.edge_endpoints(edge_index) {
+            Some(endpoints) => endpoints,
+            None => return Err(PyIndexError::new_err("No edge found for index")),
+        };
+        let weight = self.graph.remove_edge(edge_index).unwrap();
+        let node_w = self.graph.add_node(node_data);
+        let weight_uw = match edge_data_uw {
+            OptionalArg::Set(weight_uw) => weight_uw,
+            OptionalArg::Unset => weight.clone_ref(py),
+        };
+        let weight_wv = match edge_data_wv {
+            OptionalArg::Set(weight_wv) => weight_wv,
+            OptionalArg::Unset => weight,
+        };
+        self.graph.add_edge(node_u, node_w, weight_uw);
+        self.graph.add_edge(node_w, node_v, weight_wv);
+        self.track_edge(node_u, node_w);
+        self.track_edge(node_w, node_v);
+        Ok(node_w.index())
+    }
+
+    /// Remove edges from the graph.
+    ///
+    /// Note if there are multiple edges between the specified nodes only one
+    /// will be removed.
+    ///
+    /// :param iterable[tuple[int, int]] index_list: An iterable of node index pairs
+    ///     to remove from the graph
+    ///
+    /// :raises NoEdgeBetweenNodes: If there are no edges between a specified
+    ///     pair of nodes.
+    #[pyo3(text_signature = "(self, index_list, /)")]
+    pub fn remove_edges_from(&mut self, index_list: Bound<'_, PyAny>) -> PyResult<()> {
+        for py_obj in index_list.try_iter()? {
+            let (x, y) = py_obj?.extract::<(usize, usize)>()?;
+            let (p_index, c_index) = (NodeIndex::new(x), NodeIndex::new(y));
+            let edge_index = match self.graph.find_edge(p_index, c_index) {
+                Some(edge_index) => edge_index,
+                None => return Err(NoEdgeBetweenNodes::new_err("No edge found between nodes")),
+            };
+            self.graph.remove_edge(edge_index);
+            self.connectivity = None;
+        }
+        Ok(())
+    }
+
+    /// Add a new node to the graph.
+    ///
+    /// :param S obj: The python object to attach to the node
+    ///
+    /// :returns: The index of the newly created node
+    /// :rtype: int
+    #[pyo3(text_signature = "(self, obj, /)")]
+    pub fn add_node(&mut self, obj: PyObject) -> PyResult<usize> {
+        let index = self.graph.add_node(obj);
+        Ok(index.index())
+    }
+
+    /// Add new nodes to the graph.
+    ///
+    /// If ``obj_list`` has a length, such as a list or a ``range``, space for
+    /// that many nodes is reserved up front.
+    ///
+    /// :param iterable[S] obj_list: An iterable of python object to attach to the graph
+    /// :param int count_hint: An optional number of nodes to reserve space for
+    ///     when ``obj_list`` doesn't have a length, such as a generator. It's
+    ///     only a hint, the nodes are added whether there are more or fewer of
+    ///     them.
+    ///
+    /// :returns indices: A list of indices of the newly created nodes
+    /// :rtype: NodeIndices
+    #[pyo3(
+        signature=(obj_list, /, count_hint=None),
+        text_signature = "(self, obj_list, /, count_hint=None)"
+    )]
+    pub fn add_nodes_from(
+        &mut self,
+        obj_list: Bound<'_, PyAny>,
+        count_hint: Option<usize>,
+    ) -> PyResult<NodeIndices> {
+        let capacity = obj_list
+            .len()
+            .ok()
+            .or(count_hint)
+            .unwrap_or(0)
+            .min(MAX_UNCHECKED_RESERVATION);
+        self.graph.reserve_nodes(capacity);
+        let mut out_list = Vec::with_capacity(capacity);
+        for py_obj in obj_list.try_iter()? {
+            let obj = py_obj?.extract::<PyObject>()?;
+            out_list.push(self.graph.add_node(obj).index());
+        }
+        Ok(NodeIndices { nodes: out_list })
+    }
+
+    /// Remove nodes from the graph.
+    ///
+    /// If a node index in the list is not present in the graph it will be
+    /// ignored.
+    ///
+    /// :param iterable[int] index_list: An iterable of node indices to remove from the
+    ///     graph
+    #[pyo3(text_signature = "(self, index_list, /)")]
+    pub fn remove_nodes_from(&mut self, index_list: Bound<'_, PyAny>) -> PyResult<()> {
+        for py_obj in index_list.try_iter()? {
+            let node = py_obj?.extract::<usize>()?;
+            self.remove_node(node)?;
         }
+        Ok(())
     }
 
-    /// Return the list of edge indices incident to a provided node.
+    /// Remove the nodes selected by a boolean mask from the graph.
+    ///
+    /// This is equivalent to :meth:`.remove_nodes_from()` called with the
+    /// indices of the ``True`` entries of ``mask``, without building that
+    /// list of indices.
+    ///
+    /// :param numpy.ndarray mask: A 1 dimensional boolean array with an entry
+    ///     for every node index up to the highest node index in the graph,
+    ///     i.e. of length ``max(graph.node_indices()) + 1``, where the entry
+    ///     at a node index is ``True`` if the node is to be removed.
+    ///     Entries for indices that aren't present in the graph are ignored.
+    ///
+    /// :raises ValueError: If ``mask`` doesn't have the expected length
+    #[pyo3(text_signature = "(self, mask, /)")]
+    pub fn remove_nodes_from_mask(&mut self, mask: PyReadonlyArray1<bool>) -> PyResult<()> {
+        let mask = mask.as_array();
+        let node_bound = self.graph.node_bound();
+        if mask.len() != node_bound {
+            return Err(PyValueError::new_err(format!(
+                "Mask length {} does not match the expected length {node_bound}",
+                mask.len()
+            )));
+        }
+        for (node, selected) in mask.iter().enumerate() {
+            if *selected && self.graph.remove_node(NodeIndex::new(node)).is_some() {
+                self.connectivity = None;
+                self.node_removed = true;
+            }
+        }
+        Ok(())
+    }
+
+    /// Find node within this graph given a specific weight
     ///
-    /// This method returns the indices of all edges connected to the provided
-    /// ``node``. In undirected graphs, all edges connected to the node are
-    /// returned as there is no distinction between incoming and outgoing edges.
+    /// This algorithm has a worst case of O(n) since it searches the node
+    /// indices in order. If there is more than one node in the graph with the
+    /// same weight only the first match (by node index) will be returned.
     ///
-    /// :param int node: The node index to get incident edges from. If
-    ///     this node index is not present in the graph this method will
-    ///     return an empty list and not error.
+    /// :param T obj: The weight to look for in the graph.
     ///
-    /// :returns: A list of the edge indices incident to the node
-    /// :rtype: EdgeIndices
-    #[pyo3(text_signature = "(self, node, /)")]
-    pub fn in_edge_indices(&self, node: usize) -> EdgeIndices {
-        EdgeIndices {
-            edges: self
-                .graph
-                .edges(NodeIndex::new(node))
-                .map(|e| e.id().index())
+    /// :returns: the index of the first node in the graph that is equal to the
+    ///     weight. If no match is found ``None`` will be returned.
+    /// :rtype: int
+    #[pyo3(text_signature = "(self, obj, /)")]
+    pub fn find_node_by_weight(&self, py: Python, obj: PyObject) -> PyResult<Option<usize>> {
+        find_node_by_weight(py, &self.graph, &obj).map(|node| node.map(|x| x.index()))
+    }
+
+    /// Find all the nodes within this graph with a specific weight
+    ///
+    /// Like :meth:`~rustworkx.PyGraph.find_node_by_weight` this compares
+    /// ``obj`` with the data payload of every node in the graph using Python
+    /// equality, but it returns the indices of all the matching nodes instead
+    /// of only the first one.
+    ///
+    /// :param T obj: The weight to look for in the graph.
+    ///
+    /// :returns: The indices of all the nodes in the graph with data equal to
+    ///     ``obj``, in ascending order. If there is no match the list is empty.
+    /// :rtype: NodeIndices
+    #[pyo3(text_signature = "(self, obj, /)")]
+    pub fn find_nodes_by_weight(&self, py: Python, obj: PyObject) -> PyResult<NodeIndices> {
+        Ok(NodeIndices {
+            nodes: find_nodes_by_weight(py, &self.graph, &obj)?
+                .into_iter()
+                .map(|node| node.index())
                 .collect(),
-        }
+        })
     }
 
-    /// Return the list of edge indices incident to a provided node.
+    /// Get the index and data for the neighbors of a node.
     ///
-    /// This method returns the indices of all edges connected to the provided
-    /// ``node``. In undirected graphs, all edges connected to the node are
-    /// returned as there is no distinction between incoming and outgoing edges.
+    /// This will return a dictionary where the keys are the node indices of
+    /// the adjacent nodes (inbound or outbound) and the value is the edge data
+    /// objects between that adjacent node and the provided node. Note, that
+    /// in the case of multigraphs only a single edge data object will be
+    /// returned
     ///
-    /// :param int node: The node index to get incident edges from. If
-    ///     this node index is not present in the graph this method will
-    ///     return an empty list and not error.
+    /// :param int node: The index of the node to get the neighbors of
     ///
-    /// :returns: A list of the edge indices incident to the node
-    /// :rtype: EdgeIndices
+    /// :returns neighbors: A dictionary where the keys are node indices and
+    ///     the value is the edge data object for all nodes that share an
+    ///     edge with the specified node.
+    /// :rtype: dict[int, T]
     #[pyo3(text_signature = "(self, node, /)")]
-    pub fn out_edge_indices(&self, node: usize) -> EdgeIndices {
-        EdgeIndices {
-            edges: self
-                .graph
-                .edges(NodeIndex::new(node))
-                .map(|e| e.id().index())
-                .collect(),
-        }
+    pub fn adj(&mut self, node: usize) -> DictMap<usize, &PyObject> {
+        let index = NodeIndex::new(node);
+        self.graph
+            .edges_directed(index, petgraph::Direction::Outgoing)
+            .map(|edge| (edge.target().index(), edge.weight()))
+            .collect()
     }
 
-    /// Return the index map of edges incident to a provided node
+    /// Get the neighbors of a node.
     ///
-    /// :param int node: The node index to get incident edges from. If
-    ///     this node index is not present in the graph this method will
-    ///     return an empty mapping and not error.
+    /// This with return a list of neighbor node indices
     ///
-    /// :returns: A mapping of incident edge indices to the tuple
-    ///     ``(source, target, data)``. The source endpoint node index in
-    ///     this tuple will always be ``node`` to ensure you can easily
-    ///     identify that the neighbor node is the second element in the
-    ///     tuple for a given edge index.
-    /// :rtype: EdgeIndexMap
+    /// :param int node: The index of the node to get the neighbors of
+    ///
+    /// :returns: A list of the neighbor node indices
+    /// :rtype: NodeIndices
     #[pyo3(text_signature = "(self, node, /)")]
-    pub fn incident_edge_index_map(&self, py: Python, node: usize) -> EdgeIndexMap {
-        let node_index = NodeIndex::new(node);
-        EdgeIndexMap {
-            edge_map: self
+    pub fn neighbors(&self, node: usize) -> NodeIndices {
+        NodeIndices {
+            nodes: self
                 .graph
-                .edges_directed(node_index, petgraph::Direction::Outgoing)
-                .map(|edge| {
-                    (
-                        edge.id().index(),
-                        (
-                            edge.source().index(),
-                            edge.target().index(),
-                            edge.weight().clone_ref(py),
-                        ),
-                    )
-                })
+                .neighbors(NodeIndex::new(node))
+                .map(|node| node.index())
+                .collect::<HashSet<usize>>()
+                .drain()
                 .collect(),
         }
     }
 
-    /// Get the endpoint indices and edge data for all edges of a node.
+    /// Get the edges on the boundary of a set of nodes.
     ///
-    /// This will return a list of tuples with the parent index, the node index
-    /// and the edge data. This can be used to recreate add_edge() calls. As
-    /// :class:`~rustworkx.PyGraph` is undirected this will return all edges
-    /// with the second endpoint node index always being ``node``.
+    /// The edge boundary of a set of nodes is the set of edges with exactly
+    /// one endpoint in the set, i.e. the edges of the cut between the set and
+    /// the rest of the graph. Every parallel edge crossing the cut is part of
+    /// the boundary, while self loops never are.
     ///
-    /// :param int node: The index of the node to get the edges for
+    /// :param list[int] nodes: The indices of the nodes in the set. Node
+    ///     indices that are not present in the graph are ignored.
     ///
-    /// :returns: A list of tuples of the form:
-    ///     ``(parent_index, node_index, edge_data)```
-    /// :rtype: WeightedEdgeList
-    #[pyo3(text_signature = "(self, node, /)")]
-    pub fn in_edges(&self, py: Python, node: usize) -> WeightedEdgeList {
-        let index = NodeIndex::new(node);
-        let dir = petgraph::Direction::Incoming;
-        let raw_edges = self.graph.edges_directed(index, dir);
-        let out_list: Vec<(usize, usize, PyObject)> = raw_edges
-            .map(|x| (x.source().index(), node, x.weight().clone_ref(py)))
+    /// :returns: A sorted list of the indices of the edges on the boundary
+    /// :rtype: EdgeIndices
+    #[pyo3(text_signature = "(self, nodes, /)")]
+    pub fn edge_boundary(&self, nodes: Vec<usize>) -> EdgeIndices {
+        let nodes = self.present_node_set(nodes);
+        let in_set = self.node_selection_filter(nodes.iter().map(|node| node.index()));
+        let mut edges: Vec<usize> = nodes
+            .iter()
+            .flat_map(|node| self.graph.edges(*node))
+            .filter(|edge| !in_set(edge.target()))
+            .map(|edge| edge.id().index())
             .collect();
-        WeightedEdgeList { edges: out_list }
+        edges.sort_unstable();
+        EdgeIndices { edges }
     }
 
-    /// Get the endpoint indices and edge data for all edges of a node.
+    /// Get the nodes on the boundary of a set of nodes.
     ///
-    /// This will return a list of tuples with the child index, the node index
-    /// and the edge data. This can be used to recreate add_edge() calls. As
-    /// :class:`~rustworkx.PyGraph` is undirected this will return all edges
-    /// with the first endpoint node index always being ``node``.
+    /// The node boundary of a set of nodes is the set of nodes outside of
+    /// the set that are adjacent to at least one node in the set.
     ///
-    /// :param int node: The index of the node to get the edges for
+    /// :param list[int] nodes: The indices of the nodes in the set. Node
+    ///     indices that are not present in the graph are ignored.
     ///
-    /// :returns out_edges: A list of tuples of the form:
-    ///     ```(node_index, child_index, edge_data)```
-    /// :rtype: WeightedEdgeList
+    /// :returns: A sorted list of the indices of the nodes on the boundary
+    /// :rtype: NodeIndices
+    #[pyo3(text_signature = "(self, nodes, /)")]
+    pub fn node_boundary(&self, nodes: Vec<usize>) -> NodeIndices {
+        let nodes = self.present_node_set(nodes);
+        let in_set = self.node_selection_filter(nodes.iter().map(|node| node.index()));
+        let mut boundary: Vec<usize> = nodes
+            .iter()
+            .flat_map(|node| self.graph.neighbors(*node))
+            .filter(|neighbor| !in_set(*neighbor))
+            .map(|neighbor| neighbor.index())
+            .collect();
+        boundary.sort_unstable();
+        boundary.dedup();
+        NodeIndices { nodes: boundary }
+    }
+
+    /// Get the common neighbors of two nodes
+    ///
+    /// The common neighbors of two nodes are the nodes adjacent to both of
+    /// them. Parallel edges are collapsed and self loops are ignored, so
+    /// neither node is a common neighbor unless ``node_a`` and ``node_b`` are
+    /// the same node.
+    ///
+    /// :param int node_a: The index of the first node
+    /// :param int node_b: The index of the second node
+    ///
+    /// :returns: A sorted list of the indices of the common neighbors
+    /// :rtype: NodeIndices
+    /// :raises IndexError: If either node is not in the graph
+    #[pyo3(text_signature = "(self, node_a, node_b, /)")]
+    pub fn common_neighbors(&self, node_a: usize, node_b: usize) -> PyResult<NodeIndices> {
+        let neighbors_a = self.checked_distinct_neighbors(node_a)?;
+        let neighbors_b = self.checked_distinct_neighbors(node_b)?;
+        Ok(NodeIndices {
+            nodes: Self::sorted_intersection(&neighbors_a, &neighbors_b),
+        })
+    }
+
+    /// Get the Jaccard coefficient of two nodes
+    ///
+    /// The Jaccard coefficient of two nodes ``u`` and ``v`` is the number of
+    /// their common neighbors divided by the number of nodes adjacent to
+    /// either of them, :math:`|N(u) \cap N(v)| / |N(u) \cup N(v)|`. Parallel
+    /// edges are collapsed and self loops are ignored.
+    ///
+    /// :param int node_a: The index of the first node
+    /// :param int node_b: The index of the second node
+    ///
+    /// :returns: The Jaccard coefficient, ``0.0`` if neither node has a
+    ///     neighbor
+    /// :rtype: float
+    /// :raises IndexError: If either node is not in the graph
+    #[pyo3(text_signature = "(self, node_a, node_b, /)")]
+    pub fn jaccard_coefficient(&self, node_a: usize, node_b: usize) -> PyResult<f64> {
+        let neighbors_a = self.checked_distinct_neighbors(node_a)?;
+        let neighbors_b = self.checked_distinct_neighbors(node_b)?;
+        Ok(Self::jaccard_from_neighbors(&neighbors_a, &neighbors_b))
+    }
+
+    /// Get the Jaccard coefficient of many pairs of nodes
+    ///
+    /// This is the same as calling
+    /// :meth:`~rustworkx.PyGraph.jaccard_coefficient` for every pair, but
+    /// the neighbors of each node are only computed once.
+    ///
+    /// :param list[tuple[int, int]] pairs: The pairs of node indices to get
+    ///     the Jaccard coefficient of
+    ///
+    /// :returns: The Jaccard coefficient of every pair, in the order of
+    ///     ``pairs``
+    /// :rtype: list[float]
+    /// :raises IndexError: If any node is not in the graph
+    #[pyo3(text_signature = "(self, pairs, /)")]
+    pub fn jaccard_coefficient_pairs(&self, pairs: Vec<(usize, usize)>) -> PyResult<Vec<f64>> {
+        let mut neighbors: HashMap<usize, Vec<usize>> = HashMap::new();
+        let mut out_list = Vec::with_capacity(pairs.len());
+        for (node_a, node_b) in pairs {
+            for node in [node_a, node_b] {
+                if !neighbors.contains_key(&node) {
+                    neighbors.insert(node, self.checked_distinct_neighbors(node)?);
+                }
+            }
+            out_list.push(Self::jaccard_from_neighbors(
+                &neighbors[&node_a],
+                &neighbors[&node_b],
+            ));
+        }
+        Ok(out_list)
+    }
+
+    /// Get the Adamic-Adar index of two nodes
+    ///
+    /// The Adamic-Adar index of two nodes ``u`` and ``v`` is the sum of
+    /// :math:`1 / \log |N(w)|` over their common neighbors ``w``, so that
+    /// common neighbors with fewer neighbors of their own weigh more.
+    /// Parallel edges are collapsed and self loops are ignored, both for the
+    /// common neighbors and their number of neighbors. Common neighbors with
+    /// a single neighbor, which only occur when ``node_a`` and ``node_b`` are
+    /// the same node, are skipped.
+    ///
+    /// :param int node_a: The index of the first node
+    /// :param int node_b: The index of the second node
+    ///
+    /// :returns: The Adamic-Adar index, ``0.0`` if the nodes have no common
+    ///     neighbor
+    /// :rtype: float
+    /// :raises IndexError: If either node is not in the graph
+    #[pyo3(text_signature = "(self, node_a, node_b, /)")]
+    pub fn adamic_adar_index(&self, node_a: usize, node_b: usize) -> PyResult<f64> {
+        let neighbors_a = self.checked_distinct_neighbors(node_a)?;
+        let neighbors_b = self.checked_distinct_neighbors(node_b)?;
+        Ok(Self::sorted_intersection(&neighbors_a, &neighbors_b)
+            .into_iter()
+            .map(|node| self.node_distinct_neighbors(NodeIndex::new(node)).len())
+            // A common neighbor of a single node with itself can have degree 1,
+            // which would contribute 1 / ln(1) = inf
+            .filter(|degree| *degree > 1)
+            .map(|degree| 1.0 / (degree as f64).ln())
+            .sum())
+    }
+
+    /// Get the nodes in the connected component containing a node.
+    ///
+    /// This only searches the component of ``node``, so unlike
+    /// :func:`~rustworkx.connected_components` it doesn't compute every
+    /// component of the graph.
+    ///
+    /// :param int node: The index of the node to get the component of
+    ///
+    /// :returns: A sorted list of the node indices in the connected component
+    ///     of ``node``, including ``node`` itself
+    /// :rtype: NodeIndices
+    /// :raises IndexError: If ``node`` is not present in the graph
     #[pyo3(text_signature = "(self, node, /)")]
-    pub fn out_edges(&self, py: Python, node: usize) -> WeightedEdgeList {
-        let index = NodeIndex::new(node);
-        let dir = petgraph::Direction::Outgoing;
-        let raw_edges = self.graph.edges_directed(index, dir);
-        let out_list: Vec<(usize, usize, PyObject)> = raw_edges
-            .map(|x| (node, x.target().index(), x.weight().clone_ref(py)))
+    pub fn node_connected_component(&self, node: usize) -> PyResult<NodeIndices> {
+        let node = NodeIndex::new(node);
+        if !self.graph.contains_node(node) {
+            return Err(PyIndexError::new_err("No node found for index"));
+        }
+        let mut bfs = Bfs::new(&self.graph, node);
+        let mut nodes: Vec<usize> = Vec::new();
+        while let Some(node) = bfs.next(&self.graph) {
+            nodes.push(node.index());
+        }
+        nodes.sort_unstable();
+        Ok(NodeIndices { nodes })
+    }
+
+    /// Get the ego graph of a node, the subgraph of the nodes within a
+    /// number of hops of it.
+    ///
+    /// A breadth-first search from ``node`` collects every node at a distance
+    /// of at most ``radius`` edges from it, and the subgraph induced by those
+    /// nodes is built directly from them, so the time needed only depends on
+    /// the size of the ego graph and the degree of its nodes. The nodes of
+    /// the subgraph are added in the order of their indices in this graph and
+    /// the edges in the order of their edge indices.
+    ///
+    /// :param int node: The index of the center node of the ego graph
+    /// :param int radius: The maximum number of edges between ``node`` and any
+    ///     node of the ego graph. Defaults to 1.
+    /// :param bool center: If set to ``False`` the center ``node`` and its
+    ///     edges are excluded from the ego graph. Defaults to ``True``.
+    ///
+    /// :returns: A tuple of the ego graph and a NodeMap object mapping the
+    ///     node indices of the ego graph to the node indices of this graph
+    /// :rtype: tuple[PyGraph, NodeMap]
+    /// :raises IndexError: If ``node`` is not present in the graph
+    #[pyo3(
+        signature=(node, /, radius=1, center=true),
+        text_signature = "(self, node, /, radius=1, center=True)"
+    )]
+    pub fn ego_graph(
+        &self,
+        py: Python,
+        node: usize,
+        radius: usize,
+        center: bool,
+    ) -> PyResult<(PyGraph, NodeMap)> {
+        let center_node = NodeIndex::new(node);
+        if !self.graph.contains_node(center_node) {
+            return Err(PyIndexError::new_err(format!(
+                "No node found for index: {node}"
+            )));
+        }
+        let mut visited: HashSet<NodeIndex> = HashSet::from([center_node]);
+        let mut frontier = vec![center_node];
+        for _ in 0..radius {
+            let mut next_frontier = Vec::new();
+            for node in frontier {
+                for neighbor in self.graph.neighbors(node) {
+                    if visited.insert(neighbor) {
+                        next_frontier.push(neighbor);
+                    }
+                }
+            }
+            if next_frontier.is_empty() {
+                break;
+            }
+            frontier = next_frontier;
+        }
+        if !center {
+            visited.remove(&center_node);
+        }
+        let mut edges: Vec<EdgeIndex> = visited
+            .iter()
+            .flat_map(|node| self.graph.edges(*node))
+            .filter(|edge| visited.contains(&edge.source()) && visited.contains(&edge.target()))
+            .map(|edge| edge.id())
             .collect();
-        WeightedEdgeList { edges: out_list }
+        edges.sort_unstable();
+        edges.dedup();
+        Ok(self.build_edge_subgraph(py, visited.into_iter().collect(), edges, false))
+    }
+
+    /// Enable incremental connectivity tracking for the graph
+    ///
+    /// This builds a union-find structure of the connected components of the
+    /// graph, after which :meth:`~rustworkx.PyGraph.are_connected` answers
+    /// in near constant time. The structure is kept up to date as nodes and
+    /// edges are added to the graph, which is useful when checking
+    /// connectivity while building a graph one edge at a time.
+    ///
+    /// A union-find can't split components, so removing a node or an edge
+    /// from the graph (or any other method that removes them, such as
+    /// :meth:`~rustworkx.PyGraph.contract_nodes` or
+    /// :meth:`~rustworkx.PyGraph.clear_edges`) disables the tracking. Copies
+    /// and unpickled graphs don't track connectivity either. Call this
+    /// method again to rebuild the structure. Use
+    /// :attr:`~rustworkx.PyGraph.connectivity_tracking` to check whether the
+    /// tracking is enabled.
+    ///
+    /// Calling this on a graph that is already tracking connectivity
+    /// rebuilds the structure.
+    #[pyo3(text_signature = "(self)")]
+    pub fn enable_connectivity_tracking(&mut self) {
+        let mut components = UnionFind::new(self.graph.node_bound());
+        for edge in self.graph.edge_references() {
+            components.union(edge.source().index(), edge.target().index());
+        }
+        self.connectivity = Some(components);
+    }
+
+    /// Whether incremental connectivity tracking is enabled, see
+    /// :meth:`~rustworkx.PyGraph.enable_connectivity_tracking`
+    #[getter]
+    fn connectivity_tracking(&self) -> bool {
+        self.connectivity.is_some()
+    }
+
+    /// Check whether there is a path between two nodes
+    ///
+    /// If connectivity tracking is enabled with
+    /// :meth:`~rustworkx.PyGraph.enable_connectivity_tracking` this runs in
+    /// near constant time. Otherwise it falls back to a breadth first search
+    /// from ``node_a``.
+    ///
+    /// :param int node_a: The index of the first node
+    /// :param int node_b: The index of the second node
+    ///
+    /// :returns: ``True`` if the nodes are in the same connected component,
+    ///     ``False`` otherwise
+    /// :rtype: bool
+    /// :raises IndexError: If either node is not present in the graph
+    #[pyo3(text_signature = "(self, node_a, node_b, /)")]
+    pub fn are_connected(&mut self, node_a: usize, node_b: usize) -> PyResult<bool> {
+        let index_a = NodeIndex::new(node_a);
+        let index_b = NodeIndex::new(node_b);
+        if !self.graph.contains_node(index_a) || !self.graph.contains_node(index_b) {
+            return Err(PyIndexError::new_err("No node found for index"));
+        }
+        match self.connectivity.as_mut() {
+            Some(components) => {
+                // nodes added since the last update are in their own component
+                while components.len() < self.graph.node_bound() {
+                    components.new_set();
+                }
+                Ok(components.find_mut(node_a) == components.find_mut(node_b))
+            }
+            None => {
+                let mut bfs = Bfs::new(&self.graph, index_a);
+                while let Some(node) = bfs.next(&self.graph) {
+                    if node == index_b {
+                        return Ok(true);
+                    }
+                }
+                Ok(false)
+            }
+        }
+    }
+
+    /// Check whether the graph is connected
+    ///
+    /// A graph is connected if there is a path between every pair of nodes.
+    /// This agrees with :func:`~rustworkx.is_connected`.
+    ///
+    /// :returns: ``True`` if the graph is connected, ``False`` otherwise
+    /// :rtype: bool
+    /// :raises NullGraph: If the graph has no nodes
+    #[pyo3(text_signature = "(self)")]
+    pub fn is_connected(&self) -> PyResult<bool> {
+        if self.graph.node_count() == 0 {
+            return Err(NullGraph::new_err("Invalid operation on a NullGraph"));
+        }
+        Ok(self.count_connected_components() == 1)
     }
 
-    /// Return the edge data for the edge by its given index
+    /// Get the number of connected components of the graph
     ///
-    /// :param int edge_index: The edge index to get the data for
+    /// This agrees with :func:`~rustworkx.number_connected_components`, and
+    /// is 0 for a graph without nodes.
     ///
-    /// :returns: The data object for the edge
-    /// :rtype: T
-    /// :raises IndexError: when there is no edge present with the provided
-    ///     index
-    #[pyo3(text_signature = "(self, edge_index, /)")]
-    pub fn get_edge_data_by_index(&self, edge_index: usize) -> PyResult<&PyObject> {
-        let data = match self.graph.edge_weight(EdgeIndex::new(edge_index)) {
-            Some(data) => data,
-            None => {
-                return Err(PyIndexError::new_err(format!(
-                    "Provided edge index {edge_index} is not present in the graph"
-                )));
-            }
-        };
-        Ok(data)
+    /// :returns: The number of connected components of the graph
+    /// :rtype: int
+    #[pyo3(text_signature = "(self)")]
+    pub fn number_connected_components(&self) -> usize {
+        self.count_connected_components()
     }
 
-    /// Return the edge endpoints for the edge by its given index
+    /// Check whether the graph is a forest
     ///
-    /// :param int edge_index: The edge index to get the endpoints for
+    /// A forest is a graph without cycles, which may be disconnected. Self
+    /// loops and parallel edges are cycles, so a graph with either is not a
+    /// forest. A graph without nodes is a forest.
     ///
-    /// :returns: The endpoint tuple for the edge
-    /// :rtype: tuple[int, int]
-    /// :raises IndexError: when there is no edge present with the provided
-    ///     index
-    #[pyo3(text_signature = "(self, edge_index, /)")]
-    pub fn get_edge_endpoints_by_index(&self, edge_index: usize) -> PyResult<(usize, usize)> {
-        let endpoints = match self.graph.edge_endpoints(EdgeIndex::new(edge_index)) {
-            Some(endpoints) => (endpoints.0.index(), endpoints.1.index()),
-            None => {
-                return Err(PyIndexError::new_err(format!(
-                    "Provided edge index {edge_index} is not present in the graph"
-                )));
-            }
-        };
-        Ok(endpoints)
+    /// :returns: ``True`` if the graph is a forest, ``False`` otherwise
+    /// :rtype: bool
+    #[pyo3(text_signature = "(self)")]
+    pub fn is_forest(&self) -> bool {
+        // Every edge of a forest merges two components, so a graph is a
+        // forest exactly when it has one less edge than nodes per component
+        self.graph.edge_count() + self.count_connected_components() == self.graph.node_count()
     }
 
-    /// Update an edge's weight/payload in place
+    /// Check whether the graph is a tree
     ///
-    /// If there are parallel edges in the graph only one edge will be updated.
-    /// if you need to update a specific edge or need to ensure all parallel
-    /// edges get updated you should use
-    /// :meth:`~rustworkx.PyGraph.update_edge_by_index` instead.
+    /// A tree is a connected graph without cycles, i.e. a connected forest
+    /// (see :meth:`~rustworkx.PyGraph.is_forest`). Self loops and parallel
+    /// edges are cycles, so a graph with either is not a tree. A graph with a
+    /// single node is a tree, while a graph without nodes is not.
     ///
-    /// :param int source: The index of the first node
-    /// :param int target: The index of the second node
+    /// :returns: ``True`` if the graph is a tree, ``False`` otherwise
+    /// :rtype: bool
+    #[pyo3(text_signature = "(self)")]
+    pub fn is_tree(&self) -> bool {
+        let node_count = self.graph.node_count();
+        node_count > 0
+            && self.graph.edge_count() == node_count - 1
+            && self.count_connected_components() == 1
+    }
+
+    /// Get the edges of a spanning forest of the graph
+    ///
+    /// A breadth-first search is run from the lowest unvisited node index
+    /// until every node is visited, and the edge through which each node is
+    /// first reached is part of the spanning forest. The result contains a
+    /// spanning tree for every connected component of the graph, so it has
+    /// ``len(graph) - graph.number_connected_components()`` edges. Unlike
+    /// :func:`~rustworkx.minimum_spanning_edges` the edge weights are not
+    /// taken into account.
+    ///
+    /// The edges are returned as edge indices, which can be passed to
+    /// :meth:`~rustworkx.PyGraph.edge_subgraph` with ``by_index=True`` to get
+    /// the spanning forest as a graph.
+    ///
+    /// :returns: The indices of the edges of the spanning forest in the order
+    ///     they were found by the search
+    /// :rtype: EdgeIndices
+    #[pyo3(text_signature = "(self)")]
+    pub fn spanning_forest_edges(&self) -> EdgeIndices {
+        let mut visited = FixedBitSet::with_capacity(self.graph.node_bound());
+        let mut edges = Vec::with_capacity(self.graph.node_count().saturating_sub(1));
+        let mut queue = Vec::with_capacity(self.graph.node_count());
+        for root in self.graph.node_indices() {
+            if visited.put(root.index()) {
+                continue;
+            }
+            // Nodes are never removed from the queue, only skipped over
+            let mut head = queue.len();
+            queue.push(root);
+            while head < queue.len() {
+                let node = queue[head];
+                head += 1;
+                for edge in self.graph.edges(node) {
+                    let neighbor = edge.target();
+                    if !visited.put(neighbor.index()) {
+                        edges.push(edge.id().index());
+                        queue.push(neighbor);
+                    }
+                }
+            }
+        }
+        EdgeIndices { edges }
+    }
+
+    /// Check whether the graph is bipartite
     ///
-    /// :raises NoEdgeBetweenNodes: When there is no edge between nodes
-    #[pyo3(text_signature = "(self, source, target, edge, /)")]
-    pub fn update_edge(&mut self, source: usize, target: usize, edge: PyObject) -> PyResult<()> {
-        let index_a = NodeIndex::new(source);
-        let index_b = NodeIndex::new(target);
-        let edge_index = match self.graph.find_edge(index_a, index_b) {
-            Some(edge_index) => edge_index,
-            None => return Err(NoEdgeBetweenNodes::new_err("No edge found between nodes")),
-        };
-        let data = self.graph.edge_weight_mut(edge_index).unwrap();
-        *data = edge;
-        Ok(())
+    /// A graph is bipartite if its nodes can be split into two sets such that
+    /// every edge connects a node in one set to a node in the other. A graph
+    /// with a self loop is never bipartite.
+    ///
+    /// :returns: ``True`` if the graph is bipartite, ``False`` otherwise
+    /// :rtype: bool
+    #[pyo3(text_signature = "(self)")]
+    pub fn is_bipartite(&self) -> bool {
+        two_color(&self.graph).is_some()
     }
 
-    /// Update an edge's weight/data payload in place by the edge index
+    /// Compute a two-coloring of the graph
     ///
-    /// :param int edge_index: The index of the edge
-    /// :param T edge: The python object to attach to the edge
+    /// Each connected component is colored independently with a breadth first
+    /// search, so disconnected graphs are supported. See
+    /// :func:`~rustworkx.two_color` for the equivalent function.
     ///
-    /// :raises IndexError: when there is no edge present with the provided
-    ///     index
-    #[pyo3(text_signature = "(self, edge_index, edge, /)")]
-    pub fn update_edge_by_index(&mut self, edge_index: usize, edge: PyObject) -> PyResult<()> {
-        match self.graph.edge_weight_mut(EdgeIndex::new(edge_index)) {
-            Some(data) => *data = edge,
-            None => return Err(PyIndexError::new_err("No edge found for index")),
-        };
-        Ok(())
+    /// :returns: A dictionary mapping each node index to its color (``0`` or
+    ///     ``1``), or ``None`` if the graph is not bipartite
+    /// :rtype: dict[int, int] | None
+    #[pyo3(text_signature = "(self)")]
+    pub fn bipartite_coloring(&self) -> Option<DictMap<usize, u8>> {
+        two_color(&self.graph).map(|colors| {
+            colors
+                .into_iter()
+                .map(|(node, color)| (node.index(), color))
+                .collect()
+        })
     }
 
-    /// Return the node data for a given node index
+    /// Get the degree for a node
     ///
-    /// :param int node: The index of the node
+    /// :param int node: The index of the node to find the inbound degree of
     ///
-    /// :returns: The data object set for that node
-    /// :rtype: S
-    /// :raises IndexError: when an invalid node index is provided
+    /// :returns degree: The inbound degree of the specified node
+    /// :rtype: int
     #[pyo3(text_signature = "(self, node, /)")]
-    pub fn get_node_data(&self, node: usize) -> PyResult<&PyObject> {
+    pub fn degree(&self, node: usize) -> usize {
         let index = NodeIndex::new(node);
-        let node = match self.graph.node_weight(index) {
-            Some(node) => node,
-            None => return Err(PyIndexError::new_err("No node found for index")),
-        };
-        Ok(node)
+        let neighbors = self.graph.edges(index);
+        neighbors.fold(0, |count, edge| {
+            if edge.source() == edge.target() {
+                return count + 2;
+            }
+            count + 1
+        })
     }
 
-    /// Return the edge data for all the edges between 2 nodes.
-    ///
-    /// :param int node_a: The index of the first node
-    /// :param int node_b: The index of the second node
-    ///
-    /// :returns: A list with all the data objects for the edges between nodes
-    /// :rtype: list[T]
-    /// :raises NoEdgeBetweenNodes: When there is no edge between nodes
-    #[pyo3(text_signature = "(self, node_a, node_b, /)")]
-    pub fn get_all_edge_data(&self, node_a: usize, node_b: usize) -> PyResult<Vec<&PyObject>> {
-        let index_a = NodeIndex::new(node_a);
-        let index_b = NodeIndex::new(node_b);
-        let out: Vec<&PyObject> = self
+    /// Get the degree sequence of the graph
+    ///
+    /// The degree of every node in the graph is computed like
+    /// :meth:`~rustworkx.PyGraph.degree`, so a self loop adds 2 to the degree
+    /// of its node, in a single pass over the edges of the graph.
+    ///
+    /// :param bool descending: If set to ``True`` (the default) the degrees
+    ///     are sorted in descending order, otherwise they are sorted in
+    ///     ascending order.
+    ///
+    /// :returns: The sorted degrees of all the nodes in the graph
+    /// :rtype: list[int]
+    #[pyo3(signature=(descending=true), text_signature = "(self, /, descending=True)")]
+    pub fn degree_sequence(&self, descending: bool) -> Vec<usize> {
+        let degrees = self.node_degrees();
+        let mut sequence: Vec<usize> = self
             .graph
-            .edges(index_a)
-            .filter(|edge| edge.target() == index_b)
-            .map(|edge| edge.weight())
+            .node_indices()
+            .map(|node| degrees[node.index()])
             .collect();
-        if out.is_empty() {
-            Err(NoEdgeBetweenNodes::new_err("No edge found between nodes"))
+        if descending {
+            sequence.sort_unstable_by(|a, b| b.cmp(a));
         } else {
-            Ok(out)
+            sequence.sort_unstable();
         }
+        sequence
     }
 
-    /// Get edge list
+    /// Get the number of triangles in the graph
     ///
-    /// Returns a list of tuples of the form ``(source, target)`` where
-    /// ``source`` and ``target`` are the node indices.
+    /// A triangle is a set of three distinct nodes that are all adjacent to
+    /// each other. Parallel edges are collapsed, so three nodes connected by
+    /// several edges still form a single triangle, and self loops are
+    /// ignored.
     ///
-    /// :returns: An edge list without weights
-    /// :rtype: EdgeList
+    /// :returns: The number of triangles in the graph
+    /// :rtype: int
     #[pyo3(text_signature = "(self)")]
-    pub fn edge_list(&self) -> EdgeList {
-        EdgeList {
-            edges: self
-                .graph
-                .edge_references()
-                .map(|edge| (edge.source().index(), edge.target().index()))
-                .collect(),
-        }
+    pub fn triangle_count(&self) -> usize {
+        self.node_triangles().iter().sum::<usize>() / 3
     }
 
-    /// Get edge list with weights
+    /// Get the number of triangles every node is part of
     ///
-    /// Returns a list of tuples of the form ``(source, target, weight)`` where
-    /// ``source`` and ``target`` are the node indices and ``weight`` is the
-    /// payload of the edge.
+    /// Like :meth:`~rustworkx.PyGraph.triangle_count`, parallel edges are
+    /// collapsed and self loops are ignored.
     ///
-    /// :returns: An edge list with weights
-    /// :rtype: WeightedEdgeList
+    /// :returns: A dictionary mapping each node index, in ascending order, to
+    ///     the number of triangles it is part of
+    /// :rtype: dict[int, int]
     #[pyo3(text_signature = "(self)")]
-    pub fn weighted_edge_list(&self, py: Python) -> WeightedEdgeList {
-        WeightedEdgeList {
-            edges: self
-                .graph
-                .edge_references()
-                .map(|edge| {
-                    (
-                        edge.source().index(),
-                        edge.target().index(),
-                        edge.weight().clone_ref(py),
-                    )
-                })
-                .collect(),
+    pub fn triangles_per_node(&self) -> DictMap<usize, usize> {
+        let triangles = self.node_triangles();
+        self.graph
+            .node_indices()
+            .map(|node| (node.index(), triangles[node.index()]))
+            .collect()
+    }
+
+    /// Get the local clustering coefficient of a node or of every node
+    ///
+    /// The local clustering coefficient of a node is the fraction of pairs of
+    /// its neighbors that are adjacent to each other, i.e.
+    /// :math:`\frac{2T(v)}{d(v)(d(v) - 1)}` where :math:`T(v)` is the number of
+    /// triangles the node :math:`v` is part of and :math:`d(v)` is its number
+    /// of distinct neighbors. Parallel edges are collapsed and self loops are
+    /// ignored, so the result for a multigraph is the same as for
+    /// :meth:`~rustworkx.PyGraph.to_simple_graph` without its self loops.
+    /// Nodes with fewer than two distinct neighbors have a clustering
+    /// coefficient of ``0.0``.
+    ///
+    /// :param int node: The index of the node to get the clustering
+    ///     coefficient of. If not specified the clustering coefficients of
+    ///     all the nodes are returned.
+    ///
+    /// :returns: The clustering coefficient of ``node`` if it's specified,
+    ///     otherwise a dictionary mapping each node index, in ascending
+    ///     order, to its clustering coefficient
+    /// :rtype: float | dict[int, float]
+    /// :raises IndexError: If ``node`` is not present in the graph
+    #[pyo3(signature=(node=None), text_signature = "(self, node=None)")]
+    pub fn clustering_coefficient(&self, py: Python, node: Option<usize>) -> PyResult<PyObject> {
+        match node {
+            Some(node) => {
+                let index = NodeIndex::new(node);
+                if !self.graph.contains_node(index) {
+                    return Err(PyIndexError::new_err(format!(
+                        "No node found for index: {node}"
+                    )));
+                }
+                let neighbors = self.node_distinct_neighbors(index);
+                // Each edge between two neighbors is seen from both ends
+                let neighbor_edges: usize = neighbors
+                    .iter()
+                    .map(|neighbor| {
+                        self.node_distinct_neighbors(NodeIndex::new(*neighbor))
+                            .iter()
+                            .filter(|other| neighbors.binary_search(other).is_ok())
+                            .count()
+                    })
+                    .sum();
+                Self::clustering_from_triangles(neighbor_edges / 2, neighbors.len()).into_py_any(py)
+            }
+            None => {
+                let clustering = self.node_clustering();
+                let out_map: DictMap<usize, f64> = self
+                    .graph
+                    .node_indices()
+                    .map(|node| (node.index(), clustering[node.index()]))
+                    .collect();
+                out_map.into_py_any(py)
+            }
         }
     }
 
-    /// Get an edge index map
+    /// Get the average clustering coefficient of the graph
     ///
-    /// Returns a read only mapping from edge indices to the weighted edge
-    /// tuple in the form: ``{0: (0, 1, "weight"), 1: (2, 3, 2.3)}``
+    /// This is the mean of the local clustering coefficients of all the
+    /// nodes, as returned by :meth:`~rustworkx.PyGraph.clustering_coefficient`,
+    /// including the nodes with a clustering coefficient of ``0.0``.
     ///
-    /// :returns: An edge index map
-    /// :rtype: EdgeIndexMap
+    /// :returns: The average clustering coefficient, ``0.0`` for a graph
+    ///     without nodes
+    /// :rtype: float
     #[pyo3(text_signature = "(self)")]
-    pub fn edge_index_map(&self, py: Python) -> EdgeIndexMap {
-        EdgeIndexMap {
-            edge_map: self
-                .graph
-                .edge_references()
-                .map(|edge| {
-                    (
-                        edge.id().index(),
-                        (
-                            edge.source().index(),
-                            edge.target().index(),
-                            edge.weight().clone_ref(py),
-                        ),
-                    )
-                })
-                .collect(),
+    pub fn average_clustering(&self) -> f64 {
+        if self.graph.node_count() == 0 {
+            return 0.0;
         }
+        let clustering = self.node_clustering();
+        let total: f64 = self
+            .graph
+            .node_indices()
+            .map(|node| clustering[node.index()])
+            .sum();
+        total / self.graph.node_count() as f64
     }
 
-    /// Remove a node from the graph.
-    ///
-    /// :param int node: The index of the node to remove. If the index is not
-    ///     present in the graph it will be ignored and this function will
-    ///     have no effect.
-    #[pyo3(text_signature = "(self, node, /)")]
-    pub fn remove_node(&mut self, node: usize) -> PyResult<()> {
-        let index = NodeIndex::new(node);
-        self.graph.remove_node(index);
-        self.node_removed = true;
-        Ok(())
+    /// Get the degree assortativity coefficient of the graph
+    ///
+    /// This is the Pearson correlation coefficient of the degrees of the
+    /// endpoints of the edges, as defined by Newman [1]_. It is positive if
+    /// nodes tend to be connected to nodes with a similar degree and negative
+    /// if high degree nodes tend to be connected to low degree nodes. The
+    /// degrees are the ones returned by :meth:`~rustworkx.PyGraph.degree`, so
+    /// a self loop counts twice, and every edge is counted once, including
+    /// each parallel edge and self loop.
+    ///
+    /// :returns: The degree assortativity coefficient, which is between
+    ///     ``-1.0`` and ``1.0``. It is ``nan`` if the coefficient is undefined
+    ///     because the graph has no edges or the endpoints of every edge have
+    ///     the same degree, for example in a graph with a single edge or a
+    ///     regular graph.
+    /// :rtype: float
+    ///
+    /// .. [1] M. E. J. Newman, "Assortative mixing in networks",
+    ///    Physical Review Letters 89, 208701 (2002).
+    ///    https://doi.org/10.1103/PhysRevLett.89.208701
+    #[pyo3(text_signature = "(self)")]
+    pub fn degree_assortativity(&self) -> f64 {
+        let degrees = self.node_degrees();
+        let mut sum_product = 0.0;
+        let mut sum_mean = 0.0;
+        let mut sum_square_mean = 0.0;
+        for edge in self.graph.edge_references() {
+            let source = degrees[edge.source().index()] as f64;
+            let target = degrees[edge.target().index()] as f64;
+            sum_product += source * target;
+            sum_mean += (source + target) / 2.0;
+            sum_square_mean += (source * source + target * target) / 2.0;
+        }
+        let edge_count = self.graph.edge_count() as f64;
+        let mean = sum_mean / edge_count;
+        let variance = sum_square_mean / edge_count - mean * mean;
+        // The sums of integer degrees are exact, so the variance is exactly
+        // zero if every endpoint has the same degree
+        if self.graph.edge_count() == 0 || variance <= 0.0 {
+            return f64::NAN;
+        }
+        (sum_product / edge_count - mean * mean) / variance
     }
 
-    /// Add an edge between 2 nodes.
+    /// Get the core number of every node in the graph
     ///
-    /// If :attr:`~rustworkx.PyGraph.multigraph` is ``False`` and an edge already
-    /// exists between ``node_a`` and ``node_b`` the weight/payload of that
-    /// existing edge will be updated to be ``edge``.
+    /// A k-core is a maximal subgraph in which every node has degree at least
+    /// k, and the core number of a node is the largest k such that the node
+    /// is in a k-core. The core numbers are computed in linear time by
+    /// repeatedly removing the node of lowest degree.
     ///
-    /// :param int node_a: The index of the parent node
-    /// :param int node_b: The index of the child node
-    /// :param T edge: The python object to attach to the edge
+    /// The degree of a node is its number of distinct neighbors: parallel
+    /// edges between two nodes count as a single edge and self loops are
+    /// ignored, so the result for a multigraph is the same as for
+    /// :meth:`~rustworkx.PyGraph.to_simple_graph` without its self loops.
     ///
-    /// :returns: The index of the newly created (or updated in the case
-    ///     of an existing edge with ``multigraph=False``) edge.
-    /// :rtype: int
-    #[pyo3(text_signature = "(self, node_a, node_b, edge, /)")]
-    pub fn add_edge(&mut self, node_a: usize, node_b: usize, edge: PyObject) -> PyResult<usize> {
-        let p_index = NodeIndex::new(node_a);
-        let c_index = NodeIndex::new(node_b);
-        if !self.graph.contains_node(p_index) || !self.graph.contains_node(c_index) {
-            return Err(PyIndexError::new_err(
-                "One of the endpoints of the edge does not exist in graph",
-            ));
-        }
-        Ok(self._add_edge(p_index, c_index, edge))
+    /// :returns: A dictionary mapping each node index, in ascending order, to
+    ///     its core number
+    /// :rtype: dict[int, int]
+    #[pyo3(text_signature = "(self)")]
+    pub fn core_number(&self) -> DictMap<usize, usize> {
+        let cores = self.core_numbers();
+        self.graph
+            .node_indices()
+            .map(|node| (node.index(), cores[node.index()]))
+            .collect()
     }
 
-    /// Add new edges to the graph.
+    /// Get the k-core of the graph
     ///
-    /// :param iterable[tuple[int, int, T]] obj_list: An iterable of tuples of the form
-    ///     ``(node_a, node_b, T)`` to attach to the graph. ``node_a`` and
-    ///     ``node_b`` are integer indices describing where an edge should be
-    ///     added, and ``T`` is the python object for the edge data.
+    /// The k-core is the subgraph induced by the nodes with a core number of
+    /// at least ``k``, as returned by :meth:`~rustworkx.PyGraph.core_number`,
+    /// so parallel edges count once and self loops are ignored when computing
+    /// it. The subgraph itself keeps all the edges between its nodes,
+    /// including parallel edges and self loops.
     ///
-    /// If :attr:`~rustworkx.PyGraph.multigraph` is ``False`` and an edge already
-    /// exists between ``node_a`` and ``node_b`` the weight/payload of that
-    /// existing edge will be updated to be ``edge``. This will occur in order
-    /// from ``obj_list`` so if there are multiple parallel edges in ``obj_list``
-    /// the last entry will be used.
+    /// :param int k: The minimum core number of the nodes of the subgraph
     ///
-    /// :returns: A list of indices of the newly created edges
-    /// :rtype: EdgeIndices
-    #[pyo3(text_signature = "(self, obj_list, /)")]
-    pub fn add_edges_from(&mut self, obj_list: Bound<'_, PyAny>) -> PyResult<EdgeIndices> {
-        let mut out_list = Vec::new();
-        for py_obj in obj_list.try_iter()? {
-            let obj = py_obj?.extract::<(usize, usize, PyObject)>()?;
-            out_list.push(self.add_edge(obj.0, obj.1, obj.2)?);
-        }
-        Ok(EdgeIndices { edges: out_list })
+    /// :returns: A tuple of the k-core and a NodeMap object mapping the node
+    ///     indices of the k-core to the node indices of this graph
+    /// :rtype: tuple[PyGraph, NodeMap]
+    #[pyo3(text_signature = "(self, k, /)")]
+    pub fn k_core(&self, py: Python, k: usize) -> (PyGraph, NodeMap) {
+        let cores = self.core_numbers();
+        let (subgraph, node_map, _) = self.build_node_subgraph(
+            py,
+            |node| cores[node.index()] >= k,
+            self.graph.node_count(),
+            false,
+            false,
+        );
+        (subgraph, node_map)
     }
-
-    /// Add new edges to the graph without python data.
-    ///
-    /// :param iterable[tuple[int, int]] obj_list: An iterable of tuples of the form
-    ///     ``(parent, child)`` to attach to the graph. ``parent`` and
-    ///     ``child`` are integer indices describing where an edge should be
-    ///     added. Unlike :meth:`add_edges_from` there is no data payload and
-    ///     when the edge is created None will be used.
+
+    /// Get the degree histogram of the graph
     ///
-    /// If :attr:`~rustworkx.PyGraph.multigraph` is ``False`` and an edge already
-    /// exists between ``node_a`` and ``node_b`` the weight/payload of that
-    /// existing edge will be updated to be ``None``.
+    /// The degree of every node is computed like
+    /// :meth:`~rustworkx.PyGraph.degree`, so a self loop adds 2 to the degree
+    /// of its node, in a single pass over the edges of the graph.
     ///
-    /// :returns: A list of indices of the newly created edges
-    /// :rtype: EdgeIndices
-    #[pyo3(text_signature = "(self, obj_list, /)")]
-    pub fn add_edges_from_no_data(
-        &mut self,
-        py: Python,
-        obj_list: Bound<'_, PyAny>,
-    ) -> PyResult<EdgeIndices> {
-        let mut out_list: Vec<usize> = Vec::new();
-        for py_obj in obj_list.try_iter()? {
-            let obj = py_obj?.extract::<(usize, usize)>()?;
-            out_list.push(self.add_edge(obj.0, obj.1, py.None())?);
+    /// :returns: A dictionary mapping each degree value to the number of
+    ///     nodes with that degree, in ascending order of degree. Degree
+    ///     values that no node has are not included.
+    /// :rtype: dict[int, int]
+    #[pyo3(text_signature = "(self)")]
+    pub fn degree_histogram(&self) -> BTreeMap<usize, usize> {
+        let degrees = self.node_degrees();
+        let mut histogram = BTreeMap::new();
+        for node in self.graph.node_indices() {
+            *histogram.entry(degrees[node.index()]).or_insert(0) += 1;
         }
-        Ok(EdgeIndices { edges: out_list })
+        histogram
     }
 
-    /// Extend graph from an edge list
+    /// Generate a new :class:`~rustworkx.PyDiGraph` object from this graph
     ///
-    /// This method differs from :meth:`add_edges_from_no_data` in that it will
-    /// add nodes if a node index is not present in the edge list.
+    /// This will create a new :class:`~rustworkx.PyDiGraph` object from this
+    /// graph. By default all edges in this graph will result in a
+    /// bidirectional edge pair in the output graph.
     ///
-    /// If :attr:`~rustworkx.PyGraph.multigraph` is ``False`` and an edge already
-    /// exists between ``node_a`` and ``node_b`` the weight/payload of that
-    /// existing edge will be updated to be ``None``.
+    /// .. note::
     ///
-    /// :param iterable[tuple[int, int]] edge_list: An iterable of tuples
-    ///     in the form ``(source, target)`` where ``source`` and ``target``
-    ///     are integer node indices. If the node index
-    ///     is not present in the graph, nodes will be added (with a node
-    ///     weight of ``None``) to that index.
-    #[pyo3(text_signature = "(self, edge_list, /)")]
-    pub fn extend_from_edge_list(
-        &mut self,
+    ///     The node indices in the output :class:`~rustworkx.PyDiGraph` may
+    ///     differ if nodes have been removed.
+    ///
+    /// :param bool bidirectional: If set to ``False`` each edge in this graph
+    ///     results in a single edge in the output graph, directed from the
+    ///     first to the second endpoint as returned by
+    ///     :meth:`~rustworkx.PyGraph.get_edge_endpoints_by_index`. By default
+    ///     this is ``True``.
+    /// :param bool edge_map: If set to ``True`` a dictionary mapping the edge
+    ///     indices of the output graph to the index of the edge in this graph
+    ///     they were created from is returned along with the output graph.
+    ///     By default this is ``False``.
+    ///
+    /// :returns: A new :class:`~rustworkx.PyDiGraph` object with a
+    ///     bidirectional edge pair (or a single edge) for each edge in this
+    ///     graph. Also all node and edge weights/data payloads are copied by
+    ///     reference to the output graph. If ``edge_map`` is ``True`` a tuple
+    ///     of the output graph and the edge index mapping is returned instead.
+    /// :rtype: PyDiGraph or tuple[PyDiGraph, dict[int, int]]
+    #[pyo3(
+        signature=(bidirectional=true, edge_map=false),
+        text_signature = "(self, /, bidirectional=True, edge_map=False)"
+    )]
+    pub fn to_directed(
+        &self,
         py: Python,
-        edge_list: Bound<'_, PyAny>,
-    ) -> PyResult<()> {
-        for py_obj in edge_list.try_iter()? {
-            let (source, target) = py_obj?.extract::<(usize, usize)>()?;
-            let max_index = cmp::max(source, target);
-            while max_index >= self.node_count() {
-                self.graph.add_node(py.None());
+        bidirectional: bool,
+        edge_map: bool,
+    ) -> PyResult<PyObject> {
+        let node_count = self.node_count();
+        let edge_count = if bidirectional {
+            2 * self.graph.edge_count()
+        } else {
+            self.graph.edge_count()
+        };
+        let mut new_graph = StablePyGraph::<Directed>::with_capacity(node_count, edge_count);
+        let mut node_map: HashMap<NodeIndex, NodeIndex> = HashMap::with_capacity(node_count);
+        for node_index in self.graph.node_indices() {
+            let node = self.graph[node_index].clone_ref(py);
+            let new_index = new_graph.add_node(node);
+            node_map.insert(node_index, new_index);
+        }
+        let mut edge_index_map: DictMap<usize, usize> = if edge_map {
+            DictMap::with_capacity(edge_count)
+        } else {
+            DictMap::new()
+        };
+        for edge in self.graph.edge_references() {
+            let &source = node_map.get(&edge.source()).unwrap();
+            let &target = node_map.get(&edge.target()).unwrap();
+            let weight = edge.weight();
+            let forward = new_graph.add_edge(source, target, weight.clone_ref(py));
+            if edge_map {
+                edge_index_map.insert(forward.index(), edge.id().index());
+            }
+            if bidirectional {
+                let backward = new_graph.add_edge(target, source, weight.clone_ref(py));
+                if edge_map {
+                    edge_index_map.insert(backward.index(), edge.id().index());
+                }
             }
-            let source_index = NodeIndex::new(source);
-            let target_index = NodeIndex::new(target);
-            self._add_edge(source_index, target_index, py.None());
         }
-        Ok(())
+        let digraph = crate::digraph::PyDiGraph {
+            graph: new_graph,
+            node_removed: false,
+            cycle_state: algo::DfsSpace::default(),
+            check_cycle: false,
+            multigraph: self.multigraph,
+            attrs: py.None(),
+        };
+        if edge_map {
+            (digraph, edge_index_map).into_py_any(py)
+        } else {
+            digraph.into_py_any(py)
+        }
     }
 
-    /// Extend graph from a weighted edge list
+    /// Generate a dot file from the graph
     ///
-    /// This method differs from :meth:`add_edges_from` in that it will
-    /// add nodes if a node index is not present in the edge list.
+    /// :param node_attr: A callable that will take in a node data object
+    ///     and return a dictionary of attributes to be associated with the
+    ///     node in the dot file. The key and value of this dictionary **must**
+    ///     be a string. If they're not strings rustworkx will raise TypeError
+    ///     (unfortunately without an error message because of current
+    ///     limitations in the PyO3 type checking)
+    /// :param edge_attr: A callable that will take in an edge data object
+    ///     and return a dictionary of attributes to be associated with the
+    ///     node in the dot file. The key and value of this dictionary **must**
+    ///     be a string. If they're not strings rustworkx will raise TypeError
+    ///     (unfortunately without an error message because of current
+    ///     limitations in the PyO3 type checking)
+    /// :param dict[str, str] graph_attr: An optional dictionary that specifies any graph
+    ///     attributes for the output dot file. The key and value of this
+    ///     dictionary **must** be a string. If they're not strings rustworkx
+    ///     will raise TypeError (unfortunately without an error message
+    ///     because of current limitations in the PyO3 type checking)
+    /// :param str filename: An optional path to write the dot file to
+    ///     if specified there is no return from the function
+    /// :param bool use_attrs_as_graph_attr: If set to ``True`` the
+    ///     :attr:`~rustworkx.PyGraph.attrs` of the graph are emitted as graph
+    ///     attributes in the dot file, merged with ``graph_attr``. On a key
+    ///     conflict the value in ``graph_attr`` takes precedence. The
+    ///     ``attrs`` must be ``None`` or a dictionary whose keys and values
+    ///     are strings, otherwise a TypeError is raised. Defaults to
+    ///     ``False``.
+    /// :param bool show_stats: If set to ``True`` a graph ``label`` with the
+    ///     number of nodes and edges, for example ``"|V|=5 |E|=7"``, is added
+    ///     to the dot file. A ``label`` set in ``graph_attr`` (or in the graph
+    ///     ``attrs`` with ``use_attrs_as_graph_attr``) takes precedence and is
+    ///     left unchanged. Defaults to ``False``.
+    /// :param list[list[int]] rank_groups: An optional list of groups of node
+    ///     indices. Each group is emitted as a ``{rank=same; ...}`` subgraph so
+    ///     that graphviz places the nodes of the group on the same rank. An
+    ///     IndexError is raised if any of the nodes is not in the graph.
+    /// :param file: An optional file-like object opened in text mode to write
+    ///     the dot file to, such as a pipe, a socket file or a file opened
+    ///     with :func:`gzip.open`. The output is passed to its ``write()``
+    ///     method incrementally in chunks, so the full dot file is never held
+    ///     in memory. If specified there is no return from the function. This
+    ///     can't be used together with ``filename``.
+    ///
+    /// The dictionaries returned by ``edge_attr`` may also contain the
+    /// ``tailport`` and ``headport`` keys. These are emitted as port
+    /// specifiers on the endpoints of the edge, for example ``0:e -- 1:w``,
+    /// instead of as edge attributes.
+    ///
+    /// :returns: A string with the dot file contents if neither filename nor
+    ///     file is specified.
+    /// :rtype: str
+    /// :raises ValueError: If both ``filename`` and ``file`` are specified
     ///
-    /// If :attr:`~rustworkx.PyGraph.multigraph` is ``False`` and an edge already
-    /// exists between ``node_a`` and ``node_b`` the weight/payload of that
-    /// existing edge will be updated to be ``edge``. This will occur in order
-    /// from ``obj_list`` so if there are multiple parallel edges in ``obj_list``
-    /// the last entry will be used.
+    /// Using this method enables you to leverage graphviz to visualize a
+    /// :class:`rustworkx.PyGraph` object. For example:
     ///
-    /// :param iterable[tuple[int, int, T]] edge_list: An iterable of tuples in the form
-    ///     ``(source, target, weight)`` where source and target are integer
-    ///     node indices. If the node index is not present in the graph,
-    ///     nodes will be added (with a node weight of ``None``) to that index.
-    #[pyo3(text_signature = "(self, edge_list, /)")]
-    pub fn extend_from_weighted_edge_list(
-        &mut self,
-        py: Python,
-        edge_list: Bound<'_, PyAny>,
-    ) -> PyResult<()> {
-        for py_obj in edge_list.try_iter()? {
-            let (source, target, weight) = py_obj?.extract::<(usize, usize, PyObject)>()?;
-            let max_index = cmp::max(source, target);
-            while max_index >= self.node_count() {
-                self.graph.add_node(py.None());
+    /// .. jupyter-execute::
+    ///
+    ///   import os
+    ///   import tempfile
+    ///
+    ///   import pydot
+    ///   from PIL import Image
+    ///
+    ///   import rustworkx as rx
+    ///
+    ///   graph = rx.undirected_gnp_random_graph(15, .25)
+    ///   dot_str = graph.to_dot(
+    ///       lambda node: dict(
+    ///           color='black', fillcolor='lightblue', style='filled'))
+    ///   dot = pydot.graph_from_dot_data(dot_str)[0]
+    ///
+    ///   with tempfile.TemporaryDirectory() as tmpdirname:
+    ///       tmp_path = os.path.join(tmpdirname, 'dag.png')
+    ///       dot.write_png(tmp_path)
+    ///       image = Image.open(tmp_path)
+    ///       os.remove(tmp_path)
+    ///   image
+    ///
+    #[pyo3(
+        text_signature = "(self, /, node_attr=None, edge_attr=None, graph_attr=None, filename=None, use_attrs_as_graph_attr=False, show_stats=False, rank_groups=None, file=None)",
+        signature = (node_attr=None, edge_attr=None, graph_attr=None, filename=None, use_attrs_as_graph_attr=false, show_stats=false, rank_groups=None, file=None)
+    )]
+    #[allow(clippy::too_many_arguments)]
+    pub fn to_dot<'py>(
+        &self,
+        py: Python<'py>,
+        node_attr: Option<PyObject>,
+        edge_attr: Option<PyObject>,
+        graph_attr: Option<BTreeMap<String, String>>,
+        filename: Option<String>,
+        use_attrs_as_graph_attr: bool,
+        show_stats: bool,
+        rank_groups: Option<Vec<Vec<usize>>>,
+        file: Option<Bound<'py, PyAny>>,
+    ) -> PyResult<Option<Bound<'py, PyString>>> {
+        if filename.is_some() && file.is_some() {
+            return Err(PyValueError::new_err(
+                "Only one of filename and file can be specified",
+            ));
+        }
+        if let Some(rank_groups) = &rank_groups {
+            for node in rank_groups.iter().flatten() {
+                if !self.graph.contains_node(NodeIndex::new(*node)) {
+                    return Err(PyIndexError::new_err(format!(
+                        "No node found for index: {node}"
+                    )));
+                }
+            }
+        }
+        let graph_attr = if use_attrs_as_graph_attr && !self.attrs.is_none(py) {
+            let mut merged_attr: BTreeMap<String, String> =
+                self.attrs.extract(py).map_err(|_| {
+                    PyTypeError::new_err(
+                        "Graph attrs must be a dict of str to str to use as dot graph attributes",
+                    )
+                })?;
+            merged_attr.extend(graph_attr.unwrap_or_default());
+            Some(merged_attr)
+        } else {
+            graph_attr
+        };
+        let graph_attr = if show_stats {
+            let mut graph_attr = graph_attr.unwrap_or_default();
+            graph_attr.entry("label".to_string()).or_insert_with(|| {
+                format!(
+                    "\"|V|={} |E|={}\"",
+                    self.graph.node_count(),
+                    self.graph.edge_count()
+                )
+            });
+            Some(graph_attr)
+        } else {
+            graph_attr
+        };
+        if let Some(file) = file {
+            let mut buf_writer = BufWriter::new(PyTextWriter::new(file));
+            build_dot(
+                py,
+                &self.graph,
+                &mut buf_writer,
+                graph_attr,
+                node_attr,
+                edge_attr,
+                rank_groups,
+            )?;
+            buf_writer.flush()?;
+            return Ok(None);
+        }
+        match filename {
+            Some(filename) => {
+                let mut file = File::create(filename)?;
+                build_dot(
+                    py,
+                    &self.graph,
+                    &mut file,
+                    graph_attr,
+                    node_attr,
+                    edge_attr,
+                    rank_groups,
+                )?;
+                Ok(None)
+            }
+            None => {
+                let mut file = Vec::<u8>::new();
+                build_dot(
+                    py,
+                    &self.graph,
+                    &mut file,
+                    graph_attr,
+                    node_attr,
+                    edge_attr,
+                    rank_groups,
+                )?;
+                Ok(Some(PyString::new(py, str::from_utf8(&file)?)))
             }
-            let source_index = NodeIndex::new(source);
-            let target_index = NodeIndex::new(target);
-            self._add_edge(source_index, target_index, weight);
         }
-        Ok(())
     }
 
-    /// Remove an edge between 2 nodes.
+    /// Read an edge list file and create a new PyGraph object from the
+    /// contents
     ///
-    /// Note if there are multiple edges between the specified nodes only one
-    /// will be removed.
+    /// The expected format of the edge list file is a line separated list
+    /// of delimited node ids. If there are more than 3 elements on
+    /// a line the 3rd on will be treated as a string weight for the edge
+    /// (or passed to ``weight_fn`` if it is specified)
+    ///
+    /// If the weight starts with a ``"`` it is read as a quoted string, as
+    /// written by :meth:`~rustworkx.PyGraph.write_edge_list` for weights that
+    /// would otherwise not be read back unchanged: the weight is the text
+    /// between the opening and closing ``"``, in which ``\\``, ``\"``,
+    /// ``\n`` and ``\r`` are replaced by a backslash, a quote, a line feed
+    /// and a carriage return respectively.
+    ///
+    /// :param path: The path of the file to read from or a file-like object
+    ///     whose ``read()`` method returns the contents of the edge list as
+    ///     ``str`` or ``bytes``. To read an edge list from a string use
+    ///     :meth:`~rustworkx.PyGraph.read_edge_list_from_string`.
+    /// :param str comment: Optional character to use as a comment prefix
+    ///     (by default there are no comment characters)
+    /// :param str deliminator: Optional character to use as a deliminator
+    ///     (by default any whitespace will be used)
+    /// :param bool labels: If set to ``True`` the first two separated fields
+    ///     will be treated as string labels uniquely identifying a node
+    ///     instead of node indices
+    /// :param bool multigraph: When set to ``False`` the output
+    ///     :class:`~rustworkx.PyGraph` object will not be a multigraph and
+    ///     a repeated pair of endpoints in the file will update the weight
+    ///     of the existing edge instead of adding a parallel edge. By default
+    ///     this is ``True``.
+    /// :param Callable weight_fn: An optional callable that will be passed the
+    ///     raw string of the weight field(s) on a line and is expected to
+    ///     return the Python object to use as the weight of that edge, for
+    ///     example ``float``. If not specified the weight will be stored as a
+    ///     string. Lines without a weight field always have a weight of
+    ///     ``None`` and this callable is not called for them.
+    ///
+    /// :raises ValueError: If a line in the file has fewer than two fields, a
+    ///     node index on a line can not be parsed as an integer or a quoted
+    ///     weight is not terminated by its closing quote. The error message
+    ///     includes the offending line number.
     ///
-    /// :param int parent: The index of the parent node
-    /// :param int child: The index of the child node
+    /// For example:
     ///
-    /// :raises NoEdgeBetweenNodes: If there is no edge between the nodes
-    ///     specified
-    #[pyo3(text_signature = "(self, node_a, node_b, /)")]
-    pub fn remove_edge(&mut self, node_a: usize, node_b: usize) -> PyResult<()> {
-        let p_index = NodeIndex::new(node_a);
-        let c_index = NodeIndex::new(node_b);
-        let edge_index = match self.graph.find_edge(p_index, c_index) {
-            Some(edge_index) => edge_index,
-            None => return Err(NoEdgeBetweenNodes::new_err("No edge found between nodes")),
-        };
-        self.graph.remove_edge(edge_index);
-        Ok(())
-    }
-
-    /// Remove an edge identified by the provided index
+    /// .. jupyter-execute::
     ///
-    /// :param int edge: The index of the edge to remove
-    #[pyo3(text_signature = "(self, edge, /)")]
-    pub fn remove_edge_from_index(&mut self, edge: usize) -> PyResult<()> {
-        let edge_index = EdgeIndex::new(edge);
-        self.graph.remove_edge(edge_index);
-        Ok(())
+    ///   import tempfile
+    ///
+    ///   import rustworkx as rx
+    ///   from rustworkx.visualization import mpl_draw
+    ///
+    ///   with tempfile.NamedTemporaryFile('wt') as fd:
+    ///       path = fd.name
+    ///       fd.write('0 1\n')
+    ///       fd.write('0 2\n')
+    ///       fd.write('0 3\n')
+    ///       fd.write('1 2\n')
+    ///       fd.write('2 3\n')
+    ///       fd.flush()
+    ///       graph = rx.PyGraph.read_edge_list(path=path)
+    ///   mpl_draw(graph)
+    ///
+    #[staticmethod]
+    #[pyo3(signature=(path, comment=None, deliminator=None, labels=false, multigraph=true, weight_fn=None),  text_signature = "(path, /, comment=None, deliminator=None, labels=False, multigraph=True, weight_fn=None)")]
+    pub fn read_edge_list(
+        py: Python,
+        path: &Bound<PyAny>,
+        comment: Option<String>,
+        deliminator: Option<String>,
+        labels: bool,
+        multigraph: bool,
+        weight_fn: Option<PyObject>,
+    ) -> PyResult<PyGraph> {
+        if let Ok(path) = path.extract::<String>() {
+            let file = File::open(path)?;
+            return PyGraph::parse_edge_list(
+                py,
+                BufReader::new(file),
+                comment,
+                deliminator,
+                labels,
+                multigraph,
+                weight_fn,
+            );
+        }
+        if !path.hasattr("read")? {
+            return Err(PyTypeError::new_err(
+                "path must be a str or a file-like object with a read() method",
+            ));
+        }
+        let data = path.call_method0("read")?;
+        let data: Vec<u8> = match data.extract::<String>() {
+            Ok(data) => data.into_bytes(),
+            Err(_) => data.downcast::<PyBytes>()?.as_bytes().to_vec(),
+        };
+        PyGraph::parse_edge_list(
+            py,
+            data.as_slice(),
+            comment,
+            deliminator,
+            labels,
+            multigraph,
+            weight_fn,
+        )
     }
 
-    /// Remove edges from the graph.
+    /// Create a new PyGraph object from the contents of an edge list in a
+    /// string
     ///
-    /// Note if there are multiple edges between the specified nodes only one
-    /// will be removed.
+    /// This is the same as :meth:`~rustworkx.PyGraph.read_edge_list` but reads
+    /// the edge list from a string instead of a file, see it for the format
+    /// and the description of the arguments.
     ///
-    /// :param iterable[tuple[int, int]] index_list: An iterable of node index pairs
-    ///     to remove from the graph
+    /// :param str data: The contents of the edge list
+    /// :param str comment: Optional character to use as a comment prefix
+    ///     (by default there are no comment characters)
+    /// :param str deliminator: Optional character to use as a deliminator
+    ///     (by default any whitespace will be used)
+    /// :param bool labels: If set to ``True`` the first two separated fields
+    ///     will be treated as string labels uniquely identifying a node
+    ///     instead of node indices
+    /// :param bool multigraph: When set to ``False`` the output
+    ///     :class:`~rustworkx.PyGraph` object will not be a multigraph. By
+    ///     default this is ``True``.
+    /// :param Callable weight_fn: An optional callable that will be passed the
+    ///     raw string of the weight field(s) on a line and is expected to
+    ///     return the Python object to use as the weight of that edge.
+    ///
+    /// :returns: A new graph object generated from the edge list
+    /// :rtype: PyGraph
+    /// :raises ValueError: If a line of the edge list is invalid
     ///
-    /// :raises NoEdgeBetweenNodes: If there are no edges between a specified
-    ///     pair of nodes.
-    #[pyo3(text_signature = "(self, index_list, /)")]
-    pub fn remove_edges_from(&mut self, index_list: Bound<'_, PyAny>) -> PyResult<()> {
-        for py_obj in index_list.try_iter()? {
-            let (x, y) = py_obj?.extract::<(usize, usize)>()?;
-            let (p_index, c_index) = (NodeIndex::new(x), NodeIndex::new(y));
-            let edge_index = match self.graph.find_edge(p_index, c_index) {
-                Some(edge_index) => edge_index,
-                None => return Err(NoEdgeBetweenNodes::new_err("No edge found between nodes")),
-            };
-            self.graph.remove_edge(edge_index);
-        }
-        Ok(())
-    }
-
-    /// Add a new node to the graph.
+    /// For example:
     ///
-    /// :param S obj: The python object to attach to the node
+    /// .. jupyter-execute::
     ///
-    /// :returns: The index of the newly created node
-    /// :rtype: int
-    #[pyo3(text_signature = "(self, obj, /)")]
-    pub fn add_node(&mut self, obj: PyObject) -> PyResult<usize> {
-        let index = self.graph.add_node(obj);
-        Ok(index.index())
-    }
-
-    /// Add new nodes to the graph.
+    ///   import rustworkx as rx
     ///
-    /// :param iterable[S] obj_list: An iterable of python object to attach to the graph
+    ///   graph = rx.PyGraph.read_edge_list_from_string("0 1\n1 2\n")
+    ///   print(graph.edge_list())
     ///
-    /// :returns indices: A list of indices of the newly created nodes
-    /// :rtype: NodeIndices
-    #[pyo3(text_signature = "(self, obj_list, /)")]
-    pub fn add_nodes_from(&mut self, obj_list: Bound<'_, PyAny>) -> PyResult<NodeIndices> {
-        let mut out_list = Vec::new();
-        for py_obj in obj_list.try_iter()? {
-            let obj = py_obj?.extract::<PyObject>()?;
-            out_list.push(self.graph.add_node(obj).index());
-        }
-        Ok(NodeIndices { nodes: out_list })
+    #[staticmethod]
+    #[pyo3(signature=(data, comment=None, deliminator=None, labels=false, multigraph=true, weight_fn=None),  text_signature = "(data, /, comment=None, deliminator=None, labels=False, multigraph=True, weight_fn=None)")]
+    pub fn read_edge_list_from_string(
+        py: Python,
+        data: &str,
+        comment: Option<String>,
+        deliminator: Option<String>,
+        labels: bool,
+        multigraph: bool,
+        weight_fn: Option<PyObject>,
+    ) -> PyResult<PyGraph> {
+        PyGraph::parse_edge_list(
+            py,
+            data.as_bytes(),
+            comment,
+            deliminator,
+            labels,
+            multigraph,
+            weight_fn,
+        )
     }
 
-    /// Remove nodes from the graph.
+    /// Write an edge list file from the PyGraph object
     ///
-    /// If a node index in the list is not present in the graph it will be
-    /// ignored.
+    /// :param path: The path to write the output file to or a file-like
+    ///     object opened in text mode, whose ``write()`` method is called once
+    ///     with the full edge list. If not specified the edge list is
+    ///     returned as a string instead.
+    /// :param str deliminator: The optional character to use as a deliminator
+    ///     if not specified ``" "`` is used.
+    /// :param callable weight_fn: An optional callback function that will be
+    ///     passed an edge's data payload/weight object and is expected to
+    ///     return a string (a ``TypeError`` will be raised if it doesn't
+    ///     return a string). If specified the weight in the output file
+    ///     for each edge will be set to the returned string. If the string
+    ///     contains the deliminator, a line break or leading or trailing
+    ///     whitespace (or any whitespace with the default deliminator), is
+    ///     empty or starts with ``"`` it is written as a quoted string which
+    ///     :meth:`~rustworkx.PyGraph.read_edge_list` reads back unchanged.
     ///
-    /// :param iterable[int] index_list: An iterable of node indices to remove from the
-    ///     graph
-    #[pyo3(text_signature = "(self, index_list, /)")]
-    pub fn remove_nodes_from(&mut self, index_list: Bound<'_, PyAny>) -> PyResult<()> {
-        for py_obj in index_list.try_iter()? {
-            let node = py_obj?.extract::<usize>()?;
-            self.remove_node(node)?;
-        }
-        Ok(())
-    }
-
-    /// Find node within this graph given a specific weight
+    /// :returns: The edge list as a string if ``path`` is not specified,
+    ///     otherwise ``None``
+    /// :rtype: str | None
     ///
-    /// This algorithm has a worst case of O(n) since it searches the node
-    /// indices in order. If there is more than one node in the graph with the
-    /// same weight only the first match (by node index) will be returned.
+    ///  For example:
     ///
-    /// :param T obj: The weight to look for in the graph.
+    ///  .. jupyter-execute::
     ///
-    /// :returns: the index of the first node in the graph that is equal to the
-    ///     weight. If no match is found ``None`` will be returned.
-    /// :rtype: int
-    #[pyo3(text_signature = "(self, obj, /)")]
-    pub fn find_node_by_weight(&self, py: Python, obj: PyObject) -> PyResult<Option<usize>> {
-        find_node_by_weight(py, &self.graph, &obj).map(|node| node.map(|x| x.index()))
-    }
-
-    /// Get the index and data for the neighbors of a node.
+    ///     import os
+    ///     import tempfile
     ///
-    /// This will return a dictionary where the keys are the node indices of
-    /// the adjacent nodes (inbound or outbound) and the value is the edge data
-    /// objects between that adjacent node and the provided node. Note, that
-    /// in the case of multigraphs only a single edge data object will be
-    /// returned
+    ///     import rustworkx as rx
     ///
-    /// :param int node: The index of the node to get the neighbors of
+    ///     graph = rx.generators.path_graph(5)
+    ///     path = os.path.join(tempfile.gettempdir(), "edge_list")
+    ///     graph.write_edge_list(path, deliminator=',')
+    ///     # Print file contents
+    ///     with open(path, 'rt') as edge_file:
+    ///         print(edge_file.read())
+    ///     # Or get the contents directly
+    ///     print(graph.write_edge_list(deliminator=','))
     ///
-    /// :returns neighbors: A dictionary where the keys are node indices and
-    ///     the value is the edge data object for all nodes that share an
-    ///     edge with the specified node.
-    /// :rtype: dict[int, T]
-    #[pyo3(text_signature = "(self, node, /)")]
-    pub fn adj(&mut self, node: usize) -> DictMap<usize, &PyObject> {
-        let index = NodeIndex::new(node);
-        self.graph
-            .edges_directed(index, petgraph::Direction::Outgoing)
-            .map(|edge| (edge.target().index(), edge.weight()))
-            .collect()
+    #[pyo3(text_signature = "(self, /, path=None, deliminator=None, weight_fn=None)", signature = (path=None, deliminator=None, weight_fn=None))]
+    pub fn write_edge_list(
+        &self,
+        py: Python,
+        path: Option<Bound<PyAny>>,
+        deliminator: Option<char>,
+        weight_fn: Option<PyObject>,
+    ) -> PyResult<Option<String>> {
+        let path = match path {
+            Some(path) => path,
+            None => {
+                let mut buf: Vec<u8> = Vec::new();
+                self.write_edge_list_to(py, &mut buf, deliminator, &weight_fn)?;
+                return Ok(Some(String::from_utf8(buf)?));
+            }
+        };
+        if let Ok(path) = path.extract::<String>() {
+            let file = File::create(path)?;
+            let mut buf_writer = BufWriter::new(file);
+            self.write_edge_list_to(py, &mut buf_writer, deliminator, &weight_fn)?;
+            buf_writer.flush()?;
+            return Ok(None);
+        }
+        if !path.hasattr("write")? {
+            return Err(PyTypeError::new_err(
+                "path must be a str or a file-like object with a write() method",
+            ));
+        }
+        let mut buf: Vec<u8> = Vec::new();
+        self.write_edge_list_to(py, &mut buf, deliminator, &weight_fn)?;
+        path.call_method1("write", (String::from_utf8(buf)?,))?;
+        Ok(None)
     }
 
-    /// Get the neighbors of a node.
+    /// Read a Matrix Market file and create a new PyGraph object from its
+    /// contents
     ///
-    /// This with return a list of neighbor node indices
+    /// The file must contain a square matrix in the ``coordinate`` format with
+    /// the ``symmetric`` symmetry type, where only the entries of the lower
+    /// triangle are stored. Every node ``i`` of the graph corresponds to row
+    /// and column ``i + 1`` of the matrix and has ``None`` as its data
+    /// payload, and every entry of the matrix is added as an edge. The field
+    /// type of the matrix determines the data payload of the edges: ``real``
+    /// (or ``double``) entries are read as a ``float``, ``integer`` entries as
+    /// an ``int`` and ``pattern`` entries, which have no value, as ``None``.
     ///
-    /// :param int node: The index of the node to get the neighbors of
+    /// :param str path: The path of the Matrix Market file to read from
     ///
-    /// :returns: A list of the neighbor node indices
-    /// :rtype: NodeIndices
-    #[pyo3(text_signature = "(self, node, /)")]
-    pub fn neighbors(&self, node: usize) -> NodeIndices {
-        NodeIndices {
-            nodes: self
+    /// :returns: A new graph object generated from the Matrix Market file
+    /// :rtype: PyGraph
+    /// :raises ValueError: If the file is not a valid Matrix Market file or
+    ///     uses a format, symmetry type or size that can't be read into a
+    ///     :class:`~rustworkx.PyGraph`. The error message includes the
+    ///     offending line number.
+    #[staticmethod]
+    #[pyo3(text_signature = "(path, /)")]
+    pub fn read_mtx(py: Python, path: &str) -> PyResult<PyGraph> {
+        let file = File::open(path)?;
+        let mut lines = BufReader::new(file).lines().enumerate();
+        let header = match lines.next() {
+            Some((_, line)) => line?,
+            None => return Err(PyValueError::new_err("The Matrix Market file is empty")),
+        };
+        let header_fields: Vec<String> = header
+            .split_whitespace()
+            .map(|field| field.to_lowercase())
+            .collect();
+        if header_fields.len() != 5
+            || header_fields[0] != "%%matrixmarket"
+            || header_fields[1] != "matrix"
+            || header_fields[2] != "coordinate"
+        {
+            return Err(PyValueError::new_err(format!(
+                "Invalid Matrix Market header, only the coordinate matrix format is supported: {header:?}"
+            )));
+        }
+        let field = header_fields[3].as_str();
+        if !matches!(field, "real" | "double" | "integer" | "pattern") {
+            return Err(PyValueError::new_err(format!(
+                "Unsupported Matrix Market field type {field:?}, only real, double, integer and pattern are supported"
+            )));
+        }
+        if header_fields[4] != "symmetric" {
+            return Err(PyValueError::new_err(format!(
+                "Unsupported Matrix Market symmetry type {:?}, only symmetric matrices can be read as an undirected graph",
+                header_fields[4]
+            )));
+        }
+        let mut out_graph = PyGraph {
+            graph: StablePyGraph::<Undirected>::default(),
+            node_removed: false,
+            multigraph: true,
+            attrs: py.None(),
+            connectivity: None,
+        };
+        // The number of nodes and of entries from the size line
+        let mut size: Option<(usize, usize)> = None;
+        let mut entries: usize = 0;
+        for (line_index, line_raw) in lines {
+            let line_number = line_index + 1;
+            let line = line_raw?;
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('%') {
+                continue;
+            }
+            let pieces: Vec<&str> = line.split_whitespace().collect();
+            let parse_index = |field: &str| -> PyResult<usize> {
+                field.parse::<usize>().map_err(|err| {
+                    PyValueError::new_err(format!(
+                        "Invalid integer {field:?} on line {line_number} of the Matrix Market file: {err}"
+                    ))
+                })
+            };
+            let (node_count, entry_count) = match size {
+                Some(size) => size,
+                None => {
+                    if pieces.len() != 3 {
+                        return Err(PyValueError::new_err(format!(
+                            "Line {line_number} of the Matrix Market file is not a valid size line: {line:?}"
+                        )));
+                    }
+                    let rows = parse_index(pieces[0])?;
+                    let columns = parse_index(pieces[1])?;
+                    let entry_count = parse_index(pieces[2])?;
+                    if rows != columns {
+                        return Err(PyValueError::new_err(format!(
+                            "A symmetric matrix must be square, but the size on line {line_number} is {rows}x{columns}"
+                        )));
+                    }
+                    check_header_node_count(rows, "Matrix Market", line_number)?;
+                    out_graph.graph.reserve_exact_nodes(rows);
+                    out_graph
+                        .graph
+                        .reserve_edges(entry_count.min(MAX_UNCHECKED_RESERVATION));
+                    for _ in 0..rows {
+                        out_graph.graph.add_node(py.None());
+                    }
+                    size = Some((rows, entry_count));
+                    continue;
+                }
+            };
+            let expected_fields = if field == "pattern" { 2 } else { 3 };
+            if pieces.len() != expected_fields {
+                return Err(PyValueError::new_err(format!(
+                    "Line {line_number} of the Matrix Market file should have {expected_fields} fields: {line:?}"
+                )));
+            }
+            let row = parse_index(pieces[0])?;
+            let column = parse_index(pieces[1])?;
+            if row == 0 || column == 0 || row > node_count || column > node_count {
+                return Err(PyValueError::new_err(format!(
+                    "Entry ({row}, {column}) on line {line_number} of the Matrix Market file is out of bounds for a {node_count}x{node_count} matrix"
+                )));
+            }
+            let weight = match field {
+                "pattern" => py.None(),
+                "integer" => pieces[2]
+                    .parse::<i64>()
+                    .map_err(|err| {
+                        PyValueError::new_err(format!(
+                            "Invalid integer value {:?} on line {line_number} of the Matrix Market file: {err}",
+                            pieces[2]
+                        ))
+                    })?
+                    .into_py_any(py)?,
+                _ => pieces[2]
+                    .parse::<f64>()
+                    .map_err(|err| {
+                        PyValueError::new_err(format!(
+                            "Invalid real value {:?} on line {line_number} of the Matrix Market file: {err}",
+                            pieces[2]
+                        ))
+                    })?
+                    .into_py_any(py)?,
+            };
+            if entries == entry_count {
+                return Err(PyValueError::new_err(format!(
+                    "The Matrix Market file has more than the {entry_count} entries specified in its size line"
+                )));
+            }
+            entries += 1;
+            out_graph
                 .graph
-                .neighbors(NodeIndex::new(node))
-                .map(|node| node.index())
-                .collect::<HashSet<usize>>()
-                .drain()
-                .collect(),
+                .add_edge(NodeIndex::new(row - 1), NodeIndex::new(column - 1), weight);
         }
-    }
-
-    /// Get the degree for a node
-    ///
-    /// :param int node: The index of the node to find the inbound degree of
-    ///
-    /// :returns degree: The inbound degree of the specified node
-    /// :rtype: int
-    #[pyo3(text_signature = "(self, node, /)")]
-    pub fn degree(&self, node: usize) -> usize {
-        let index = NodeIndex::new(node);
-        let neighbors = self.graph.edges(index);
-        neighbors.fold(0, |count, edge| {
-            if edge.source() == edge.target() {
-                return count + 2;
+        match size {
+            None => Err(PyValueError::new_err(
+                "The Matrix Market file is missing the size line",
+            )),
+            Some((_, entry_count)) if entries != entry_count => {
+                Err(PyValueError::new_err(format!(
+                    "The Matrix Market file has {entries} entries but its size line specifies {entry_count}"
+                )))
             }
-            count + 1
-        })
+            Some(_) => Ok(out_graph),
+        }
     }
 
-    /// Generate a new :class:`~rustworkx.PyDiGraph` object from this graph
-    ///
-    /// This will create a new :class:`~rustworkx.PyDiGraph` object from this
-    /// graph. All edges in this graph will result in a bidirectional edge
-    /// pair in the output graph.
-    ///
-    /// .. note::
-    ///
-    ///     The node indices in the output :class:`~rustworkx.PyDiGraph` may
-    ///     differ if nodes have been removed.
+    /// Read a DIMACS graph file and create a new PyGraph object from its
+    /// contents
     ///
-    /// :returns: A new :class:`~rustworkx.PyDiGraph` object with a
-    ///     bidirectional edge pair for each edge in this graph. Also all
-    ///     node and edge weights/data payloads are copied by reference to
-    ///     the output graph
-    /// :rtype: PyDiGraph
-    #[pyo3(text_signature = "(self)")]
-    pub fn to_directed(&self, py: Python) -> crate::digraph::PyDiGraph {
-        let node_count = self.node_count();
-        let mut new_graph =
-            StablePyGraph::<Directed>::with_capacity(node_count, 2 * self.graph.edge_count());
-        let mut node_map: HashMap<NodeIndex, NodeIndex> = HashMap::with_capacity(node_count);
-        for node_index in self.graph.node_indices() {
-            let node = self.graph[node_index].clone_ref(py);
-            let new_index = new_graph.add_node(node);
-            node_map.insert(node_index, new_index);
-        }
-        for edge in self.graph.edge_references() {
-            let &source = node_map.get(&edge.source()).unwrap();
-            let &target = node_map.get(&edge.target()).unwrap();
-            let weight = edge.weight();
-            new_graph.add_edge(source, target, weight.clone_ref(py));
-            new_graph.add_edge(target, source, weight.clone_ref(py));
-        }
-        crate::digraph::PyDiGraph {
-            graph: new_graph,
+    /// This reads the undirected graph format used by the DIMACS graph
+    /// coloring and clique benchmarks (usually with a ``.col`` or ``.clq``
+    /// extension) and the shortest path format of the DIMACS shortest path
+    /// challenge (usually with a ``.gr`` extension). The file must have a
+    /// problem line of the form ``p <format> <nodes> <edges>``, before any
+    /// edge line, which is used to create the nodes of the graph. Each edge is
+    /// given on a line of the form ``e <u> <v>`` or ``e <u> <v> <weight>``, or
+    /// an arc line ``a <u> <v> <weight>`` in the shortest path format, where
+    /// the node ids ``u`` and ``v`` are 1-based so node id ``1`` is node index
+    /// ``0`` in the returned graph. Lines starting with ``c`` are comments and
+    /// are ignored like empty lines.
+    ///
+    /// Every node has ``None`` as its data payload. The data payload of an
+    /// edge is its weight as a ``float`` if the edge line has one and
+    /// ``None`` otherwise. Arcs are read as undirected edges, and repeated
+    /// edges in the file, including the two arcs of a ``.gr`` file going in
+    /// opposite directions between the same nodes, are added as parallel
+    /// edges.
+    ///
+    /// :param str path: The path of the DIMACS file to read from
+    ///
+    /// :returns: A new graph object generated from the DIMACS file
+    /// :rtype: PyGraph
+    /// :raises ValueError: If the file is not a valid DIMACS graph file. The
+    ///     error message includes the offending line number.
+    #[staticmethod]
+    #[pyo3(text_signature = "(path, /)")]
+    pub fn read_dimacs(py: Python, path: &str) -> PyResult<PyGraph> {
+        let file = File::open(path)?;
+        let buf_reader = BufReader::new(file);
+        let mut out_graph = PyGraph {
+            graph: StablePyGraph::<Undirected>::default(),
             node_removed: false,
-            cycle_state: algo::DfsSpace::default(),
-            check_cycle: false,
-            multigraph: self.multigraph,
+            multigraph: true,
             attrs: py.None(),
+            connectivity: None,
+        };
+        let mut node_count: Option<usize> = None;
+        for (line_index, line_raw) in buf_reader.lines().enumerate() {
+            let line_number = line_index + 1;
+            let line = line_raw?;
+            let pieces: Vec<&str> = line.split_whitespace().collect();
+            let parse_int = |field: &str| -> PyResult<usize> {
+                field.parse::<usize>().map_err(|err| {
+                    PyValueError::new_err(format!(
+                        "Invalid integer {field:?} on line {line_number} of the DIMACS file: {err}"
+                    ))
+                })
+            };
+            match pieces.first() {
+                None | Some(&"c") => continue,
+                Some(&"p") => {
+                    if node_count.is_some() {
+                        return Err(PyValueError::new_err(format!(
+                            "Line {line_number} of the DIMACS file is a second problem line"
+                        )));
+                    }
+                    if pieces.len() != 4 {
+                        return Err(PyValueError::new_err(format!(
+                            "Line {line_number} of the DIMACS file is not a valid problem line: {line:?}"
+                        )));
+                    }
+                    let nodes = parse_int(pieces[2])?;
+                    let edges = parse_int(pieces[3])?;
+                    check_header_node_count(nodes, "DIMACS", line_number)?;
+                    out_graph.graph.reserve_exact_nodes(nodes);
+                    out_graph
+                        .graph
+                        .reserve_edges(edges.min(MAX_UNCHECKED_RESERVATION));
+                    for _ in 0..nodes {
+                        out_graph.graph.add_node(py.None());
+                    }
+                    node_count = Some(nodes);
+                }
+                Some(&"e") | Some(&"a") => {
+                    let nodes = match node_count {
+                        Some(nodes) => nodes,
+                        None => {
+                            return Err(PyValueError::new_err(format!(
+                                "Edge on line {line_number} of the DIMACS file is before the problem line"
+                            )))
+                        }
+                    };
+                    if pieces.len() != 3 && pieces.len() != 4 {
+                        return Err(PyValueError::new_err(format!(
+                            "Line {line_number} of the DIMACS file is not a valid edge line: {line:?}"
+                        )));
+                    }
+                    let u = parse_int(pieces[1])?;
+                    let v = parse_int(pieces[2])?;
+                    if u == 0 || v == 0 || u > nodes || v > nodes {
+                        return Err(PyValueError::new_err(format!(
+                            "Edge ({u}, {v}) on line {line_number} of the DIMACS file references a node id outside of 1..={nodes}"
+                        )));
+                    }
+                    let weight = match pieces.get(3) {
+                        Some(weight) => weight
+                            .parse::<f64>()
+                            .map_err(|err| {
+                                PyValueError::new_err(format!(
+                                    "Invalid edge weight {weight:?} on line {line_number} of the DIMACS file: {err}"
+                                ))
+                            })?
+                            .into_py_any(py)?,
+                        None => py.None(),
+                    };
+                    out_graph
+                        .graph
+                        .add_edge(NodeIndex::new(u - 1), NodeIndex::new(v - 1), weight);
+                }
+                Some(kind) => {
+                    return Err(PyValueError::new_err(format!(
+                        "Unknown line type {kind:?} on line {line_number} of the DIMACS file"
+                    )))
+                }
+            }
+        }
+        if node_count.is_none() {
+            return Err(PyValueError::new_err(
+                "The DIMACS file is missing the problem line",
+            ));
         }
+        Ok(out_graph)
     }
 
-    /// Generate a dot file from the graph
+    /// Write a Matrix Market file from the PyGraph object
+    ///
+    /// The graph is written as a square ``symmetric`` matrix in the
+    /// ``coordinate`` format with one row and column for every node index up
+    /// to the largest one in the graph, so any holes in the index space of
+    /// the graph are written as isolated nodes. Every edge is written as an
+    /// entry in the lower triangle of the matrix, with parallel edges written
+    /// as repeated entries. Node data payloads are not written.
+    ///
+    /// :param str path: The path to write the output file to
+    /// :param callable weight_fn: An optional callback function that will be
+    ///     passed an edge's data payload/weight object and is expected to
+    ///     return a ``float``. If specified the matrix is written with the
+    ///     ``real`` field type and the returned values, otherwise it is
+    ///     written with the ``pattern`` field type and the edges have no
+    ///     value.
     ///
-    /// :param node_attr: A callable that will take in a node data object
-    ///     and return a dictionary of attributes to be associated with the
-    ///     node in the dot file. The key and value of this dictionary **must**
-    ///     be a string. If they're not strings rustworkx will raise TypeError
-    ///     (unfortunately without an error message because of current
-    ///     limitations in the PyO3 type checking)
-    /// :param edge_attr: A callable that will take in an edge data object
-    ///     and return a dictionary of attributes to be associated with the
-    ///     node in the dot file. The key and value of this dictionary **must**
-    ///     be a string. If they're not strings rustworkx will raise TypeError
-    ///     (unfortunately without an error message because of current
-    ///     limitations in the PyO3 type checking)
-    /// :param dict[str, str] graph_attr: An optional dictionary that specifies any graph
-    ///     attributes for the output dot file. The key and value of this
-    ///     dictionary **must** be a string. If they're not strings rustworkx
-    ///     will raise TypeError (unfortunately without an error message
-    ///     because of current limitations in the PyO3 type checking)
-    /// :param str filename: An optional path to write the dot file to
-    ///     if specified there is no return from the function
+    ///  For example:
     ///
-    /// :returns: A string with the dot file contents if filename is not
-    ///     specified.
-    /// :rtype: str
+    ///  .. jupyter-execute::
     ///
-    /// Using this method enables you to leverage graphviz to visualize a
-    /// :class:`rustworkx.PyGraph` object. For example:
+    ///     import os
+    ///     import tempfile
     ///
-    /// .. jupyter-execute::
+    ///     import rustworkx as rx
     ///
-    ///   import os
-    ///   import tempfile
+    ///     graph = rx.generators.path_graph(5)
+    ///     path = os.path.join(tempfile.gettempdir(), "graph.mtx")
+    ///     graph.write_mtx(path)
+    ///     # Print file contents
+    ///     with open(path, 'rt') as mtx_file:
+    ///         print(mtx_file.read())
     ///
-    ///   import pydot
-    ///   from PIL import Image
+    #[pyo3(text_signature = "(self, path, /, weight_fn=None)", signature = (path, weight_fn=None))]
+    pub fn write_mtx(&self, py: Python, path: &str, weight_fn: Option<PyObject>) -> PyResult<()> {
+        let file = File::create(path)?;
+        let mut buf_writer = BufWriter::new(file);
+        let field = if weight_fn.is_some() {
+            "real"
+        } else {
+            "pattern"
+        };
+        let node_count = self.graph.node_bound();
+        writeln!(
+            buf_writer,
+            "%%MatrixMarket matrix coordinate {field} symmetric"
+        )?;
+        writeln!(
+            buf_writer,
+            "{node_count} {node_count} {}",
+            self.graph.edge_count()
+        )?;
+        for edge in self.graph.edge_references() {
+            let row = cmp::max(edge.source(), edge.target()).index() + 1;
+            let column = cmp::min(edge.source(), edge.target()).index() + 1;
+            if weight_fn.is_some() {
+                let weight: f64 = weight_callable(py, &weight_fn, edge.weight(), 1.0)?;
+                writeln!(buf_writer, "{row} {column} {weight:?}")?;
+            } else {
+                writeln!(buf_writer, "{row} {column}")?;
+            }
+        }
+        buf_writer.flush()?;
+        Ok(())
+    }
+
+    /// Read a GraphML file and create a new PyGraph object from its contents
+    ///
+    /// Only the first graph in the file is read and it must be undirected.
+    /// The ``<data>`` elements of each node and edge are stored in its payload
+    /// as a dictionary. If every node id is of the form ``n<index>`` and every
+    /// edge id is of the form ``e<index>`` (as written by
+    /// :meth:`~rustworkx.PyGraph.write_graphml`) the node and edge indices are
+    /// preserved. Otherwise nodes and edges are added in the order they are
+    /// found in the file and their GraphML id is stored under the ``"id"`` key
+    /// of their payload like :func:`~rustworkx.read_graphml` does.
+    ///
+    /// :param str path: The path of the GraphML file to read from
+    /// :param str compression: Optional compression format of the file. By
+    ///     default the file is compressed with gzip only if its extension is
+    ///     ``.graphmlz`` or ``.gz``, set this to ``"gzip"`` to force it.
+    ///
+    /// :returns: A new graph object generated from the GraphML file
+    /// :rtype: PyGraph
+    /// :raises Exception: when an error is encountered while parsing the
+    ///     GraphML file or the graph in it is directed
+    #[staticmethod]
+    #[pyo3(signature=(path, compression=None), text_signature = "(path, /, compression=None)")]
+    pub fn read_graphml(py: Python, path: &str, compression: Option<String>) -> PyResult<PyGraph> {
+        graphml::read_graphml_to_pygraph(py, path, compression)
+    }
+
+    /// Write the PyGraph object to a GraphML file
     ///
-    ///   import rustworkx as rx
+    /// Nodes and edges are written with their index as GraphML id (``n<index>``
+    /// for nodes and ``e<index>`` for edges) so that
+    /// :meth:`~rustworkx.PyGraph.read_graphml` recreates the same indices. The
+    /// keys of the graph are inferred from the data written for the nodes and
+    /// edges.
     ///
-    ///   graph = rx.undirected_gnp_random_graph(15, .25)
-    ///   dot_str = graph.to_dot(
-    ///       lambda node: dict(
-    ///           color='black', fillcolor='lightblue', style='filled'))
-    ///   dot = pydot.graph_from_dot_data(dot_str)[0]
+    /// :param str path: The path to write the output file to
+    /// :param node_attr_fn: An optional callable that will take in a node data
+    ///     object and return a dictionary of the ``<data>`` to write for the
+    ///     node. The key and value of this dictionary **must** be a string. If
+    ///     not specified, node payloads that are dictionaries with
+    ///     ``bool``, ``int``, ``float`` or ``str`` values are written as is and
+    ///     other payloads are written without any data.
+    /// :param edge_attr_fn: An optional callable that will take in an edge data
+    ///     object and return a dictionary of the ``<data>`` to write for the
+    ///     edge, with the same contract as ``node_attr_fn``.
+    /// :param str compression: Optional compression format of the output file.
+    ///     By default the file is compressed with gzip only if its extension
+    ///     is ``.graphmlz`` or ``.gz``, set this to ``"gzip"`` to force it.
+    ///
+    /// :raises Exception: when the same key is used with values of different
+    ///     types or the file can't be written
+    #[pyo3(
+        text_signature = "(self, path, /, node_attr_fn=None, edge_attr_fn=None, compression=None)",
+        signature = (path, node_attr_fn=None, edge_attr_fn=None, compression=None)
+    )]
+    pub fn write_graphml(
+        &self,
+        py: Python,
+        path: &str,
+        node_attr_fn: Option<PyObject>,
+        edge_attr_fn: Option<PyObject>,
+        compression: Option<String>,
+    ) -> PyResult<()> {
+        graphml::write_pygraph_graphml(py, self, path, node_attr_fn, edge_attr_fn, compression)
+    }
+
+    /// Write the PyGraph object to a GEXF file
     ///
-    ///   with tempfile.TemporaryDirectory() as tmpdirname:
-    ///       tmp_path = os.path.join(tmpdirname, 'dag.png')
-    ///       dot.write_png(tmp_path)
-    ///       image = Image.open(tmp_path)
-    ///       os.remove(tmp_path)
-    ///   image
+    /// The graph is written as a static GEXF 1.3 document, the format used by
+    /// `Gephi <https://gephi.org/>`__. Nodes and edges are written with their
+    /// index as GEXF id so the indices of the graph are preserved. The node
+    /// and edge data are written as ``<attvalues>``, with the attributes
+    /// declared from the data written for the nodes and edges.
     ///
+    /// :param str path: The path to write the output file to
+    /// :param node_attr_fn: An optional callable that will take in a node data
+    ///     object and return a dictionary of the attributes to write for the
+    ///     node. The key and value of this dictionary **must** be a string,
+    ///     as for the ``node_attr`` argument of :meth:`~.PyGraph.to_dot`, and
+    ///     the attributes are declared with type ``string``. If not
+    ///     specified, node payloads that are dictionaries are written as is,
+    ///     with ``bool``, ``int``, ``float`` and ``str`` values declared with
+    ///     type ``boolean``, ``long``, ``double`` and ``string``
+    ///     respectively, and other payloads are written without attributes.
+    /// :param edge_attr_fn: An optional callable that will take in an edge data
+    ///     object and return a dictionary of the attributes to write for the
+    ///     edge, with the same contract as ``node_attr_fn``.
+    ///
+    /// :raises Exception: when the same attribute is used with values of
+    ///     different types or the file can't be written
     #[pyo3(
-        text_signature = "(self, /, node_attr=None, edge_attr=None, graph_attr=None, filename=None)",
-        signature = (node_attr=None, edge_attr=None, graph_attr=None, filename=None)
+        text_signature = "(self, path, /, node_attr_fn=None, edge_attr_fn=None)",
+        signature = (path, node_attr_fn=None, edge_attr_fn=None)
     )]
-    pub fn to_dot<'py>(
+    pub fn write_gexf(
         &self,
-        py: Python<'py>,
-        node_attr: Option<PyObject>,
-        edge_attr: Option<PyObject>,
-        graph_attr: Option<BTreeMap<String, String>>,
-        filename: Option<String>,
-    ) -> PyResult<Option<Bound<'py, PyString>>> {
-        match filename {
-            Some(filename) => {
-                let mut file = File::create(filename)?;
-                build_dot(py, &self.graph, &mut file, graph_attr, node_attr, edge_attr)?;
-                Ok(None)
-            }
-            None => {
-                let mut file = Vec::<u8>::new();
-                build_dot(py, &self.graph, &mut file, graph_attr, node_attr, edge_attr)?;
-                Ok(Some(PyString::new(py, str::from_utf8(&file)?)))
-            }
-        }
+        py: Python,
+        path: &str,
+        node_attr_fn: Option<PyObject>,
+        edge_attr_fn: Option<PyObject>,
+    ) -> PyResult<()> {
+        gexf::write_pygraph_gexf(py, self, path, node_attr_fn, edge_attr_fn)
     }
 
-    /// Read an edge list file and create a new PyGraph object from the
+    /// Read a Pajek ``.net`` file and create a new PyGraph object from its
     /// contents
     ///
-    /// The expected format of the edge list file is a line separated list
-    /// of delimited node ids. If there are more than 3 elements on
-    /// a line the 3rd on will be treated as a string weight for the edge
+    /// The ``*Vertices n`` section creates the nodes of the graph, with the
+    /// 1-based Pajek vertex ``i`` becoming the node with index ``i - 1``. The
+    /// payload of each node is its label as a string, which may be double
+    /// quoted to contain whitespace, or the vertex number if it has no label.
+    /// Anything after the label on a vertex line, such as coordinates, is
+    /// ignored. Every line of the ``*Edges`` and ``*Arcs`` sections adds an
+    /// undirected edge, whose payload is its weight as a float or ``None`` if
+    /// the line has no weight. Lines starting with ``%`` are comments.
     ///
-    /// :param str path: The path of the file to read from
-    /// :param str comment: Optional character to use as a comment prefix
-    ///     (by default there are no comment characters)
-    /// :param str deliminator: Optional character to use as a deliminator
-    ///     (by default any whitespace will be used)
-    /// :param bool labels: If set to ``True`` the first two separated fields
-    ///     will be treated as string labels uniquely identifying a node
-    ///     instead of node indices
+    /// :param str path: The path of the Pajek file to read from
+    ///
+    /// :returns: A new graph object generated from the Pajek file
+    /// :rtype: PyGraph
+    /// :raises ValueError: If the file has a section other than
+    ///     ``*Network``, ``*Vertices``, ``*Edges`` or ``*Arcs``, a line outside
+    ///     of these sections, a vertex number that is not between 1 and the
+    ///     number of vertices, an unterminated quoted label or a weight that
+    ///     can not be parsed as a float. The error message includes the
+    ///     offending line number.
     ///
     /// For example:
     ///
@@ -1355,163 +5206,223 @@ impl PyGraph {
     ///   import tempfile
     ///
     ///   import rustworkx as rx
-    ///   from rustworkx.visualization import mpl_draw
     ///
     ///   with tempfile.NamedTemporaryFile('wt') as fd:
-    ///       path = fd.name
-    ///       fd.write('0 1\n')
-    ///       fd.write('0 2\n')
-    ///       fd.write('0 3\n')
-    ///       fd.write('1 2\n')
+    ///       fd.write('*Vertices 3\n')
+    ///       fd.write('1 "Jane Doe"\n')
+    ///       fd.write('2 "John Doe"\n')
+    ///       fd.write('*Edges\n')
+    ///       fd.write('1 2 1.5\n')
     ///       fd.write('2 3\n')
     ///       fd.flush()
-    ///       graph = rx.PyGraph.read_edge_list(path=path)
-    ///   mpl_draw(graph)
+    ///       graph = rx.PyGraph.read_pajek(fd.name)
+    ///   print(graph.nodes())
+    ///   print(graph.weighted_edge_list())
     ///
     #[staticmethod]
-    #[pyo3(signature=(path, comment=None, deliminator=None, labels=false),  text_signature = "(path, /, comment=None, deliminator=None, labels=False)")]
-    pub fn read_edge_list(
-        py: Python,
-        path: &str,
-        comment: Option<String>,
-        deliminator: Option<String>,
-        labels: bool,
-    ) -> PyResult<PyGraph> {
-        let file = File::open(path)?;
-        let buf_reader = BufReader::new(file);
-        let mut out_graph = StablePyGraph::<Undirected>::default();
-        let mut label_map: HashMap<String, usize> = HashMap::new();
-        for line_raw in buf_reader.lines() {
-            let line = line_raw?;
-            let skip = match &comment {
-                Some(comm) => line.trim().starts_with(comm),
-                None => line.trim().is_empty(),
-            };
-            if skip {
-                continue;
-            }
-            let line_no_comments = match &comment {
-                Some(comm) => line
-                    .find(comm)
-                    .map(|idx| &line[..idx])
-                    .unwrap_or(&line)
-                    .trim()
-                    .to_string(),
-                None => line,
-            };
-            let pieces: Vec<&str> = match &deliminator {
-                Some(del) => line_no_comments.split(del).collect(),
-                None => line_no_comments.split_whitespace().collect(),
-            };
-            let src: usize;
-            let target: usize;
-            if labels {
-                let src_str = pieces[0];
-                let target_str = pieces[1];
-                src = match label_map.get(src_str) {
-                    Some(index) => *index,
-                    None => {
-                        let index = out_graph.add_node(src_str.into_py_any(py)?).index();
-                        label_map.insert(src_str.to_string(), index);
-                        index
-                    }
-                };
-                target = match label_map.get(target_str) {
-                    Some(index) => *index,
-                    None => {
-                        let index = out_graph.add_node(target_str.into_py_any(py)?).index();
-                        label_map.insert(target_str.to_string(), index);
-                        index
-                    }
-                };
-            } else {
-                src = pieces[0].parse::<usize>()?;
-                target = pieces[1].parse::<usize>()?;
-                let max_index = cmp::max(src, target);
-                // Add nodes to graph
-                while max_index >= out_graph.node_count() {
-                    out_graph.add_node(py.None());
-                }
-            }
-            // Add edges tp graph
-            let weight = if pieces.len() > 2 {
-                let weight_str = match &deliminator {
-                    Some(del) => pieces[2..].join(del),
-                    None => pieces[2..].join(&' '.to_string()),
-                };
-                PyString::new(py, &weight_str).into_any().unbind()
-            } else {
-                py.None()
-            };
-            out_graph.add_edge(NodeIndex::new(src), NodeIndex::new(target), weight);
-        }
-        Ok(PyGraph {
-            graph: out_graph,
-            node_removed: false,
-            multigraph: true,
-            attrs: py.None(),
-        })
+    #[pyo3(text_signature = "(path, /)")]
+    pub fn read_pajek(py: Python, path: &str) -> PyResult<PyGraph> {
+        pajek::read_pajek_to_pygraph(py, path)
     }
 
-    /// Write an edge list file from the PyGraph object
+    /// Write the PyGraph object to a Pajek ``.net`` file
+    ///
+    /// The nodes are written in the ``*Vertices`` section numbered ``1..n``
+    /// in node index order, so :meth:`~rustworkx.PyGraph.read_pajek` only
+    /// recreates the same node indices if none have been removed. Labels are
+    /// always written double quoted. The edges are written in the ``*Edges``
+    /// section, with their payload as weight if it is a number.
     ///
     /// :param str path: The path to write the output file to
-    /// :param str deliminator: The optional character to use as a deliminator
-    ///     if not specified ``" "`` is used.
-    /// :param callable weight_fn: An optional callback function that will be
-    ///     passed an edge's data payload/weight object and is expected to
-    ///     return a string (a ``TypeError`` will be raised if it doesn't
-    ///     return a string). If specified the weight in the output file
-    ///     for each edge will be set to the returned string.
+    /// :param label_fn: An optional callable that will take in a node data
+    ///     object and return the label of the node as a string. If not
+    ///     specified ``str()`` of the node data object is used as its label.
+    ///
+    /// :raises ValueError: If a label contains a double quote or a line
+    ///     break, which can't be represented in a Pajek file
+    /// :raises TypeError: If ``label_fn`` doesn't return a string
+    #[pyo3(text_signature = "(self, path, /, label_fn=None)", signature = (path, label_fn=None))]
+    pub fn write_pajek(&self, py: Python, path: &str, label_fn: Option<PyObject>) -> PyResult<()> {
+        pajek::write_pygraph_pajek(py, self, path, label_fn)
+    }
+
+    /// Generate a JSON object representing the graph in a node-link format
+    ///
+    /// The output has the same ``{"nodes": [...], "links": [...]}`` shape as
+    /// :func:`~rustworkx.node_link_json`, with each node carrying its index as
+    /// ``id`` and each link its ``source``, ``target``, ``id`` and ``data``. The
+    /// :attr:`~.PyGraph.attrs` attribute and the ``multigraph`` flag of the graph
+    /// are included so :meth:`~rustworkx.PyGraph.read_node_link_json` can
+    /// reconstruct an identical graph.
+    ///
+    /// Payloads are written as JSON values, so they must be ``None``, a
+    /// ``bool``, ``int``, ``float`` or ``str``, or a ``list`` or a ``dict``
+    /// with string keys of such values. Subclasses of these types, tuples and
+    /// non-finite floats are rejected as they would not read back as an equal
+    /// object of the same type.
+    ///
+    /// :param str path: An optional path to write the JSON output to. If
+    ///     specified the method will not return anything and instead will
+    ///     write the JSON to the file specified.
+    /// :param node_attr_fn: An optional callable that will be passed the node
+    ///     data payload for each node in the graph and is expected to return
+    ///     the value to write as the node ``data`` in its place.
+    /// :param edge_attr_fn: An optional callable that will be passed the edge
+    ///     data payload for each edge in the graph, with the same contract as
+    ///     ``node_attr_fn``.
+    ///
+    /// :returns: Either the JSON string for the payload or ``None`` if ``path``
+    ///     is specified
+    /// :rtype: str
+    /// :raises TypeError: If a payload, the graph ``attrs`` or the return of
+    ///     a callback can't be written to JSON without loss
+    #[pyo3(
+        text_signature = "(self, /, path=None, node_attr_fn=None, edge_attr_fn=None)",
+        signature = (path=None, node_attr_fn=None, edge_attr_fn=None)
+    )]
+    pub fn write_node_link_json(
+        &self,
+        py: Python,
+        path: Option<String>,
+        node_attr_fn: Option<PyObject>,
+        edge_attr_fn: Option<PyObject>,
+    ) -> PyResult<Option<String>> {
+        json::graph_node_link_json_with_payloads(py, self, path, node_attr_fn, edge_attr_fn)
+    }
+
+    /// Read a node-link JSON object and create a new PyGraph object from it
     ///
-    ///  For example:
+    /// Unlike :func:`~rustworkx.parse_node_link_json` each node and link is
+    /// placed at the index given by its ``id``, so holes in the index space of
+    /// a graph written with :meth:`~rustworkx.PyGraph.write_node_link_json`
+    /// are recreated. Nodes and links without an ``id`` are added after the
+    /// others. The ``multigraph`` flag and the graph attributes are restored.
     ///
-    ///  .. jupyter-execute::
+    /// :param str path_or_str: Either a JSON string or the path of a JSON file
+    ///     to read from
     ///
-    ///     import os
-    ///     import tempfile
+    /// :returns: The graph represented by the node-link JSON
+    /// :rtype: PyGraph
+    /// :raises JSONDeserializationError: If the JSON is invalid, represents a
+    ///     directed graph or has duplicate node or link ids
+    #[staticmethod]
+    #[pyo3(text_signature = "(path_or_str, /)")]
+    pub fn read_node_link_json(py: Python, path_or_str: &str) -> PyResult<PyGraph> {
+        json::graph_from_node_link_json_with_indices(py, path_or_str)
+    }
+
+    /// Create a new :class:`~rustworkx.PyGraph` object from an edge list
+    ///
+    /// Like :meth:`~rustworkx.PyGraph.extend_from_edge_list` and
+    /// :meth:`~rustworkx.PyGraph.extend_from_weighted_edge_list` nodes are
+    /// added (with a node weight of ``None``) for every node index up to the
+    /// largest one in the edge list.
+    ///
+    /// :param iterable edge_list: An iterable of tuples in the form
+    ///     ``(source, target)`` or ``(source, target, weight)`` where ``source``
+    ///     and ``target`` are integer node indices and ``weight`` is the
+    ///     python object to use as the edge weight/data payload. Edges given
+    ///     as a ``(source, target)`` tuple have a weight of ``None`` unless
+    ///     ``weights`` is specified.
+    /// :param iterable weights: An optional iterable of edge weights/data
+    ///     payloads which are used in order for the edges in ``edge_list``.
+    ///     If specified ``edge_list`` must only contain ``(source, target)``
+    ///     tuples and have the same length as ``weights``.
+    /// :param int num_nodes: An optional hint for the number of nodes the
+    ///     graph will contain. If specified enough capacity will be allocated
+    ///     up front to store this many nodes. This does not add any nodes, it
+    ///     is only a potential performance optimization.
+    /// :param bool multigraph: When set to ``False`` the output
+    ///     :class:`~rustworkx.PyGraph` object will not be a multigraph and a
+    ///     repeated pair of endpoints in the edge list will update the weight
+    ///     of the existing edge instead of adding a parallel edge. By default
+    ///     this is ``True``.
+    ///
+    /// :returns: A new graph object generated from the edge list
+    /// :rtype: PyGraph
+    /// :raises ValueError: If a tuple in ``edge_list`` doesn't have 2 or 3
+    ///     elements, or if ``weights`` is specified and either contains a
+    ///     different number of elements than ``edge_list`` or ``edge_list``
+    ///     contains a ``(source, target, weight)`` tuple.
     ///
-    ///     import rustworkx as rx
+    /// For example:
     ///
-    ///     graph = rx.generators.path_graph(5)
-    ///     path = os.path.join(tempfile.gettempdir(), "edge_list")
-    ///     graph.write_edge_list(path, deliminator=',')
-    ///     # Print file contents
-    ///     with open(path, 'rt') as edge_file:
-    ///         print(edge_file.read())
+    /// .. jupyter-execute::
     ///
-    #[pyo3(text_signature = "(self, path, /, deliminator=None, weight_fn=None)", signature = (path, deliminator=None, weight_fn=None))]
-    pub fn write_edge_list(
-        &self,
+    ///   import rustworkx as rx
+    ///   from rustworkx.visualization import mpl_draw
+    ///
+    ///   graph = rx.PyGraph.from_edge_list([(0, 1), (1, 2), (2, 0)], weights=["a", "b", "c"])
+    ///   mpl_draw(graph, edge_labels=str)
+    ///
+    #[staticmethod]
+    #[pyo3(
+        signature=(edge_list, weights=None, num_nodes=None, multigraph=true),
+        text_signature = "(edge_list, /, weights=None, num_nodes=None, multigraph=True)"
+    )]
+    pub fn from_edge_list(
         py: Python,
-        path: &str,
-        deliminator: Option<char>,
-        weight_fn: Option<PyObject>,
-    ) -> PyResult<()> {
-        let file = File::create(path)?;
-        let mut buf_writer = BufWriter::new(file);
-        let delim = match deliminator {
-            Some(delim) => delim.to_string(),
-            None => " ".to_string(),
+        edge_list: Bound<'_, PyAny>,
+        weights: Option<Bound<'_, PyAny>>,
+        num_nodes: Option<usize>,
+        multigraph: bool,
+    ) -> PyResult<PyGraph> {
+        let mut out_graph = PyGraph {
+            graph: StablePyGraph::<Undirected>::default(),
+            node_removed: false,
+            multigraph,
+            attrs: py.None(),
+            connectivity: None,
         };
-
-        for edge in self.graph.edge_references() {
-            buf_writer.write_all(
-                format!(
-                    "{}{}{}",
-                    edge.source().index(),
-                    delim,
-                    edge.target().index()
-                )
-                .as_bytes(),
-            )?;
-            match weight_callable(py, &weight_fn, edge.weight(), None as Option<String>)? {
-                Some(weight) => buf_writer.write_all(format!("{delim}{weight}\n").as_bytes()),
-                None => buf_writer.write_all(b"\n"),
-            }?;
+        out_graph.reserve_for_edge_list(&edge_list, num_nodes);
+        let mut weights = match weights {
+            Some(weights) => Some(weights.try_iter()?),
+            None => None,
+        };
+        for py_obj in edge_list.try_iter()? {
+            let py_obj = py_obj?;
+            let (source, target, weight) = match (py_obj.len()?, &mut weights) {
+                (2, None) => {
+                    let (source, target) = py_obj.extract::<(usize, usize)>()?;
+                    (source, target, py.None())
+                }
+                (2, Some(weights)) => {
+                    let (source, target) = py_obj.extract::<(usize, usize)>()?;
+                    match weights.next() {
+                        Some(weight) => (source, target, weight?.unbind()),
+                        None => {
+                            return Err(PyValueError::new_err(
+                                "weights has fewer elements than edge_list",
+                            ))
+                        }
+                    }
+                }
+                (3, None) => py_obj.extract::<(usize, usize, PyObject)>()?,
+                (3, Some(_)) => {
+                    return Err(PyValueError::new_err(
+                        "edge_list can't contain (source, target, weight) tuples if weights is specified",
+                    ))
+                }
+                (len, _) => {
+                    return Err(PyValueError::new_err(format!(
+                        "Edges must be (source, target) or (source, target, weight) tuples, but got a tuple of length {len}"
+                    )))
+                }
+            };
+            let max_index = cmp::max(source, target);
+            while max_index >= out_graph.graph.node_count() {
+                out_graph.graph.add_node(py.None());
+            }
+            out_graph._add_edge(NodeIndex::new(source), NodeIndex::new(target), weight);
         }
-        buf_writer.flush()?;
-        Ok(())
+        if let Some(mut weights) = weights {
+            if weights.next().is_some() {
+                return Err(PyValueError::new_err(
+                    "weights has more elements than edge_list",
+                ));
+            }
+        }
+        Ok(out_graph)
     }
 
     /// Create a new :class:`~rustworkx.PyGraph` object from an adjacency matrix
@@ -1537,17 +5448,26 @@ impl PyGraph {
     /// :param float null_value: An optional float that will treated as a null
     ///     value. If any element in the input matrix is this value it will be
     ///     treated as not an edge. By default this is ``0.0``.
+    /// :param int parallel_threshold: The number of rows of the matrix at
+    ///     which the matrix is scanned for edges in parallel. If the matrix
+    ///     has fewer rows than this value it will be scanned in a single
+    ///     thread. The edges are added to the graph in the same order either
+    ///     way. The default value is 300.
     ///
     /// :returns: A new graph object generated from the adjacency matrix
     /// :rtype: PyGraph
     #[staticmethod]
-    #[pyo3(signature=(matrix, null_value=0.0), text_signature = "(matrix, /, null_value=0.0)")]
+    #[pyo3(
+        signature=(matrix, null_value=0.0, parallel_threshold=300),
+        text_signature = "(matrix, /, null_value=0.0, parallel_threshold=300)"
+    )]
     pub fn from_adjacency_matrix<'p>(
         py: Python<'p>,
         matrix: PyReadonlyArray2<'p, f64>,
         null_value: f64,
+        parallel_threshold: usize,
     ) -> PyResult<PyGraph> {
-        _from_adjacency_matrix(py, matrix, null_value)
+        _from_adjacency_matrix(py, matrix, null_value, parallel_threshold)
     }
 
     /// Create a new :class:`~rustworkx.PyGraph` object from an adjacency matrix
@@ -1573,18 +5493,351 @@ impl PyGraph {
     /// :param float null_value: An optional complex that will treated as a null
     ///     value. If any element in the input matrix is this value it will be
     ///     treated as not an edge. By default this is ``0.0+0.0j``
+    /// :param int parallel_threshold: The number of rows of the matrix at
+    ///     which the matrix is scanned for edges in parallel. If the matrix
+    ///     has fewer rows than this value it will be scanned in a single
+    ///     thread. The edges are added to the graph in the same order either
+    ///     way. The default value is 300.
     ///
     /// :returns: A new graph object generated from the adjacency matrix
     /// :rtype: PyGraph
     ///
     #[staticmethod]
-    #[pyo3(signature=(matrix, null_value=Complex64::zero()), text_signature = "(matrix, /, null_value=0.0+0.0j)")]
+    #[pyo3(
+        signature=(matrix, null_value=Complex64::zero(), parallel_threshold=300),
+        text_signature = "(matrix, /, null_value=0.0+0.0j, parallel_threshold=300)"
+    )]
     pub fn from_complex_adjacency_matrix<'p>(
         py: Python<'p>,
         matrix: PyReadonlyArray2<'p, Complex64>,
         null_value: Complex64,
+        parallel_threshold: usize,
+    ) -> PyResult<PyGraph> {
+        _from_adjacency_matrix(py, matrix, null_value, parallel_threshold)
+    }
+
+    /// Create a new :class:`~rustworkx.PyGraph` object from an adjacency matrix
+    /// with matrix elements of type ``int``
+    ///
+    /// This method can be used to construct a new :class:`~rustworkx.PyGraph`
+    /// object from an input adjacency matrix. The node weights will be the
+    /// index from the matrix. The edge weights will be an integer value of the
+    /// value from the matrix.
+    ///
+    /// This differs from the
+    /// :meth:`~rustworkx.PyGraph.from_adjacency_matrix` in that the type of
+    /// the elements of the input matrix in this method must be an ``int``
+    /// (specifically a ``numpy.int64``) and the output graph edge weights
+    /// will be ``int`` too, so integer data such as edge counts is stored
+    /// without a conversion to ``float``.
+    ///
+    /// :param ndarray matrix: The input numpy array adjacency matrix to create
+    ///     a new :class:`~rustworkx.PyGraph` object from. It must be a 2
+    ///     dimensional array and be an ``int``/``np.int64`` data type.
+    /// :param int null_value: An optional integer that will treated as a null
+    ///     value. If any element in the input matrix is this value it will be
+    ///     treated as not an edge. By default this is ``0``
+    /// :param int parallel_threshold: The number of rows of the matrix at
+    ///     which the matrix is scanned for edges in parallel. If the matrix
+    ///     has fewer rows than this value it will be scanned in a single
+    ///     thread. The edges are added to the graph in the same order either
+    ///     way. The default value is 300.
+    ///
+    /// :returns: A new graph object generated from the adjacency matrix
+    /// :rtype: PyGraph
+    ///
+    #[staticmethod]
+    #[pyo3(
+        signature=(matrix, null_value=0, parallel_threshold=300),
+        text_signature = "(matrix, /, null_value=0, parallel_threshold=300)"
+    )]
+    pub fn from_int_adjacency_matrix<'p>(
+        py: Python<'p>,
+        matrix: PyReadonlyArray2<'p, i64>,
+        null_value: i64,
+        parallel_threshold: usize,
+    ) -> PyResult<PyGraph> {
+        _from_adjacency_matrix(py, matrix, null_value, parallel_threshold)
+    }
+
+    /// Create a new :class:`~rustworkx.PyGraph` object from a SciPy sparse
+    /// matrix
+    ///
+    /// This method can be used to construct a new :class:`~rustworkx.PyGraph`
+    /// object from a ``scipy.sparse`` adjacency matrix without first
+    /// converting it to a dense array. The input is converted to CSR form
+    /// (by calling its ``tocsr()`` method) and only the stored entries are
+    /// considered as potential edges. The node weights will be the index
+    /// from the matrix. The edge weights will be a float value of the
+    /// value from the matrix.
+    ///
+    /// As the output graph is undirected only the upper triangle of the
+    /// matrix (including the diagonal, which will result in self loops) is
+    /// used to add edges, any entries below the diagonal are ignored.
+    ///
+    /// :param matrix: The input ``scipy.sparse`` adjacency matrix (for
+    ///     example a ``csr_matrix``, ``csr_array`` or ``coo_matrix``) to
+    ///     create a new :class:`~rustworkx.PyGraph` object from. It must be
+    ///     square and the data array must be a ``float``/``np.float64`` data
+    ///     type.
+    /// :param float null_value: An optional float that will treated as a null
+    ///     value. If any stored element in the input matrix is this value it
+    ///     will be treated as not an edge. By default this is ``0.0``.
+    ///
+    /// :returns: A new graph object generated from the sparse adjacency matrix
+    /// :rtype: PyGraph
+    /// :raises ValueError: If the input matrix is not square or its CSR
+    ///     ``indptr``, ``indices`` and ``data`` arrays are inconsistent
+    #[staticmethod]
+    #[pyo3(signature=(matrix, null_value=0.0), text_signature = "(matrix, /, null_value=0.0)")]
+    pub fn from_scipy_sparse_matrix(
+        py: Python,
+        matrix: Bound<'_, PyAny>,
+        null_value: f64,
     ) -> PyResult<PyGraph> {
-        _from_adjacency_matrix(py, matrix, null_value)
+        let csr = matrix.call_method0("tocsr")?;
+        let (num_rows, num_cols): (usize, usize) = csr.getattr("shape")?.extract()?;
+        if num_rows != num_cols {
+            return Err(PyValueError::new_err(format!(
+                "Adjacency matrix must be square, got shape ({num_rows}, {num_cols})"
+            )));
+        }
+        let indptr = csr.getattr("indptr")?;
+        let indptr = SparseIndexArray::extract(&indptr)?;
+        let indices = csr.getattr("indices")?;
+        let indices = SparseIndexArray::extract(&indices)?;
+        let data = csr.getattr("data")?.extract::<PyReadonlyArray1<f64>>()?;
+        let data = data.as_array();
+        if indptr.len() != num_rows + 1 {
+            return Err(PyValueError::new_err(format!(
+                "Sparse matrix indptr has length {}, expected {}",
+                indptr.len(),
+                num_rows + 1
+            )));
+        }
+        if data.len() != indices.len() {
+            return Err(PyValueError::new_err(format!(
+                "Sparse matrix data has length {}, but indices has length {}",
+                data.len(),
+                indices.len()
+            )));
+        }
+        // Every row slice must lie within the indices array
+        let mut row_bounds = Vec::with_capacity(num_rows + 1);
+        for pos in 0..=num_rows {
+            match indptr.index(pos) {
+                Some(bound)
+                    if bound <= indices.len()
+                        && row_bounds.last().map_or(true, |prev| *prev <= bound) =>
+                {
+                    row_bounds.push(bound)
+                }
+                _ => return Err(PyValueError::new_err(
+                    "Sparse matrix indptr must be non-decreasing and within the bounds of indices",
+                )),
+            }
+        }
+        let mut out_graph = StablePyGraph::<Undirected>::with_capacity(num_rows, data.len());
+        for node in 0..num_rows {
+            out_graph.add_node(node.into_py_any(py)?);
+        }
+        for row in 0..num_rows {
+            let source_index = NodeIndex::new(row);
+            for pos in row_bounds[row]..row_bounds[row + 1] {
+                let col = match indices.index(pos) {
+                    Some(col) if col < num_rows => col,
+                    _ => {
+                        return Err(PyValueError::new_err(format!(
+                            "Sparse matrix column index at position {pos} is out of range for {num_rows} nodes"
+                        )))
+                    }
+                };
+                if col < row {
+                    continue;
+                }
+                let elem = data[pos];
+                let is_null = if null_value.is_nan() {
+                    elem.is_nan()
+                } else {
+                    elem == null_value
+                };
+                if !is_null {
+                    out_graph.add_edge(source_index, NodeIndex::new(col), elem.into_py_any(py)?);
+                }
+            }
+        }
+        Ok(PyGraph {
+            graph: out_graph,
+            node_removed: false,
+            multigraph: true,
+            attrs: py.None(),
+            connectivity: None,
+        })
+    }
+
+    /// Return the node-edge incidence matrix of the graph
+    ///
+    /// The output matrix has a row for every node index and a column for
+    /// every edge index in the graph. Each column has a ``1.0`` entry in the
+    /// rows of the two endpoints of that edge. A self loop is represented by
+    /// a single ``2.0`` entry in the row of its endpoint, so that the sum of
+    /// each row is always equal to the :meth:`~rustworkx.PyGraph.degree` of
+    /// the node.
+    ///
+    /// The shape of the matrix is ``(node_bound, edge_bound)``, where the
+    /// bounds are one more than the largest node and edge index that has been
+    /// used in the graph. If nodes or edges have been removed the rows and
+    /// columns for those indices will be all zeros.
+    ///
+    /// :returns: The incidence matrix of the graph
+    /// :rtype: numpy.ndarray
+    #[pyo3(text_signature = "(self)")]
+    pub fn incidence_matrix<'py>(&self, py: Python<'py>) -> Bound<'py, PyArray2<f64>> {
+        let mut matrix = Array2::<f64>::zeros((self.graph.node_bound(), self.graph.edge_bound()));
+        for edge in self.graph.edge_references() {
+            let column = edge.id().index();
+            let source = edge.source().index();
+            let target = edge.target().index();
+            if source == target {
+                matrix[[source, column]] = 2.0;
+            } else {
+                matrix[[source, column]] = 1.0;
+                matrix[[target, column]] = 1.0;
+            }
+        }
+        matrix.into_pyarray(py)
+    }
+
+    /// Return the adjacency matrix of the graph in compressed sparse row form
+    ///
+    /// The rows and columns of the matrix are the nodes of the graph in node
+    /// index order, compacted so that they are numbered ``0..len(graph)``
+    /// even if nodes have been removed. The matrix is symmetric: an edge
+    /// between nodes ``u`` and ``v`` results in an entry in row ``u`` and
+    /// an entry in row ``v``, while a self loop results in a single entry on
+    /// the diagonal. The weights of parallel edges are summed. The column
+    /// indices in each row are sorted and unique.
+    ///
+    /// The output can be passed directly to ``scipy.sparse.csr_array``::
+    ///
+    ///     indptr, indices, data, node_map = graph.to_csr()
+    ///     matrix = scipy.sparse.csr_array((data, indices, indptr))
+    ///
+    /// :param weight_fn: An optional callable that will be passed the
+    ///     data/weight of each edge and return a float to use as the entry
+    ///     for that edge. If not specified ``default_weight`` is used for
+    ///     every edge.
+    /// :param float default_weight: The entry used for each edge if
+    ///     ``weight_fn`` is not specified. By default this is ``1.0``.
+    ///
+    /// :returns: A tuple ``(indptr, indices, data, node_map)`` of the
+    ///     ``int64`` row pointer array of length ``len(graph) + 1``, the
+    ///     ``int64`` column index array, the ``float64`` entry array and a
+    ///     mapping of the row/column numbers to the node indices of the
+    ///     graph
+    /// :rtype: tuple[numpy.ndarray, numpy.ndarray, numpy.ndarray, NodeMap]
+    #[pyo3(
+        signature=(weight_fn=None, default_weight=1.0),
+        text_signature = "(self, /, weight_fn=None, default_weight=1.0)"
+    )]
+    pub fn to_csr<'py>(
+        &self,
+        py: Python<'py>,
+        weight_fn: Option<PyObject>,
+        default_weight: f64,
+    ) -> PyResult<CsrArrays<'py>> {
+        let node_count = self.graph.node_count();
+        let mut row_map: Vec<usize> = vec![usize::MAX; self.graph.node_bound()];
+        let mut node_map: DictMap<usize, usize> = DictMap::with_capacity(node_count);
+        for (row, node) in self.graph.node_indices().enumerate() {
+            row_map[node.index()] = row;
+            node_map.insert(row, node.index());
+        }
+        let mut indptr: Vec<i64> = Vec::with_capacity(node_count + 1);
+        let mut indices: Vec<i64> = Vec::with_capacity(2 * self.graph.edge_count());
+        let mut data: Vec<f64> = Vec::with_capacity(2 * self.graph.edge_count());
+        let mut row_entries: Vec<(usize, f64)> = Vec::new();
+        indptr.push(0);
+        for node in self.graph.node_indices() {
+            row_entries.clear();
+            for edge in self.graph.edges(node) {
+                let weight = weight_callable(py, &weight_fn, edge.weight(), default_weight)?;
+                row_entries.push((row_map[edge.target().index()], weight));
+            }
+            row_entries.sort_unstable_by_key(|entry| entry.0);
+            for &(column, weight) in row_entries.iter() {
+                if indices.len() > *indptr.last().unwrap() as usize
+                    && *indices.last().unwrap() == column as i64
+                {
+                    *data.last_mut().unwrap() += weight;
+                } else {
+                    indices.push(column as i64);
+                    data.push(weight);
+                }
+            }
+            indptr.push(indices.len() as i64);
+        }
+        Ok((
+            indptr.into_pyarray(py),
+            indices.into_pyarray(py),
+            data.into_pyarray(py),
+            NodeMap { node_map },
+        ))
+    }
+
+    /// Return the endpoints of every edge in the graph as a NumPy array
+    ///
+    /// This is the same as :meth:`~rustworkx.PyGraph.edge_list` but returns
+    /// a 2 dimensional array instead of a list of tuples, which can be used
+    /// for vectorized processing with NumPy or SciPy without iterating over
+    /// the edges in Python.
+    ///
+    /// :returns: A ``uint32`` array of shape ``(len(graph.edge_indices()), 2)``
+    ///     where row ``i`` contains the endpoints of the edge with index
+    ///     ``graph.edge_indices()[i]``
+    /// :rtype: numpy.ndarray
+    #[pyo3(text_signature = "(self)")]
+    pub fn edge_endpoints_array<'py>(&self, py: Python<'py>) -> Bound<'py, PyArray2<u32>> {
+        let mut endpoints = Array2::<u32>::zeros((self.graph.edge_count(), 2));
+        for (row, edge) in self.graph.edge_references().enumerate() {
+            endpoints[[row, 0]] = edge.source().index() as u32;
+            endpoints[[row, 1]] = edge.target().index() as u32;
+        }
+        endpoints.into_pyarray(py)
+    }
+
+    /// Return the weight of every edge in the graph as a NumPy array
+    ///
+    /// The weights are in the same order as the rows of
+    /// :meth:`~rustworkx.PyGraph.edge_endpoints_array`, so the two arrays
+    /// can be used together, for example to build a ``scipy.sparse`` matrix.
+    ///
+    /// :param weight_fn: An optional callable that will be passed the
+    ///     data/weight of each edge and return a float to use as its weight.
+    ///     If not specified ``default`` is used for every edge.
+    /// :param float default: The weight used for each edge if ``weight_fn``
+    ///     is not specified. By default this is ``1.0``.
+    ///
+    /// :returns: A ``float64`` array of length ``len(graph.edge_indices())``
+    ///     where entry ``i`` is the weight of the edge with index
+    ///     ``graph.edge_indices()[i]``
+    /// :rtype: numpy.ndarray
+    #[pyo3(
+        signature=(weight_fn=None, default=1.0),
+        text_signature = "(self, /, weight_fn=None, default=1.0)"
+    )]
+    pub fn edge_weights_array<'py>(
+        &self,
+        py: Python<'py>,
+        weight_fn: Option<PyObject>,
+        default: f64,
+    ) -> PyResult<Bound<'py, PyArray1<f64>>> {
+        let weights = self
+            .graph
+            .edge_weights()
+            .map(|weight| weight_callable(py, &weight_fn, weight, default))
+            .collect::<PyResult<Vec<f64>>>()?;
+        Ok(weights.into_pyarray(py))
     }
 
     /// Add another PyGraph object into this PyGraph
@@ -1664,43 +5917,63 @@ impl PyGraph {
         node_map_func: Option<PyObject>,
         edge_map_func: Option<PyObject>,
     ) -> PyResult<PyObject> {
-        let mut new_node_map: DictMap<NodeIndex, NodeIndex> =
-            DictMap::with_capacity(other.node_count());
-
-        // TODO: Reimplement this without looping over the graphs
-        // Loop over other nodes add to self graph
-        for node in other.graph.node_indices() {
-            let new_index = self.graph.add_node(weight_transform_callable(
-                py,
-                &node_map_func,
-                &other.graph[node],
-            )?);
-            new_node_map.insert(node, new_index);
-        }
-
-        // loop over other edges and add to self graph
-        for edge in other.graph.edge_references() {
-            let new_p_index = new_node_map.get(&edge.source()).unwrap();
-            let new_c_index = new_node_map.get(&edge.target()).unwrap();
-            let weight = weight_transform_callable(py, &edge_map_func, edge.weight())?;
-            self.graph.add_edge(*new_p_index, *new_c_index, weight);
-        }
-        // Add edges from map
-        for (this_index, (index, weight)) in node_map.iter() {
-            let new_index = new_node_map.get(&NodeIndex::new(*index)).unwrap();
-            self.graph.add_edge(
-                NodeIndex::new(*this_index),
-                *new_index,
-                weight.clone_ref(py),
-            );
-        }
+        let (new_indices, _) =
+            self.compose_graph(py, other, node_map, node_map_func, edge_map_func, false)?;
         let out_dict = PyDict::new(py);
-        for (orig_node, new_node) in new_node_map.iter() {
-            out_dict.set_item(orig_node.index(), new_node.index())?;
+        for node in other.graph.node_indices() {
+            out_dict.set_item(node.index(), new_indices[node.index()].index())?;
         }
         Ok(out_dict.into())
     }
 
+    /// Add another PyGraph object into this PyGraph and return how its nodes
+    /// and edges map to this graph
+    ///
+    /// This is the same as :meth:`~rustworkx.PyGraph.compose`, but in
+    /// addition to the node index mapping it returns the edge index mappings
+    /// needed to trace edge data through the composition.
+    ///
+    /// :param PyGraph other: The other PyGraph object to add onto this
+    ///     graph.
+    /// :param dict[int, tuple[int, T]] node_map: A dictionary mapping node
+    ///     indices from this PyGraph object to a tuple of the node index in
+    ///     the other PyGraph object to add an edge to and the weight of that
+    ///     edge, see :meth:`~rustworkx.PyGraph.compose`.
+    /// :param Callable node_map_func: An optional python callable that will take in a
+    ///     single node weight/data object and return a new node weight/data
+    ///     object that will be used when adding an node from other onto this
+    ///     graph.
+    /// :param Callable edge_map_func: An optional python callable that will take in a
+    ///     single edge weight/data object and return a new edge weight/data
+    ///     object that will be used when adding an edge from other onto this
+    ///     graph.
+    ///
+    /// :returns: A tuple of 3 dictionaries: the mapping of node indices from
+    ///     the other graph to node indices in this graph, the mapping of edge
+    ///     indices from the other graph to the edge indices of the new edges
+    ///     in this graph, and the mapping of the keys of ``node_map`` (node
+    ///     indices in this graph) to the index of the edge added for them.
+    /// :rtype: tuple[dict[int, int], dict[int, int], dict[int, int]]
+    #[pyo3(text_signature = "(self, other, node_map, /, node_map_func=None, edge_map_func=None)", signature = (other, node_map, node_map_func=None, edge_map_func=None))]
+    pub fn compose_with_edge_map(
+        &mut self,
+        py: Python,
+        other: &PyGraph,
+        node_map: HashMap<usize, (usize, PyObject)>,
+        node_map_func: Option<PyObject>,
+        edge_map_func: Option<PyObject>,
+    ) -> PyResult<ComposeMaps> {
+        let (new_indices, edge_maps) =
+            self.compose_graph(py, other, node_map, node_map_func, edge_map_func, true)?;
+        let (new_edge_map, node_map_edges) = edge_maps.unwrap();
+        let new_node_map = other
+            .graph
+            .node_indices()
+            .map(|node| (node.index(), new_indices[node.index()].index()))
+            .collect();
+        Ok((new_node_map, new_edge_map, node_map_edges))
+    }
+
     /// Substitute a node with a PyGraph object
     ///
     /// :param int node: The index of the node to be replaced with the PyGraph object
@@ -1719,6 +5992,16 @@ impl PyGraph {
     ///     will return an object to use as the weight for a newly created edge
     ///     after the edge is mapped from ``other``. If not specified the weight
     ///     from the edge in ``other`` will be copied by reference and used.
+    /// :param bool strict: If set to ``True`` every edge to or from ``node``
+    ///     must be mapped to a node of ``other`` that is included in the
+    ///     graph. ``edge_map_fn`` is called for all of these edges before the
+    ///     graph is modified, and if it returns ``None`` or an index that is
+    ///     not a (filtered) node of ``other`` for any of them a single
+    ///     ``ValueError`` listing every such edge as its ``(source, target)``
+    ///     endpoints and the returned value is raised and the graph is left
+    ///     unchanged. By default this is ``False``, edges for which ``None``
+    ///     is returned are skipped and an ``IndexError`` is raised for the
+    ///     first invalid index.
     ///
     /// :returns: A mapping of node indices in ``other`` to the equivalent node
     ///     in this graph.
@@ -1732,9 +6015,10 @@ impl PyGraph {
     ///    order when iterated over multiple times).
     ///
     #[pyo3(
-        text_signature = "(self, node, other, edge_map_fn, /, node_filter=None, edge_weight_map=None",
-        signature = (node, other, edge_map_fn, node_filter=None, edge_weight_map=None)
+        text_signature = "(self, node, other, edge_map_fn, /, node_filter=None, edge_weight_map=None, strict=False)",
+        signature = (node, other, edge_map_fn, node_filter=None, edge_weight_map=None, strict=false)
     )]
+    #[allow(clippy::too_many_arguments)]
     fn substitute_node_with_subgraph(
         &mut self,
         py: Python,
@@ -1743,6 +6027,7 @@ impl PyGraph {
         edge_map_fn: PyObject,
         node_filter: Option<PyObject>,
         edge_weight_map: Option<PyObject>,
+        strict: bool,
     ) -> PyResult<NodeMap> {
         let filter_fn = |obj: &PyObject, filter_fn: &Option<PyObject>| -> PyResult<bool> {
             match filter_fn {
@@ -1761,9 +6046,8 @@ impl PyGraph {
             }
         };
 
-        let map_fn = |source: usize, target: usize, weight: &PyObject| -> PyResult<Option<usize>> {
-            let res = edge_map_fn.call1(py, (source, target, weight))?;
-            res.extract(py)
+        let map_fn = |source: usize, target: usize, weight: &PyObject| -> PyResult<PyObject> {
+            edge_map_fn.call1(py, (source, target, weight))
         };
 
         let node_index = NodeIndex::new(node);
@@ -1773,17 +6057,70 @@ impl PyGraph {
             )));
         }
 
-        // Copy all nodes from other to self
-        let mut out_map: DictMap<usize, usize> = DictMap::with_capacity(other.node_count());
+        let mut kept_nodes: Vec<NodeIndex> = Vec::with_capacity(other.node_count());
         for node in other.graph.node_indices() {
-            let node_weight: Py<PyAny> = other.graph[node].clone_ref(py);
-            if !filter_fn(&node_weight, &node_filter)? {
-                continue;
+            if filter_fn(&other.graph[node], &node_filter)? {
+                kept_nodes.push(node);
+            }
+        }
+
+        // Incoming and outgoing edges.
+        let in_edges: Vec<(NodeIndex, NodeIndex, PyObject)> = self
+            .graph
+            .edge_references()
+            .filter(|edge| edge.target() == node_index)
+            .map(|edge| (edge.source(), edge.target(), edge.weight().clone_ref(py)))
+            .collect();
+        // Keep track of what's present on incoming edges
+        let in_set: HashSet<(NodeIndex, NodeIndex)> =
+            in_edges.iter().map(|edge| (edge.0, edge.1)).collect();
+        // Retrieve outgoing edges. Make sure to not include any incoming edge.
+        let out_edges: Vec<(NodeIndex, NodeIndex, PyObject)> = self
+            .graph
+            .edges(node_index)
+            .filter(|edge| !in_set.contains(&(edge.target(), edge.source())))
+            .map(|edge| (edge.source(), edge.target(), edge.weight().clone_ref(py)))
+            .collect();
+
+        // In strict mode map every edge up front, so a failure leaves the
+        // graph unchanged and reports all the unmapped edges at once.
+        let strict_map: Option<Vec<usize>> = if strict {
+            let kept_set: HashSet<usize> = kept_nodes.iter().map(|node| node.index()).collect();
+            let mut mapped: Vec<usize> = Vec::with_capacity(in_edges.len() + out_edges.len());
+            let mut failures: Vec<String> = Vec::new();
+            for (source, target, weight) in in_edges.iter().chain(out_edges.iter()) {
+                let res = map_fn(source.index(), target.index(), weight)?;
+                match res.extract::<Option<usize>>(py)? {
+                    Some(old_index) if kept_set.contains(&old_index) => mapped.push(old_index),
+                    _ => failures.push(format!(
+                        "({}, {}) -> {}",
+                        source.index(),
+                        target.index(),
+                        res.bind(py).repr()?
+                    )),
+                }
             }
-            let new_index: NodeIndex = self.graph.add_node(node_weight);
+            if !failures.is_empty() {
+                return Err(PyValueError::new_err(format!(
+                    "Failed to map {} edges of node {node}: {}",
+                    failures.len(),
+                    failures.join(", ")
+                )));
+            }
+            Some(mapped)
+        } else {
+            None
+        };
+
+        // Copy all nodes from other to self
+        let mut out_map: DictMap<usize, usize> = DictMap::with_capacity(kept_nodes.len());
+        for node in kept_nodes {
+            let new_index: NodeIndex = self.graph.add_node(other.graph[node].clone_ref(py));
             out_map.insert(node.index(), new_index.index());
         }
 
+        // the node is removed, which can disconnect its neighbors
+        self.connectivity = None;
         if out_map.is_empty() {
             self.graph.remove_node(node_index);
             return Ok(NodeMap {
@@ -1802,52 +6139,31 @@ impl PyGraph {
                 weight_map_fn(edge.weight(), &edge_weight_map)?,
             );
         }
-        // Incoming and outgoing edges.
-        let in_edges: Vec<(NodeIndex, NodeIndex, PyObject)> = self
-            .graph
-            .edge_references()
-            .filter(|edge| edge.target() == node_index)
-            .map(|edge| (edge.source(), edge.target(), edge.weight().clone_ref(py)))
-            .collect();
-        // Keep track of what's present on incoming edges
-        let in_set: HashSet<(NodeIndex, NodeIndex)> =
-            in_edges.iter().map(|edge| (edge.0, edge.1)).collect();
-        // Retrieve outgoing edges. Make sure to not include any incoming edge.
-        let out_edges: Vec<(NodeIndex, NodeIndex, PyObject)> = self
-            .graph
-            .edges(node_index)
-            .filter(|edge| !in_set.contains(&(edge.target(), edge.source())))
-            .map(|edge| (edge.source(), edge.target(), edge.weight().clone_ref(py)))
-            .collect();
-        for (source, target, weight) in in_edges {
-            let old_index: Option<usize> = map_fn(source.index(), target.index(), &weight)?;
-            let target_out: NodeIndex = match old_index {
-                Some(old_index) => match out_map.get(&old_index) {
-                    Some(new_index) => NodeIndex::new(*new_index),
-                    None => {
-                        return Err(PyIndexError::new_err(format!(
-                            "No mapped index {old_index} found"
-                        )))
-                    }
+        let num_in_edges = in_edges.len();
+        let boundary_edges = in_edges.into_iter().chain(out_edges);
+        for (position, (source, target, weight)) in boundary_edges.enumerate() {
+            let old_index: usize = match &strict_map {
+                Some(mapped) => mapped[position],
+                None => match map_fn(source.index(), target.index(), &weight)?
+                    .extract::<Option<usize>>(py)?
+                {
+                    Some(old_index) => old_index,
+                    None => continue,
                 },
-                None => continue,
             };
-            self._add_edge(source, target_out, weight);
-        }
-        for (source, target, weight) in out_edges {
-            let old_index: Option<usize> = map_fn(source.index(), target.index(), &weight)?;
-            let source_out: NodeIndex = match old_index {
-                Some(old_index) => match out_map.get(&old_index) {
-                    Some(new_index) => NodeIndex::new(*new_index),
-                    None => {
-                        return Err(PyIndexError::new_err(format!(
-                            "No mapped index {old_index} found"
-                        )))
-                    }
-                },
-                None => continue,
+            let new_index = match out_map.get(&old_index) {
+                Some(new_index) => NodeIndex::new(*new_index),
+                None => {
+                    return Err(PyIndexError::new_err(format!(
+                        "No mapped index {old_index} found"
+                    )))
+                }
             };
-            self._add_edge(source_out, target, weight);
+            if position < num_in_edges {
+                self._add_edge(source, new_index, weight);
+            } else {
+                self._add_edge(new_index, target, weight);
+            }
         }
         // Remove original node
         self.graph.remove_node(node_index);
@@ -1864,7 +6180,9 @@ impl PyGraph {
     /// :param list[int] nodes: A set of nodes to be removed and replaced
     ///     by the new node. Any nodes not in the graph are ignored.
     ///     If empty, this method behaves like :meth:`~PyGraph.add_node`
-    ///     (but slower).
+    ///     (but slower). This can also be a 1 dimensional NumPy array of
+    ///     ``int64`` or ``uint64`` node indices, which is read directly
+    ///     without converting it to a list first.
     /// :param S obj: The data/weight to associate with the new node.
     /// :param Callable weight_combo_fn: An optional python callable that, when
     ///     specified, is used to merge parallel edges introduced by the
@@ -1875,31 +6193,185 @@ impl PyGraph {
     ///     when not a multigraph, parallel edges and their weights will be
     ///     combined by choosing one of the edge's weights arbitrarily based
     ///     on an internal iteration order, subject to change.
+    /// :param bool keep_internal_edges_as_self_loops: If set to ``True`` the
+    ///     edges between two nodes of ``nodes`` (including self loops on them)
+    ///     are kept as self loops on the new node, for example to preserve
+    ///     the total edge weight of the graph when coarsening it. If
+    ///     ``weight_combo_fn`` is specified, or the graph is not a multigraph,
+    ///     these are merged into a single self loop in the same way as the
+    ///     other parallel edges. Defaults to ``False``, in which case these
+    ///     edges are removed along with ``nodes``.
     /// :returns: The index of the newly created node.
     /// :rtype: int
-    #[pyo3(text_signature = "(self, nodes, obj, /, weight_combo_fn=None)", signature = (nodes, obj, weight_combo_fn=None))]
+    #[pyo3(
+        text_signature = "(self, nodes, obj, /, weight_combo_fn=None, keep_internal_edges_as_self_loops=False)",
+        signature = (nodes, obj, weight_combo_fn=None, keep_internal_edges_as_self_loops=false)
+    )]
     pub fn contract_nodes(
         &mut self,
         py: Python,
-        nodes: Vec<usize>,
+        nodes: &Bound<PyAny>,
         obj: PyObject,
         weight_combo_fn: Option<PyObject>,
+        keep_internal_edges_as_self_loops: bool,
     ) -> RxPyResult<usize> {
-        let nodes = nodes.into_iter().map(|i| NodeIndex::new(i));
-        let res = match (weight_combo_fn, &self.multigraph) {
-            (Some(user_callback), _) => {
-                self.graph
-                    .contract_nodes_simple(nodes, obj, |w1, w2| user_callback.call1(py, (w1, w2)))?
-            }
-            (None, false) => {
-                // By default, just take first edge.
-                self.graph.contract_nodes_simple(nodes, obj, move |w1, _| {
-                    Ok::<_, PyErr>(w1.clone_ref(py))
-                })?
+        let nodes = node_index_array(nodes)?;
+        self.contract_node_list(
+            py,
+            nodes,
+            obj,
+            weight_combo_fn,
+            keep_internal_edges_as_self_loops,
+        )
+    }
+
+    /// Substitute a set of nodes with a single new node and return how the
+    /// edges of the new node map to the edges they replace.
+    ///
+    /// This contracts the nodes in the same way as
+    /// :meth:`~PyGraph.contract_nodes`, and additionally returns a mapping
+    /// that can be used to propagate data from the edges between ``nodes``
+    /// and the rest of the graph to the edges of the new node.
+    ///
+    /// :param list[int] nodes: A set of nodes to be removed and replaced
+    ///     by the new node. Any nodes not in the graph are ignored.
+    /// :param S obj: The data/weight to associate with the new node.
+    /// :param Callable weight_combo_fn: An optional python callable that, when
+    ///     specified, is used to merge parallel edges introduced by the
+    ///     contraction, with the same behavior as in
+    ///     :meth:`~PyGraph.contract_nodes`.
+    /// :returns: A tuple of the index of the newly created node and a
+    ///     dictionary mapping the index of each new edge of the new node to
+    ///     the index of the edge it replaces, or to the list of indices of
+    ///     the edges that were merged into it.
+    /// :rtype: tuple[int, dict[int, int | list[int]]]
+    #[pyo3(text_signature = "(self, nodes, obj, /, weight_combo_fn=None)", signature = (nodes, obj, weight_combo_fn=None))]
+    pub fn contract_nodes_with_map(
+        &mut self,
+        py: Python,
+        nodes: Vec<usize>,
+        obj: PyObject,
+        weight_combo_fn: Option<PyObject>,
+    ) -> PyResult<(usize, DictMap<usize, PyObject>)> {
+        let mut node_set: HashSet<NodeIndex> = HashSet::with_capacity(nodes.len());
+        let nodes: Vec<NodeIndex> = nodes
+            .into_iter()
+            .map(NodeIndex::new)
+            .filter(|node| self.graph.contains_node(*node) && node_set.insert(*node))
+            .collect();
+        // the edges between the contracted nodes and the rest of the graph,
+        // grouped by the outside endpoint if parallel edges get merged
+        let merge = weight_combo_fn.is_some() || !self.multigraph;
+        let mut new_edges: Vec<(NodeIndex, PyObject, Vec<usize>)> = Vec::new();
+        let mut merged_edges: DictMap<NodeIndex, usize> = DictMap::new();
+        for node in nodes.iter() {
+            for edge in self
+                .graph
+                .edges_directed(*node, petgraph::Direction::Incoming)
+            {
+                let source = edge.source();
+                if node_set.contains(&source) {
+                    continue;
+                }
+                match merged_edges.get(&source) {
+                    Some(position) if merge => {
+                        let (_, weight, edges) = &mut new_edges[*position];
+                        *weight = match &weight_combo_fn {
+                            Some(weight_combo_fn) => {
+                                weight_combo_fn.call1(py, (edge.weight(), &*weight))?
+                            }
+                            None => edge.weight().clone_ref(py),
+                        };
+                        edges.push(edge.id().index());
+                    }
+                    _ => {
+                        merged_edges.insert(source, new_edges.len());
+                        new_edges.push((
+                            source,
+                            edge.weight().clone_ref(py),
+                            vec![edge.id().index()],
+                        ));
+                    }
+                }
             }
-            (None, true) => self.graph.contract_nodes(nodes, obj),
+        }
+        self.connectivity = None;
+        let new_node = self.graph.add_node(obj);
+        let mut edge_map: DictMap<usize, PyObject> = DictMap::with_capacity(new_edges.len());
+        for (source, weight, edges) in new_edges {
+            let new_edge = self.graph.add_edge(source, new_node, weight);
+            let origin = if edges.len() == 1 {
+                edges[0].into_py_any(py)?
+            } else {
+                edges.into_py_any(py)?
+            };
+            edge_map.insert(new_edge.index(), origin);
+        }
+        for node in nodes {
+            self.graph.remove_node(node);
+            self.node_removed = true;
+        }
+        Ok((new_node.index(), edge_map))
+    }
+
+    /// Contract an edge, merging its two endpoints into a single new node.
+    ///
+    /// This is equivalent to calling :meth:`~PyGraph.contract_nodes` with the
+    /// endpoints of the edge.
+    ///
+    /// :param int edge_index: The index of the edge to contract.
+    /// :param S obj: The data/weight to associate with the new node.
+    /// :param Callable weight_combo_fn: An optional python callable that, when
+    ///     specified, is used to merge parallel edges introduced by the
+    ///     contraction, with the same behavior as in
+    ///     :meth:`~PyGraph.contract_nodes`.
+    /// :param bool keep_self_loops: If set to ``True`` every edge between the
+    ///     two endpoints (including the contracted edge) and every self loop
+    ///     on either endpoint becomes a self loop on the new node. These
+    ///     self loops are merged like other parallel edges. By default they
+    ///     are dropped.
+    /// :returns: The index of the newly created node.
+    /// :rtype: int
+    /// :raises IndexError: If the edge index is not present in the graph.
+    #[pyo3(
+        text_signature = "(self, edge_index, obj, /, weight_combo_fn=None, keep_self_loops=False)",
+        signature = (edge_index, obj, weight_combo_fn=None, keep_self_loops=false)
+    )]
+    pub fn contract_edge(
+        &mut self,
+        py: Python,
+        edge_index: usize,
+        obj: PyObject,
+        weight_combo_fn: Option<PyObject>,
+        keep_self_loops: bool,
+    ) -> RxPyResult<usize> {
+        let (node_a, node_b) = match self.graph.edge_endpoints(EdgeIndex::new(edge_index)) {
+            Some(endpoints) => endpoints,
+            None => return Err(PyIndexError::new_err("No edge found for index").into()),
         };
-        Ok(res.index())
+        self.connectivity = None;
+        let mut self_loops: Vec<(EdgeIndex, PyObject)> = Vec::new();
+        if keep_self_loops {
+            let internal = |node: NodeIndex| node == node_a || node == node_b;
+            self_loops.extend(
+                self.graph
+                    .edges(node_a)
+                    .chain(self.graph.edges(node_b))
+                    .filter(|edge| internal(edge.source()) && internal(edge.target()))
+                    .map(|edge| (edge.id(), edge.weight().clone_ref(py))),
+            );
+            self_loops.sort_unstable_by_key(|(edge, _)| *edge);
+            self_loops.dedup_by_key(|(edge, _)| *edge);
+        }
+        let combo_fn = weight_combo_fn
+            .as_ref()
+            .map(|combo_fn| combo_fn.clone_ref(py));
+        let nodes = vec![node_a.index(), node_b.index()];
+        let node = NodeIndex::new(self.contract_node_list(py, nodes, obj, combo_fn, false)?);
+        for (_, weight) in self_loops {
+            self.add_or_merge_edge(py, node, node, weight, weight_combo_fn.as_ref())?;
+        }
+        Ok(node.index())
     }
 
     /// Return a new PyGraph object for a subgraph of this graph and a NodeMap
@@ -1917,55 +6389,113 @@ impl PyGraph {
     ///     will be copied by reference to be the attributes of the output
     ///     subgraph. By default this is set to False and the :attr:`~.PyGraph.attrs`
     ///     attribute will be ``None`` in the subgraph.
+    /// :param bool edge_map: If set to True a dictionary that maps the index
+    ///     of every edge of this graph that is retained in the subgraph to
+    ///     its index in the subgraph is returned as a third element of the
+    ///     output tuple. By default this is set to False.
     ///
     /// :returns: A tuple containing a new PyGraph object representing a subgraph of this graph
     ///     and a NodeMap object that maps the nodes of the subgraph to the nodes of the original graph.
+    ///     If ``edge_map`` is True the tuple also contains the edge index
+    ///     mapping dictionary.
     ///     It is worth noting that node and edge weight/data payloads are
     ///     passed by reference so if you update (not replace) an object used
     ///     as the weight in graph or the subgraph it will also be updated in
     ///     the other.
-    /// :rtype: tuple[PyGraph, NodeMap]
+    /// :rtype: tuple[PyGraph, NodeMap] | tuple[PyGraph, NodeMap, dict[int, int]]
     ///
-    #[pyo3(signature=(nodes, preserve_attrs=false), text_signature = "(self, nodes, /, preserve_attrs=False)")]
+    #[pyo3(
+        signature=(nodes, preserve_attrs=false, edge_map=false),
+        text_signature = "(self, nodes, /, preserve_attrs=False, edge_map=False)"
+    )]
     pub fn subgraph_with_nodemap(
         &self,
         py: Python,
         nodes: Vec<usize>,
         preserve_attrs: bool,
-    ) -> (PyGraph, NodeMap) {
-        let node_set: HashSet<usize> = nodes.iter().cloned().collect();
-        // mapping from original node index to new node index
-        let mut node_map: HashMap<NodeIndex, NodeIndex> = HashMap::with_capacity(nodes.len());
-        // mapping from new node index to original node index
-        let mut node_dict: DictMap<usize, usize> = DictMap::with_capacity(nodes.len());
-        let node_filter = |node: NodeIndex| -> bool { node_set.contains(&node.index()) };
-        let mut out_graph = StablePyGraph::<Undirected>::default();
-        let filtered = NodeFiltered(&self.graph, node_filter);
-        for node in filtered.node_references() {
-            let new_node = out_graph.add_node(node.1.clone_ref(py));
-            node_map.insert(node.0, new_node);
-            node_dict.insert(new_node.index(), node.0.index());
-        }
-        for edge in filtered.edge_references() {
-            let new_source = *node_map.get(&edge.source()).unwrap();
-            let new_target = *node_map.get(&edge.target()).unwrap();
-            out_graph.add_edge(new_source, new_target, edge.weight().clone_ref(py));
-        }
-        let attrs = if preserve_attrs {
-            self.attrs.clone_ref(py)
+        edge_map: bool,
+    ) -> PyResult<PyObject> {
+        let (subgraph, node_map, edge_map) = if nodes.is_empty() {
+            let attrs = if preserve_attrs {
+                self.attrs.clone_ref(py)
+            } else {
+                py.None()
+            };
+            let subgraph = PyGraph {
+                graph: StablePyGraph::<Undirected>::default(),
+                node_removed: false,
+                multigraph: self.multigraph,
+                attrs,
+                connectivity: None,
+            };
+            let node_map = NodeMap {
+                node_map: DictMap::new(),
+            };
+            (subgraph, node_map, edge_map.then(DictMap::new))
+        } else if self.selects_all_nodes(&nodes) {
+            self.full_node_subgraph(py, preserve_attrs, edge_map)
         } else {
-            py.None()
-        };
-        let node_map = NodeMap {
-            node_map: node_dict,
-        };
-        let subgraph = PyGraph {
-            graph: out_graph,
-            node_removed: false,
-            multigraph: self.multigraph,
-            attrs,
+            let node_filter = self.node_selection_filter(nodes.iter().copied());
+            self.build_node_subgraph(py, node_filter, nodes.len(), preserve_attrs, edge_map)
         };
-        (subgraph, node_map)
+        match edge_map {
+            Some(edge_map) => (subgraph, node_map, edge_map).into_py_any(py),
+            None => (subgraph, node_map).into_py_any(py),
+        }
+    }
+
+    /// Return a new PyGraph object for the subgraph of the nodes selected by
+    /// a boolean mask and a NodeMap object that maps the nodes of the
+    /// subgraph to the nodes of the original graph.
+    ///
+    /// This is equivalent to :meth:`.subgraph_with_nodemap()` called with the
+    /// indices of the ``True`` entries of ``mask``, without building that
+    /// list of indices.
+    ///
+    /// :param numpy.ndarray mask: A 1 dimensional boolean array with an entry
+    ///     for every node index up to the highest node index in the graph,
+    ///     i.e. of length ``max(graph.node_indices()) + 1``, where the entry
+    ///     at a node index is ``True`` if the node is part of the subgraph.
+    ///     Entries for indices that aren't present in the graph are ignored.
+    /// :param bool preserve_attrs: If set to the True the attributes of the PyGraph
+    ///     will be copied by reference to be the attributes of the output
+    ///     subgraph. By default this is set to False and the :attr:`~.PyGraph.attrs`
+    ///     attribute will be ``None`` in the subgraph.
+    ///
+    /// :returns: A tuple containing a new PyGraph object representing a subgraph of this graph
+    ///     and a NodeMap object that maps the nodes of the subgraph to the nodes of the original graph.
+    /// :rtype: tuple[PyGraph, NodeMap]
+    /// :raises ValueError: If ``mask`` doesn't have the expected length
+    #[pyo3(signature=(mask, preserve_attrs=false), text_signature = "(self, mask, /, preserve_attrs=False)")]
+    pub fn subgraph_from_mask(
+        &self,
+        py: Python,
+        mask: PyReadonlyArray1<bool>,
+        preserve_attrs: bool,
+    ) -> PyResult<(PyGraph, NodeMap)> {
+        let mask = mask.as_array();
+        let node_bound = self.graph.node_bound();
+        if mask.len() != node_bound {
+            return Err(PyValueError::new_err(format!(
+                "Mask length {} does not match the expected length {node_bound}",
+                mask.len()
+            )));
+        }
+        let mut node_bits = FixedBitSet::with_capacity(node_bound);
+        for (node, selected) in mask.iter().enumerate() {
+            if *selected {
+                node_bits.insert(node);
+            }
+        }
+        let capacity = node_bits.count_ones(..);
+        let (subgraph, node_map, _) = self.build_node_subgraph(
+            py,
+            move |node: NodeIndex| node_bits.contains(node.index()),
+            capacity,
+            preserve_attrs,
+            false,
+        );
+        Ok((subgraph, node_map))
     }
 
     /// Return a new PyGraph object for a subgraph of this graph.
@@ -1976,11 +6506,21 @@ impl PyGraph {
     ///
     /// :param list[int] nodes: A list of node indices to generate the subgraph
     ///     from. If a node index is included that is not present in the graph
-    ///     it will silently be ignored.
+    ///     it will silently be ignored, unless ``validate`` is set.
     /// :param bool preserve_attrs: If set to the True the attributes of the PyGraph
     ///     will be copied by reference to be the attributes of the output
     ///     subgraph. By default this is set to False and the :attr:`~.PyGraph.attrs`
     ///     attribute will be ``None`` in the subgraph.
+    /// :param bool preserve_indices: If set to True each node of the subgraph
+    ///     keeps its node index from this graph, leaving holes in the index
+    ///     space of the subgraph for the nodes that aren't retained, so
+    ///     ``subgraph[i]`` is the same object as ``graph[i]`` for every
+    ///     retained node ``i``. Edge indices are not preserved. By default
+    ///     this is set to False and the nodes of the subgraph are assigned
+    ///     the indices ``0..len(subgraph)``.
+    /// :param bool validate: If set to True an :class:`IndexError` is raised
+    ///     if any index in ``nodes`` is not present in the graph, instead of
+    ///     silently ignoring it. By default this is set to False.
     ///
     /// :returns: A new PyGraph object representing a subgraph of this graph.
     ///     It is worth noting that node and edge weight/data payloads are
@@ -1988,11 +6528,73 @@ impl PyGraph {
     ///     as the weight in graph or the subgraph it will also be updated in
     ///     the other.
     /// :rtype: PyGraph
+    /// :raises IndexError: If ``validate`` is True and any index in ``nodes``
+    ///     is not present in the graph. The error message lists all the
+    ///     missing indices.
     ///
-    #[pyo3(signature=(nodes, preserve_attrs=false), text_signature = "(self, nodes, /, preserve_attrs=False)")]
-    pub fn subgraph(&self, py: Python, nodes: Vec<usize>, preserve_attrs: bool) -> PyGraph {
-        let (subgraph, _) = self.subgraph_with_nodemap(py, nodes, preserve_attrs);
-        subgraph
+    #[pyo3(
+        signature=(nodes, preserve_attrs=false, preserve_indices=false, validate=false),
+        text_signature = "(self, nodes, /, preserve_attrs=False, preserve_indices=False, validate=False)"
+    )]
+    pub fn subgraph(
+        &self,
+        py: Python,
+        nodes: Vec<usize>,
+        preserve_attrs: bool,
+        preserve_indices: bool,
+        validate: bool,
+    ) -> PyResult<PyGraph> {
+        if validate {
+            let mut missing: Vec<usize> = nodes
+                .iter()
+                .copied()
+                .filter(|node| !self.graph.contains_node(NodeIndex::new(*node)))
+                .collect();
+            if !missing.is_empty() {
+                missing.sort_unstable();
+                missing.dedup();
+                return Err(PyIndexError::new_err(format!(
+                    "Node indices {missing:?} are not present in the graph"
+                )));
+            }
+        }
+        if !preserve_indices {
+            let node_filter = self.node_selection_filter(nodes.iter().copied());
+            let (subgraph, _, _) =
+                self.build_node_subgraph(py, node_filter, nodes.len(), preserve_attrs, false);
+            return Ok(subgraph);
+        }
+        let nodes = self.present_node_set(nodes);
+        let node_filter = self.node_selection_filter(nodes.iter().map(|node| node.index()));
+        let mut out_graph = StablePyGraph::<Undirected>::with_capacity(nodes.len(), 0);
+        // Temporary nodes filling the holes in the index space
+        let mut tmp_nodes = Vec::new();
+        for node in nodes {
+            while node.index() > out_graph.node_bound() {
+                tmp_nodes.push(out_graph.add_node(py.None()));
+            }
+            out_graph.add_node(self.graph[node].clone_ref(py));
+        }
+        let node_removed = !tmp_nodes.is_empty();
+        for tmp_node in tmp_nodes {
+            out_graph.remove_node(tmp_node);
+        }
+        let filtered = NodeFiltered(&self.graph, node_filter);
+        for edge in filtered.edge_references() {
+            out_graph.add_edge(edge.source(), edge.target(), edge.weight().clone_ref(py));
+        }
+        let attrs = if preserve_attrs {
+            self.attrs.clone_ref(py)
+        } else {
+            py.None()
+        };
+        Ok(PyGraph {
+            graph: out_graph,
+            node_removed,
+            multigraph: self.multigraph,
+            attrs,
+            connectivity: None,
+        })
     }
 
     /// Return a new PyGraph object for an edge induced subgraph of this graph
@@ -2000,19 +6602,63 @@ impl PyGraph {
     /// The induced subgraph contains each edge in `edge_list` and each node
     /// incident to any of those edges.
     ///
-    /// :param list[tuple[int, int]] edge_list: A list of edge tuples (2-tuples with the source
+    /// By default the edges are selected by their endpoints, so for a
+    /// multigraph specifying a ``(node_a, node_b)`` pair keeps *all* the
+    /// parallel edges between ``node_a`` and ``node_b``. To keep only some of
+    /// the parallel edges between two nodes set ``by_index`` to ``True`` and
+    /// pass the indices of the edges to keep instead. For example::
+    ///
+    ///     import rustworkx as rx
+    ///
+    ///     graph = rx.PyGraph()
+    ///     graph.add_nodes_from(["a", "b"])
+    ///     graph.add_edges_from([(0, 1, "first"), (0, 1, "second")])
+    ///     assert graph.edge_subgraph([(0, 1)]).edges() == ["first", "second"]
+    ///     assert graph.edge_subgraph([1], by_index=True).edges() == ["second"]
+    ///
+    /// .. note::
+    ///     The node indices of the subgraph are the same as in this graph,
+    ///     which requires copying the whole graph. To build only the
+    ///     selected edges and return a NodeMap object that maps the nodes of
+    ///     the subgraph to the nodes of the original graph instead, use
+    ///     :meth:`.edge_subgraph_with_nodemap()`.
+    ///
+    /// :param edge_list: A list of edge tuples (2-tuples with the source
     ///     and target node) to generate the subgraph from. In cases of parallel
     ///     edges for a multigraph all edges between the specified node. In case
     ///     of an edge specified that doesn't exist in the graph it will be
-    ///     silently ignored.
+    ///     silently ignored. If ``by_index`` is ``True`` this is a list of edge
+    ///     indices instead and any index not present in the graph is silently
+    ///     ignored.
+    /// :type edge_list: list[tuple[int, int]] | list[int]
+    /// :param bool by_index: If set to ``True`` interpret ``edge_list`` as a
+    ///     list of edge indices and keep exactly those edges. Defaults to
+    ///     ``False``.
     ///
     /// :returns: The edge subgraph
     /// :rtype: PyGraph
     ///
-    #[pyo3(text_signature = "(self, edge_list, /)")]
-    pub fn edge_subgraph(&self, edge_list: Vec<[usize; 2]>) -> PyGraph {
+    #[pyo3(signature=(edge_list, /, by_index=false), text_signature = "(self, edge_list, /, by_index=False)")]
+    pub fn edge_subgraph(&self, edge_list: &Bound<PyAny>, by_index: bool) -> PyResult<PyGraph> {
+        if by_index {
+            let edge_set: HashSet<EdgeIndex> = edge_list
+                .extract::<Vec<usize>>()?
+                .into_iter()
+                .map(EdgeIndex::new)
+                .filter(|edge| self.graph.edge_weight(*edge).is_some())
+                .collect();
+            let nodes: HashSet<NodeIndex> = edge_set
+                .iter()
+                .flat_map(|edge| {
+                    let (source, target) = self.graph.edge_endpoints(*edge).unwrap();
+                    [source, target]
+                })
+                .collect();
+            return Ok(self.retained_edge_subgraph(&nodes, |edge| edge_set.contains(&edge.id())));
+        }
         // Filter non-existent edges
         let edges: Vec<[usize; 2]> = edge_list
+            .extract::<Vec<[usize; 2]>>()?
             .into_iter()
             .filter(|x| {
                 let source = NodeIndex::new(x[0]);
@@ -2033,33 +6679,218 @@ impl PyGraph {
             let target_index = NodeIndex::new(edge[1]);
             edge_set.insert([source_index, target_index]);
         }
-        let mut out_graph = self.clone();
-        for node in self
-            .graph
-            .node_indices()
-            .filter(|node| !nodes.contains(node))
-        {
-            out_graph.graph.remove_node(node);
-            out_graph.node_removed = true;
-        }
-        for edge in self.graph.edge_references().filter(|edge| {
-            !edge_set.contains(&[edge.source(), edge.target()])
-                && !edge_set.contains(&[edge.target(), edge.source()])
-        }) {
-            out_graph.graph.remove_edge(edge.id());
+        Ok(self.retained_edge_subgraph(&nodes, |edge| {
+            edge_set.contains(&[edge.source(), edge.target()])
+                || edge_set.contains(&[edge.target(), edge.source()])
+        }))
+    }
+
+    /// Return a new PyGraph object for an edge induced subgraph of this graph
+    /// and a NodeMap object that maps the nodes of the subgraph to the nodes
+    /// of the original graph.
+    ///
+    /// The induced subgraph contains each edge in `edge_list` and each node
+    /// incident to any of those edges. Unlike :meth:`.edge_subgraph()` the
+    /// subgraph is built directly from the selected edges, so the time and
+    /// memory needed only depend on the size of the subgraph and the degree
+    /// of its nodes, not on the size of this graph. The nodes of the subgraph
+    /// are added in the order of their indices in this graph and the edges
+    /// in the order of their edge indices.
+    ///
+    /// :param list[tuple[int, int]] edge_list: A list of edge tuples (2-tuples with the source
+    ///     and target node) to generate the subgraph from. In cases of parallel
+    ///     edges for a multigraph all edges between the specified node. In case
+    ///     of an edge specified that doesn't exist in the graph it will be
+    ///     silently ignored.
+    /// :param bool preserve_attrs: If set to the True the attributes of the PyGraph
+    ///     will be copied by reference to be the attributes of the output
+    ///     subgraph. By default this is set to False and the :attr:`~.PyGraph.attrs`
+    ///     attribute will be ``None`` in the subgraph.
+    ///
+    /// :returns: A tuple of the edge subgraph and a NodeMap object mapping
+    ///     the node indices of the subgraph to the node indices of this graph.
+    ///     Node and edge weight/data payloads are passed by reference.
+    /// :rtype: tuple[PyGraph, NodeMap]
+    ///
+    #[pyo3(signature=(edge_list, preserve_attrs=false), text_signature = "(self, edge_list, /, preserve_attrs=False)")]
+    pub fn edge_subgraph_with_nodemap(
+        &self,
+        py: Python,
+        edge_list: Vec<[usize; 2]>,
+        preserve_attrs: bool,
+    ) -> (PyGraph, NodeMap) {
+        let mut pairs: HashSet<[NodeIndex; 2]> = HashSet::with_capacity(edge_list.len());
+        let mut edges: Vec<EdgeIndex> = Vec::with_capacity(edge_list.len());
+        let mut nodes: Vec<NodeIndex> = Vec::new();
+        for [source, target] in edge_list {
+            let source = NodeIndex::new(source);
+            let target = NodeIndex::new(target);
+            if !pairs.insert([source.min(target), source.max(target)]) {
+                continue;
+            }
+            let len_before = edges.len();
+            edges.extend(
+                self.graph
+                    .edges_connecting(source, target)
+                    .map(|edge| edge.id()),
+            );
+            if edges.len() > len_before {
+                nodes.push(source);
+                nodes.push(target);
+            }
         }
-        out_graph
+        self.build_edge_subgraph(py, nodes, edges, preserve_attrs)
     }
 
-    /// Return a shallow copy of the graph
+    /// Return a new PyGraph object for the subgraph induced by the edges
+    /// matching a filter function
+    ///
+    /// The filter function is passed the data payload of each edge and the
+    /// subgraph contains each edge it returns ``True`` for and the endpoints
+    /// of those edges, see :meth:`~rustworkx.PyGraph.filter_edges`. Only the
+    /// selected nodes and edges are copied. They are added to the subgraph in
+    /// index order, so the node and edge indices of the subgraph are
+    /// ``0..len(subgraph)`` and ``0..subgraph.num_edges()``.
+    ///
+    /// :param Callable filter_function: Function that is passed an edge's
+    ///     data payload and returns whether to include the edge
+    /// :param bool preserve_attrs: If set to ``True`` the attributes of the
+    ///     PyGraph will be copied by reference to be the attributes of the
+    ///     output subgraph. By default this is set to ``False`` and the
+    ///     :attr:`~.PyGraph.attrs` attribute will be ``None`` in the subgraph.
     ///
-    /// All node and edge weight/data payloads in the copy will have a
-    /// shared reference to the original graph.
-    /// :returns: A shallow copy of the graph
+    /// :returns: The edge subgraph
     /// :rtype: PyGraph
-    #[pyo3(text_signature = "(self)")]
-    pub fn copy(&self) -> PyGraph {
-        self.clone()
+    #[pyo3(
+        signature=(filter_function, /, preserve_attrs=false),
+        text_signature = "(self, filter_function, /, preserve_attrs=False)"
+    )]
+    pub fn edge_subgraph_by_filter(
+        &self,
+        py: Python,
+        filter_function: PyObject,
+        preserve_attrs: bool,
+    ) -> PyResult<PyGraph> {
+        let mut nodes: Vec<NodeIndex> = Vec::new();
+        let mut edges: Vec<EdgeIndex> = Vec::new();
+        for edge in self.graph.edge_references() {
+            if filter_function.call1(py, (edge.weight(),))?.extract(py)? {
+                edges.push(edge.id());
+                nodes.push(edge.source());
+                nodes.push(edge.target());
+            }
+        }
+        let (subgraph, _) = self.build_edge_subgraph(py, nodes, edges, preserve_attrs);
+        Ok(subgraph)
+    }
+
+    /// Return a copy of the graph
+    ///
+    /// .. warning::
+    ///
+    ///     By default this is a shallow copy: all node and edge weight/data
+    ///     payloads and the :attr:`~.PyGraph.attrs` attribute in the copy
+    ///     are shared references to the objects in the original graph, so
+    ///     mutating a payload in place (for example updating a ``dict``
+    ///     payload) is visible from both graphs. Use ``deep=True`` to copy
+    ///     the payloads too.
+    ///
+    /// :param bool deep: If set to ``True`` every node and edge payload and
+    ///     :attr:`~.PyGraph.attrs` are copied with :func:`copy.deepcopy`
+    ///     using a single memo dictionary, so objects shared between
+    ///     payloads in the original graph are also shared between the
+    ///     copied payloads (but not with the original graph). By default
+    ///     this is ``False``.
+    /// :param bool compact: If set to ``True`` the copy has contiguous node
+    ///     and edge indices, as if :meth:`~.PyGraph.compact` was called on
+    ///     it, instead of the same indices as this graph. Use
+    ///     :meth:`~.PyGraph.copy_with_nodemap` to also get the mapping of
+    ///     the node indices. By default this is ``False``.
+    ///
+    /// :returns: A copy of the graph
+    /// :rtype: PyGraph
+    #[pyo3(
+        signature = (deep=false, compact=false),
+        text_signature = "(self, /, deep=False, compact=False)"
+    )]
+    pub fn copy(&self, py: Python, deep: bool, compact: bool) -> PyResult<PyGraph> {
+        let mut out = if compact {
+            self.copy_with_nodemap(py, false)?.0
+        } else {
+            self.clone()
+        };
+        if deep {
+            out.deepcopy_payloads(py)?;
+        }
+        Ok(out)
+    }
+
+    /// Return a copy of the graph with contiguous indices and a NodeMap
+    ///
+    /// This is equivalent to ``graph.copy(compact=True)``, and additionally
+    /// returns the mapping of the node indices of this graph to the node
+    /// indices of the copy, like :meth:`~.PyGraph.compact` does. This graph
+    /// is left unchanged.
+    ///
+    /// :param bool deep: If set to ``True`` the payloads are copied with
+    ///     :func:`copy.deepcopy`, see :meth:`~.PyGraph.copy`. By default this
+    ///     is ``False``.
+    ///
+    /// :returns: A tuple of the copy of the graph and a mapping of the node
+    ///     indices of this graph to the node indices of the copy
+    /// :rtype: tuple[PyGraph, NodeMap]
+    #[pyo3(signature = (deep=false), text_signature = "(self, /, deep=False)")]
+    pub fn copy_with_nodemap(&self, py: Python, deep: bool) -> PyResult<(PyGraph, NodeMap)> {
+        let (graph, node_map) = self.compacted_graph(py);
+        let mut out = PyGraph {
+            graph,
+            node_removed: false,
+            multigraph: self.multigraph,
+            attrs: self.attrs.clone_ref(py),
+            connectivity: None,
+        };
+        if deep {
+            out.deepcopy_payloads(py)?;
+        }
+        Ok((out, NodeMap { node_map }))
+    }
+
+    /// Compare two graphs structurally
+    ///
+    /// Two :class:`~rustworkx.PyGraph` objects are equal if they have the same
+    /// ``multigraph`` flag, the same node indices with equal node payloads
+    /// and the same multiset of edges, where each edge is compared by its
+    /// endpoints and payload (edge indices are not compared). Payloads are
+    /// compared with Python's ``==``. Because node indices are stable this is
+    /// an index aligned comparison and not an isomorphism check, two graphs
+    /// with the same structure but different node indices are not equal. The
+    /// :attr:`~.PyGraph.attrs` attribute is not compared.
+    fn __richcmp__(&self, py: Python, other: &Bound<PyAny>, op: CompareOp) -> PyResult<PyObject> {
+        let other = match other.downcast::<PyGraph>() {
+            Ok(other) => other.borrow(),
+            Err(_) => return Ok(py.NotImplemented()),
+        };
+        match op {
+            CompareOp::Eq => self.structurally_equal(py, &other)?.into_py_any(py),
+            CompareOp::Ne => (!self.structurally_equal(py, &other)?).into_py_any(py),
+            _ => Ok(py.NotImplemented()),
+        }
+    }
+
+    /// Return a summary of the graph such as
+    /// ``PyGraph(multigraph=True, nodes=12, edges=34)``
+    ///
+    /// The node and edge data payloads are not included. For subclasses of
+    /// :class:`~rustworkx.PyGraph` the name of the subclass is used.
+    fn __repr__(slf: &Bound<'_, Self>) -> PyResult<String> {
+        let graph = slf.borrow();
+        Ok(format!(
+            "{}(multigraph={}, nodes={}, edges={})",
+            slf.get_type().name()?,
+            if graph.multigraph { "True" } else { "False" },
+            graph.graph.node_count(),
+            graph.graph.edge_count()
+        ))
     }
 
     /// Return the number of nodes in the graph
@@ -2067,6 +6898,30 @@ impl PyGraph {
         Ok(self.graph.node_count())
     }
 
+    /// Return an iterator over the node indices of the graph
+    ///
+    /// The indices are yielded in ascending order and, like
+    /// :meth:`~rustworkx.PyGraph.node_indices`, indices of removed nodes are
+    /// skipped, so ``len(list(graph)) == len(graph)``. The iterator is over a
+    /// snapshot of the indices taken when it is created.
+    fn __iter__<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyIterator>> {
+        self.node_indices().into_pyobject(py)?.try_iter()
+    }
+
+    /// Check if a node index is present in the graph, equivalent to
+    /// :meth:`~rustworkx.PyGraph.has_node`
+    ///
+    /// Operands that are not valid node indices, such as negative integers
+    /// or non-integers, are never contained in the graph.
+    fn __contains__(&self, idx: &Bound<PyAny>) -> bool {
+        match idx.extract::<usize>() {
+            // u32::MAX is reserved as the invalid index and larger values
+            // would be truncated by NodeIndex::new
+            Ok(idx) if idx < u32::MAX as usize => self.graph.contains_node(NodeIndex::new(idx)),
+            _ => false,
+        }
+    }
+
     fn __getitem__(&self, idx: usize) -> PyResult<&PyObject> {
         match self.graph.node_weight(NodeIndex::new(idx)) {
             Some(data) => Ok(data),
@@ -2087,6 +6942,7 @@ impl PyGraph {
         match self.graph.remove_node(NodeIndex::new(idx)) {
             Some(_) => {
                 self.node_removed = true;
+                self.connectivity = None;
                 Ok(())
             }
             None => Err(PyIndexError::new_err("No node found for index")),
@@ -2138,6 +6994,7 @@ impl PyGraph {
     fn __clear__(&mut self, py: Python) {
         self.graph = StablePyGraph::<Undirected>::default();
         self.node_removed = false;
+        self.connectivity = None;
         self.attrs = py.None();
     }
 
@@ -2220,6 +7077,14 @@ impl PyGraph {
     }
 }
 
+fn sorted_endpoints(node_a: NodeIndex, node_b: NodeIndex) -> (usize, usize) {
+    if node_a <= node_b {
+        (node_a.index(), node_b.index())
+    } else {
+        (node_b.index(), node_a.index())
+    }
+}
+
 fn weight_transform_callable(
     py: Python,
     map_fn: &Option<PyObject>,
@@ -2234,13 +7099,211 @@ fn weight_transform_callable(
     }
 }
 
+/// Choose one of the ``count`` present indices below ``bound`` uniformly at
+/// random. While at least half of the indices are present they're sampled
+/// directly, retrying on holes, otherwise the ``n``-th present index is found
+/// with ``nth`` so sparse index spaces don't need many retries.
+fn sample_present_index(
+    seed: Option<u64>,
+    bound: usize,
+    count: usize,
+    contains: impl Fn(usize) -> bool,
+    nth: impl FnOnce(usize) -> usize,
+) -> usize {
+    let mut rng: Pcg64 = match seed {
+        Some(seed) => Pcg64::seed_from_u64(seed),
+        None => Pcg64::from_os_rng(),
+    };
+    if 2 * count < bound {
+        return nth(rng.random_range(0..count));
+    }
+    loop {
+        let index = rng.random_range(0..bound);
+        if contains(index) {
+            return index;
+        }
+    }
+}
+
+/// A view of a scipy.sparse index array (``indptr`` or ``indices``), which
+/// can be either ``int32`` or ``int64`` depending on the size of the matrix.
+enum SparseIndexArray<'py> {
+    Int32(PyReadonlyArray1<'py, i32>),
+    Int64(PyReadonlyArray1<'py, i64>),
+}
+
+impl<'py> SparseIndexArray<'py> {
+    fn extract(array: &Bound<'py, PyAny>) -> PyResult<Self> {
+        if let Ok(array) = array.extract::<PyReadonlyArray1<i32>>() {
+            return Ok(SparseIndexArray::Int32(array));
+        }
+        Ok(SparseIndexArray::Int64(array.extract()?))
+    }
+
+    fn len(&self) -> usize {
+        match self {
+            SparseIndexArray::Int32(array) => array.as_array().len(),
+            SparseIndexArray::Int64(array) => array.as_array().len(),
+        }
+    }
+
+    /// Return the entry at ``pos`` as an index, or ``None`` if it is negative.
+    fn index(&self, pos: usize) -> Option<usize> {
+        match self {
+            SparseIndexArray::Int32(array) => usize::try_from(array.as_array()[pos]).ok(),
+            SparseIndexArray::Int64(array) => usize::try_from(array.as_array()[pos]).ok(),
+        }
+    }
+}
+
+/// Check a node count read from the header of a file, whose nodes are all
+/// added up front, against the largest number of nodes a graph can hold.
+pub(crate) fn check_header_node_count(
+    count: usize,
+    format: &str,
+    line_number: usize,
+) -> PyResult<()> {
+    // The largest index is reserved by petgraph as an end marker
+    let max_nodes = u32::MAX as usize;
+    if count > max_nodes {
+        return Err(PyValueError::new_err(format!(
+            "The {format} file declares {count} nodes on line {line_number}, more than the maximum of {max_nodes} nodes in a graph"
+        )));
+    }
+    Ok(())
+}
+
+/// Read a list of node indices, reading a 1 dimensional ``int64`` or
+/// ``uint64`` NumPy array directly instead of element by element.
+fn node_index_array(nodes: &Bound<'_, PyAny>) -> PyResult<Vec<usize>> {
+    if nodes.is_instance_of::<PyList>() {
+        return nodes.extract();
+    }
+    if let Ok(array) = nodes.extract::<PyReadonlyArray1<u64>>() {
+        return Ok(array.as_array().iter().map(|x| *x as usize).collect());
+    }
+    if let Ok(array) = nodes.extract::<PyReadonlyArray1<i64>>() {
+        return array
+            .as_array()
+            .iter()
+            .map(|x| {
+                usize::try_from(*x).map_err(|_| {
+                    PyOverflowError::new_err(format!("Negative node index {x} in array"))
+                })
+            })
+            .collect();
+    }
+    nodes.extract()
+}
+
+/// A writer passing its output to the ``write()`` method of a Python
+/// file-like object opened in text mode. A multi-byte character split across
+/// writes is held back until it is complete.
+struct PyTextWriter<'py> {
+    file: Bound<'py, PyAny>,
+    pending: Vec<u8>,
+}
+
+impl<'py> PyTextWriter<'py> {
+    fn new(file: Bound<'py, PyAny>) -> Self {
+        PyTextWriter {
+            file,
+            pending: Vec::new(),
+        }
+    }
+}
+
+impl Write for PyTextWriter<'_> {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.pending.extend_from_slice(buf);
+        let valid = match str::from_utf8(&self.pending) {
+            Ok(text) => text.len(),
+            Err(err) if err.error_len().is_none() => err.valid_up_to(),
+            Err(err) => return Err(std::io::Error::new(std::io::ErrorKind::InvalidData, err)),
+        };
+        if valid > 0 {
+            // The prefix was just checked to be valid UTF-8
+            let text = str::from_utf8(&self.pending[..valid]).unwrap();
+            self.file.call_method1("write", (text,))?;
+            self.pending.drain(..valid);
+        }
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+/// Quote an edge list weight if reading it back would otherwise not return
+/// the same string. Quoted weights are wrapped in ``"`` with ``\``, ``"``,
+/// line feeds and carriage returns backslash escaped.
+fn quote_edge_list_weight(weight: &str, delim: &str) -> String {
+    let needs_quotes = weight.is_empty()
+        || weight.contains(delim)
+        || weight.contains(['\n', '\r'])
+        || weight.starts_with('"')
+        || weight.starts_with(char::is_whitespace)
+        || weight.ends_with(char::is_whitespace)
+        || (delim.trim().is_empty() && weight.contains(char::is_whitespace));
+    if !needs_quotes {
+        return weight.to_string();
+    }
+    let mut quoted = String::with_capacity(weight.len() + 2);
+    quoted.push('"');
+    for c in weight.chars() {
+        match c {
+            '\\' => quoted.push_str("\\\\"),
+            '"' => quoted.push_str("\\\""),
+            '\n' => quoted.push_str("\\n"),
+            '\r' => quoted.push_str("\\r"),
+            c => quoted.push(c),
+        }
+    }
+    quoted.push('"');
+    quoted
+}
+
+/// Undo [`quote_edge_list_weight`], returning ``None`` if ``weight`` is not a
+/// valid quoted weight.
+fn unquote_edge_list_weight(weight: &str) -> Option<String> {
+    let inner = weight.trim_end().strip_prefix('"')?.strip_suffix('"')?;
+    let mut unquoted = String::with_capacity(inner.len());
+    let mut chars = inner.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => match chars.next()? {
+                'n' => unquoted.push('\n'),
+                'r' => unquoted.push('\r'),
+                c => unquoted.push(c),
+            },
+            // An unescaped quote can only be the closing quote
+            '"' => return None,
+            c => unquoted.push(c),
+        }
+    }
+    Some(unquoted)
+}
+
+/// Return the rest of a whitespace delimited line after its first ``count``
+/// fields, with the whitespace inside it left untouched.
+fn skip_whitespace_fields(line: &str, count: usize) -> &str {
+    let mut rest = line.trim_start();
+    for _ in 0..count {
+        let end = rest.find(char::is_whitespace).unwrap_or(rest.len());
+        rest = rest[end..].trim_start();
+    }
+    rest
+}
+
 fn _from_adjacency_matrix<'p, T>(
     py: Python<'p>,
     matrix: PyReadonlyArray2<'p, T>,
     null_value: T,
+    parallel_threshold: usize,
 ) -> PyResult<PyGraph>
 where
-    T: Copy + std::cmp::PartialEq + numpy::Element + pyo3::IntoPyObject<'p> + IsNan,
+    T: Copy + std::cmp::PartialEq + numpy::Element + pyo3::IntoPyObject<'p> + IsNan + Send + Sync,
 {
     let array = matrix.as_array();
     let shape = array.shape();
@@ -2248,33 +7311,45 @@ where
     let _node_indices: Vec<NodeIndex> = (0..shape[0])
         .map(|node| Ok(out_graph.add_node(node.into_py_any(py)?)))
         .collect::<PyResult<Vec<NodeIndex>>>()?;
-    for (index, row) in array.axis_iter(Axis(0)).enumerate() {
-        let source_index = NodeIndex::new(index);
-        for (target_index, elem) in row.iter().enumerate() {
-            if target_index < index {
-                continue;
-            }
-            if null_value.is_nan() {
-                if !elem.is_nan() {
-                    out_graph.add_edge(
-                        source_index,
-                        NodeIndex::new(target_index),
-                        elem.into_py_any(py)?,
-                    );
-                }
-            } else if *elem != null_value {
-                out_graph.add_edge(
-                    source_index,
-                    NodeIndex::new(target_index),
-                    elem.into_py_any(py)?,
-                );
-            }
+    let is_edge = |elem: &T| -> bool {
+        if null_value.is_nan() {
+            !elem.is_nan()
+        } else {
+            *elem != null_value
         }
+    };
+    // Edges of a row in the upper triangle (including the diagonal) of the
+    // matrix
+    let row_edges = |index: usize| {
+        array
+            .row(index)
+            .into_iter()
+            .enumerate()
+            .skip(index)
+            .filter(|(_, elem)| is_edge(elem))
+            .map(move |(target_index, elem)| (index, target_index, *elem))
+    };
+    let edges: Vec<(usize, usize, T)> = if shape[0] < parallel_threshold {
+        (0..shape[0]).flat_map(row_edges).collect()
+    } else {
+        (0..shape[0])
+            .into_par_iter()
+            .flat_map_iter(row_edges)
+            .collect()
+    };
+    out_graph.reserve_exact_edges(edges.len());
+    for (source_index, target_index, elem) in edges {
+        out_graph.add_edge(
+            NodeIndex::new(source_index),
+            NodeIndex::new(target_index),
+            elem.into_py_any(py)?,
+        );
     }
     Ok(PyGraph {
         graph: out_graph,
         node_removed: false,
         multigraph: true,
         attrs: py.None(),
+        connectivity: None,
     })
 }
@@ -35,6 +35,9 @@ use quick_xml::Error as XmlError;
 use quick_xml::{Reader, Writer};
 
 use petgraph::algo;
+use petgraph::visit::{
+    EdgeIndexable, EdgeRef, IntoEdgeReferences, IntoNodeReferences, NodeIndexable,
+};
 use petgraph::{Directed, EdgeType, Undirected};
 
 use pyo3::exceptions::PyException;
@@ -435,6 +438,7 @@ impl<'py> IntoPyObject<'py> for Graph {
                     node_removed: false,
                     multigraph: true,
                     attrs: self.attributes.into_py_any(py)?,
+                    connectivity: None,
                 };
 
                 Ok(out.into_pyobject(py)?.into_any())
@@ -518,12 +522,9 @@ impl<Index: std::cmp::Eq + std::hash::Hash> GraphElementInfos<Index> {
 }
 
 impl Graph {
-    fn try_from_stable<Ty: EdgeType>(
-        py: Python<'_>,
-        dir: Direction,
-        pygraph: &StablePyGraph<Ty>,
-        attrs: &PyObject,
-    ) -> PyResult<Self> {
+    /// Create an empty graph whose id and attributes are taken from the
+    /// ``attrs`` payload of a rustworkx graph.
+    fn with_attrs(py: Python<'_>, dir: Direction, attrs: &PyObject) -> PyResult<Self> {
         let mut attrs: Option<DictMap<String, Value>> = attrs.extract(py).ok();
         let id = attrs
             .as_mut()
@@ -537,6 +538,16 @@ impl Graph {
         if let Some(attributes) = attrs {
             graph.attributes.extend(attributes);
         }
+        Ok(graph)
+    }
+
+    fn try_from_stable<Ty: EdgeType>(
+        py: Python<'_>,
+        dir: Direction,
+        pygraph: &StablePyGraph<Ty>,
+        attrs: &PyObject,
+    ) -> PyResult<Self> {
+        let mut graph = Graph::with_attrs(py, dir, attrs)?;
         let mut node_infos = GraphElementInfos::new();
         for node_index in pygraph.node_indices() {
             node_infos.insert(py, node_index, pygraph.node_weight(node_index))?;
@@ -611,6 +622,170 @@ impl<'py> TryFrom<&Bound<'py, PyDiGraph>> for Graph {
     }
 }
 
+/// Return the GraphML data of a node or edge, either from the output of an
+/// attribute callback or from the payload itself if it is a dictionary.
+fn element_data(
+    py: Python<'_>,
+    weight: &PyObject,
+    attr_fn: Option<&PyObject>,
+) -> PyResult<DictMap<String, Value>> {
+    match attr_fn {
+        Some(attr_fn) => {
+            let attrs: DictMap<String, String> = attr_fn.call1(py, (weight,))?.extract(py)?;
+            Ok(attrs
+                .into_iter()
+                .map(|(name, value)| (name, Value::String(value)))
+                .collect())
+        }
+        None => Ok(weight.extract(py).unwrap_or_else(|_| DictMap::new())),
+    }
+}
+
+/// Parse the index out of a GraphML id of the form ``<prefix><index>``.
+fn index_from_id(id: &str, prefix: char) -> Option<u32> {
+    id.strip_prefix(prefix)?.parse().ok()
+}
+
+/// Return the indices encoded in a list of GraphML ids, or ``None`` if any
+/// of them is not of the form ``<prefix><index>`` or two ids share an index.
+fn indices_from_ids<'a>(ids: impl Iterator<Item = &'a str>, prefix: char) -> Option<Vec<u32>> {
+    let mut seen = HashSet::new();
+    ids.map(|id| index_from_id(id, prefix).filter(|index| seen.insert(*index)))
+        .collect()
+}
+
+impl Graph {
+    /// Build a GraphML graph from a :class:`~rustworkx.PyGraph` using the node
+    /// and edge indices as GraphML ids (``n<index>`` and ``e<index>``).
+    fn from_pygraph_with_index_ids(
+        py: Python<'_>,
+        pygraph: &PyGraph,
+        node_attr_fn: Option<&PyObject>,
+        edge_attr_fn: Option<&PyObject>,
+    ) -> PyResult<Self> {
+        let mut graph = Graph::with_attrs(py, Direction::UnDirected, &pygraph.attrs)?;
+        graph.nodes.reserve(pygraph.graph.node_count());
+        for (index, weight) in pygraph.graph.node_references() {
+            graph.nodes.push(Node {
+                id: format!("n{}", index.index()),
+                data: element_data(py, weight, node_attr_fn)?,
+            });
+        }
+        graph.edges.reserve(pygraph.graph.edge_count());
+        for edge in pygraph.graph.edge_references() {
+            graph.edges.push(Edge {
+                id: Some(format!("e{}", edge.id().index())),
+                source: format!("n{}", edge.source().index()),
+                target: format!("n{}", edge.target().index()),
+                data: element_data(py, edge.weight(), edge_attr_fn)?,
+            });
+        }
+        Ok(graph)
+    }
+
+    /// Convert an undirected GraphML graph into a :class:`~rustworkx.PyGraph`.
+    ///
+    /// If every node id is of the form ``n<index>`` (and, for edges, every
+    /// edge id is of the form ``e<index>``) the indices are preserved,
+    /// otherwise the ids are stored in the payloads as ``"id"`` like
+    /// :func:`~rustworkx.read_graphml` does.
+    fn into_pygraph(mut self, py: Python<'_>) -> PyResult<PyGraph> {
+        if let Direction::Directed = self.dir {
+            return Err(Error::UnSupported(String::from(
+                "Cannot read a directed GraphML graph into a PyGraph.",
+            ))
+            .into());
+        }
+        if let Some(id) = self.id {
+            self.attributes
+                .insert(String::from("id"), Value::String(id));
+        }
+        let node_indices = indices_from_ids(self.nodes.iter().map(|node| node.id.as_str()), 'n');
+        let edge_indices = node_indices.as_ref().and_then(|_| {
+            indices_from_ids(
+                self.edges
+                    .iter()
+                    .map(|edge| edge.id.as_deref().unwrap_or_default()),
+                'e',
+            )
+        });
+
+        let mut graph =
+            StablePyGraph::<Undirected>::with_capacity(self.nodes.len(), self.edges.len());
+        let mut mapping = DictMap::with_capacity(self.nodes.len());
+        let mut node_removed = false;
+        match node_indices {
+            Some(indices) => {
+                let mut nodes: Vec<(u32, Node)> = indices.into_iter().zip(self.nodes).collect();
+                nodes.sort_unstable_by_key(|(index, _)| *index);
+                // Temporary nodes filling the holes in the index space
+                let mut tmp_nodes = Vec::new();
+                for (index, node) in nodes {
+                    while (index as usize) > graph.node_bound() {
+                        tmp_nodes.push(graph.add_node(py.None()));
+                    }
+                    mapping.insert(node.id, graph.add_node(node.data.into_py_any(py)?));
+                }
+                node_removed = !tmp_nodes.is_empty();
+                for tmp_node in tmp_nodes {
+                    graph.remove_node(tmp_node);
+                }
+            }
+            None => {
+                for mut node in self.nodes {
+                    node.data
+                        .insert(String::from("id"), Value::String(node.id.clone()));
+                    mapping.insert(node.id, graph.add_node(node.data.into_py_any(py)?));
+                }
+            }
+        }
+
+        match edge_indices {
+            Some(indices) => {
+                let mut edges: Vec<(u32, Edge)> = indices.into_iter().zip(self.edges).collect();
+                edges.sort_unstable_by_key(|(index, _)| *index);
+                // Temporary edges on a temporary node fill the holes in the
+                // index space and are all removed at once with the node.
+                let tmp_node = graph.add_node(py.None());
+                for (index, edge) in edges {
+                    while (index as usize) > graph.edge_bound() {
+                        graph.add_edge(tmp_node, tmp_node, py.None());
+                    }
+                    match (mapping.get(&edge.source), mapping.get(&edge.target)) {
+                        (Some(&source), Some(&target)) => {
+                            graph.add_edge(source, target, edge.data.into_py_any(py)?);
+                        }
+                        _ => {
+                            graph.add_edge(tmp_node, tmp_node, py.None());
+                        }
+                    }
+                }
+                graph.remove_node(tmp_node);
+            }
+            None => {
+                for mut edge in self.edges {
+                    if let (Some(&source), Some(&target)) =
+                        (mapping.get(&edge.source), mapping.get(&edge.target))
+                    {
+                        if let Some(id) = edge.id {
+                            edge.data.insert(String::from("id"), Value::String(id));
+                        }
+                        graph.add_edge(source, target, edge.data.into_py_any(py)?);
+                    }
+                }
+            }
+        }
+
+        Ok(PyGraph {
+            graph,
+            node_removed,
+            multigraph: true,
+            attrs: self.attributes.into_py_any(py)?,
+            connectivity: None,
+        })
+    }
+}
+
 enum State {
     Start,
     Graph,
@@ -1322,3 +1497,38 @@ pub fn digraph_write_graphml(
     graphml.to_file(path, &compression.unwrap_or_default())?;
     Ok(())
 }
+
+/// Read the first graph of a GraphML file into a :class:`~rustworkx.PyGraph`.
+pub fn read_graphml_to_pygraph(
+    py: Python<'_>,
+    path: &str,
+    compression: Option<String>,
+) -> PyResult<PyGraph> {
+    let graphml = GraphML::from_file(path, &compression.unwrap_or_default())?;
+    match graphml.graphs.into_iter().next() {
+        Some(graph) => graph.into_pygraph(py),
+        None => Err(Error::NotFound(String::from("No graph found in the GraphML file.")).into()),
+    }
+}
+
+/// Write a :class:`~rustworkx.PyGraph` to a GraphML file using its node and
+/// edge indices as GraphML ids.
+pub fn write_pygraph_graphml(
+    py: Python<'_>,
+    graph: &PyGraph,
+    path: &str,
+    node_attr_fn: Option<PyObject>,
+    edge_attr_fn: Option<PyObject>,
+    compression: Option<String>,
+) -> PyResult<()> {
+    let mut graphml = GraphML::default();
+    graphml.graphs.push(Graph::from_pygraph_with_index_ids(
+        py,
+        graph,
+        node_attr_fn.as_ref(),
+        edge_attr_fn.as_ref(),
+    )?);
+    graphml.infer_keys()?;
+    graphml.to_file(path, &compression.unwrap_or_default())?;
+    Ok(())
+}
@@ -54,6 +54,11 @@ use pyo3::types::PySlice;
 use pyo3::IntoPyObjectExt;
 use pyo3::PyTraverseError;
 
+use petgraph::graph::{EdgeIndex, NodeIndex};
+use petgraph::visit::{EdgeIndexable, NodeIndexable};
+
+use crate::graph::PyGraph;
+
 macro_rules! last_type {
      ($a:ident,) => { $a };
      ($a:ident, $($rest_a:ident,)+) => { last_type!($($rest_a,)+) };
@@ -2016,3 +2021,77 @@ custom_hash_map_iter_impl!(
     "
 );
 impl PyGCProtocol for BiconnectedComponents {}
+
+macro_rules! lazy_index_iter_impl {
+    ($name:ident, $bound:ident, |$graph:ident, $index:ident| $contains:expr, $doc:literal) => {
+        #[doc = $doc]
+        #[pyclass(module = "rustworkx")]
+        pub struct $name {
+            graph: Option<Py<PyGraph>>,
+            next_index: usize,
+        }
+
+        impl $name {
+            pub fn new(graph: Py<PyGraph>) -> Self {
+                $name {
+                    graph: Some(graph),
+                    next_index: 0,
+                }
+            }
+        }
+
+        #[pymethods]
+        impl $name {
+            fn __iter__(slf: PyRef<Self>) -> Py<$name> {
+                slf.into()
+            }
+
+            fn __next__(&mut self, py: Python) -> Option<usize> {
+                let $graph = &self.graph.as_ref()?.borrow(py).graph;
+                while self.next_index < $graph.$bound() {
+                    let $index = self.next_index;
+                    self.next_index += 1;
+                    if $contains {
+                        return Some($index);
+                    }
+                }
+                None
+            }
+
+            fn __traverse__(&self, visit: PyVisit) -> Result<(), PyTraverseError> {
+                if let Some(graph) = &self.graph {
+                    visit.call(graph)?;
+                }
+                Ok(())
+            }
+
+            fn __clear__(&mut self) {
+                self.graph = None;
+            }
+        }
+    };
+}
+
+lazy_index_iter_impl!(
+    LazyNodeIndices,
+    node_bound,
+    |graph, index| graph.contains_node(NodeIndex::new(index)),
+    "A lazy iterator over the node indices of a :class:`~rustworkx.PyGraph`
+
+    This class is returned by :meth:`~rustworkx.PyGraph.iter_node_indices`
+    and yields the node indices in ascending order, looking each one up in
+    the graph only when it is requested.
+    "
+);
+
+lazy_index_iter_impl!(
+    LazyEdgeIndices,
+    edge_bound,
+    |graph, index| graph.edge_weight(EdgeIndex::new(index)).is_some(),
+    "A lazy iterator over the edge indices of a :class:`~rustworkx.PyGraph`
+
+    This class is returned by :meth:`~rustworkx.PyGraph.iter_edge_indices`
+    and yields the edge indices in ascending order, looking each one up in
+    the graph only when it is requested.
+    "
+);
@@ -95,6 +95,7 @@ pub fn from_node_link_json_file<'py>(
             node_removed: false,
             multigraph,
             attrs,
+            connectivity: None,
         }
         .into_pyobject(py)?
         .into_any()
@@ -170,6 +171,7 @@ pub fn parse_node_link_json<'py>(
             node_removed: false,
             multigraph,
             attrs,
+            connectivity: None,
         }
         .into_pyobject(py)?
         .into_any()
@@ -212,7 +214,7 @@ pub fn digraph_node_link_json(
     node_attrs: Option<PyObject>,
     edge_attrs: Option<PyObject>,
 ) -> PyResult<Option<String>> {
-    node_link_data::node_link_data(
+    node_link_data::node_link_data::<_, node_link_data::StringData>(
         py,
         &graph.graph,
         graph.multigraph,
@@ -221,6 +223,7 @@ pub fn digraph_node_link_json(
         graph_attrs,
         node_attrs,
         edge_attrs,
+        false,
     )
 }
 
@@ -260,7 +263,7 @@ pub fn graph_node_link_json(
     node_attrs: Option<PyObject>,
     edge_attrs: Option<PyObject>,
 ) -> PyResult<Option<String>> {
-    node_link_data::node_link_data(
+    node_link_data::node_link_data::<_, node_link_data::StringData>(
         py,
         &graph.graph,
         graph.multigraph,
@@ -269,5 +272,71 @@ pub fn graph_node_link_json(
         graph_attrs,
         node_attrs,
         edge_attrs,
+        false,
+    )
+}
+
+/// Parse node-link JSON, given either as a JSON str or as the path of a JSON
+/// file, into a :class:`~.PyGraph` preserving the node and edge indices.
+pub fn graph_from_node_link_json_with_indices(
+    py: Python,
+    path_or_str: &str,
+) -> PyResult<graph::PyGraph> {
+    let parsed = if path_or_str.trim_start().starts_with('{') {
+        serde_json::from_str(path_or_str)
+    } else {
+        let file = File::open(path_or_str)?;
+        serde_json::from_reader(BufReader::new(file))
+    };
+    let mut input: node_link_data::GraphInput<node_link_data::JsonPayload> = match parsed {
+        Ok(v) => v,
+        Err(e) => {
+            return Err(JSONDeserializationError::new_err(format!(
+                "JSON Deserialization Error {e}"
+            )));
+        }
+    };
+    if input.directed {
+        return Err(JSONDeserializationError::new_err(
+            "Cannot read a directed node-link graph into a PyGraph",
+        ));
+    }
+    let attrs = match input.attrs.take() {
+        Some(attrs) => attrs.into_py_object(py)?,
+        None => py.None(),
+    };
+    let multigraph = input.multigraph;
+    let mut inner_graph: StablePyGraph<Undirected> =
+        StablePyGraph::with_capacity(input.nodes.len(), input.links.len());
+    let node_removed =
+        node_link_data::parse_node_link_data_with_indices(py, input, &mut inner_graph)?;
+    Ok(graph::PyGraph {
+        graph: inner_graph,
+        node_removed,
+        multigraph,
+        attrs,
+        connectivity: None,
+    })
+}
+
+/// Generate node-link JSON for a :class:`~.PyGraph`, writing the payloads as
+/// JSON values when no callback is given for them.
+pub fn graph_node_link_json_with_payloads(
+    py: Python,
+    graph: &graph::PyGraph,
+    path: Option<String>,
+    node_attrs: Option<PyObject>,
+    edge_attrs: Option<PyObject>,
+) -> PyResult<Option<String>> {
+    node_link_data::node_link_data::<_, node_link_data::JsonPayload>(
+        py,
+        &graph.graph,
+        graph.multigraph,
+        &graph.attrs,
+        path,
+        None,
+        node_attrs,
+        edge_attrs,
+        true,
     )
 }
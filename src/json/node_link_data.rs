@@ -16,64 +16,174 @@ use std::fs::File;
 use hashbrown::HashMap;
 
 use serde::{Deserialize, Serialize};
+use serde_json::{Map, Number, Value};
 
+use pyo3::exceptions::PyTypeError;
 use pyo3::prelude::*;
+use pyo3::types::{PyBool, PyDict, PyFloat, PyInt, PyList, PyString};
 use pyo3::IntoPyObjectExt;
 use pyo3::Python;
 
+use petgraph::visit::EdgeIndexable;
 use petgraph::visit::EdgeRef;
 use petgraph::visit::IntoEdgeReferences;
+use petgraph::visit::NodeIndexable;
 use petgraph::EdgeType;
 
+use crate::JSONDeserializationError;
 use crate::JSONSerializationError;
 use crate::NodeIndex;
 use crate::StablePyGraph;
 
+/// The ``data`` of a node or link, which is a dictionary of strings unless
+/// the graph is written with its payloads as is
+pub type StringData = BTreeMap<String, String>;
+
 #[derive(Serialize)]
-pub struct Graph {
+pub struct Graph<D = StringData> {
     pub directed: bool,
     pub multigraph: bool,
-    pub attrs: Option<BTreeMap<String, String>>,
-    pub nodes: Vec<Node>,
-    pub links: Vec<Link>,
+    pub attrs: Option<D>,
+    pub nodes: Vec<Node<D>>,
+    pub links: Vec<Link<D>>,
 }
 
 #[derive(Deserialize)]
-pub struct GraphInput {
+pub struct GraphInput<D = StringData> {
     pub directed: bool,
     pub multigraph: bool,
-    pub attrs: Option<BTreeMap<String, String>>,
-    pub nodes: Vec<NodeInput>,
-    pub links: Vec<LinkInput>,
+    pub attrs: Option<D>,
+    pub nodes: Vec<NodeInput<D>>,
+    pub links: Vec<LinkInput<D>>,
 }
 
 #[derive(Serialize)]
-pub struct Node {
+pub struct Node<D = StringData> {
     id: usize,
-    data: Option<BTreeMap<String, String>>,
+    data: Option<D>,
 }
 
 #[derive(Deserialize)]
-pub struct NodeInput {
+pub struct NodeInput<D = StringData> {
     id: Option<usize>,
-    data: Option<BTreeMap<String, String>>,
+    data: Option<D>,
 }
 
 #[derive(Deserialize)]
-pub struct LinkInput {
+pub struct LinkInput<D = StringData> {
     source: usize,
     target: usize,
-    #[allow(dead_code)]
     id: Option<usize>,
-    data: Option<BTreeMap<String, String>>,
+    data: Option<D>,
 }
 
 #[derive(Serialize)]
-pub struct Link {
+pub struct Link<D = StringData> {
     source: usize,
     target: usize,
     id: usize,
-    data: Option<BTreeMap<String, String>>,
+    data: Option<D>,
+}
+
+/// A payload written to node-link JSON as is. Only Python objects that
+/// convert back to an equal object of the same type when the JSON is read are
+/// accepted, so writing and reading a graph never loses data.
+#[derive(Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct JsonPayload(Value);
+
+impl<'py> FromPyObject<'py> for JsonPayload {
+    fn extract_bound(ob: &Bound<'py, PyAny>) -> PyResult<Self> {
+        let value = if ob.is_none() {
+            Value::Null
+        } else if ob.is_exact_instance_of::<PyBool>() {
+            Value::Bool(ob.extract()?)
+        } else if ob.is_exact_instance_of::<PyInt>() {
+            if let Ok(value) = ob.extract::<i64>() {
+                Value::from(value)
+            } else if let Ok(value) = ob.extract::<u64>() {
+                Value::from(value)
+            } else {
+                return Err(PyTypeError::new_err(format!(
+                    "The integer {ob} is too large to be written to JSON"
+                )));
+            }
+        } else if ob.is_exact_instance_of::<PyFloat>() {
+            let value: f64 = ob.extract()?;
+            match Number::from_f64(value) {
+                Some(number) => Value::Number(number),
+                None => {
+                    return Err(PyTypeError::new_err(format!(
+                        "The float {value} can not be written to JSON"
+                    )))
+                }
+            }
+        } else if ob.is_exact_instance_of::<PyString>() {
+            Value::String(ob.extract()?)
+        } else if let Ok(list) = ob.downcast_exact::<PyList>() {
+            Value::Array(
+                list.iter()
+                    .map(|item| Ok(item.extract::<JsonPayload>()?.0))
+                    .collect::<PyResult<_>>()?,
+            )
+        } else if let Ok(dict) = ob.downcast_exact::<PyDict>() {
+            let mut map = Map::with_capacity(dict.len());
+            for (key, value) in dict.iter() {
+                if !key.is_exact_instance_of::<PyString>() {
+                    return Err(PyTypeError::new_err(format!(
+                        "The dictionary key {} is not a string and can not be written to JSON",
+                        key.repr()?
+                    )));
+                }
+                map.insert(key.extract()?, value.extract::<JsonPayload>()?.0);
+            }
+            Value::Object(map)
+        } else {
+            return Err(PyTypeError::new_err(format!(
+                "Objects of type {} can not be written to JSON, use a callback to convert them",
+                ob.get_type().name()?
+            )));
+        };
+        Ok(JsonPayload(value))
+    }
+}
+
+impl JsonPayload {
+    /// Convert the payload back into the Python object it was written from.
+    pub fn into_py_object(self, py: Python) -> PyResult<PyObject> {
+        json_to_py(py, self.0)
+    }
+}
+
+fn json_to_py(py: Python, value: Value) -> PyResult<PyObject> {
+    match value {
+        Value::Null => Ok(py.None()),
+        Value::Bool(value) => value.into_py_any(py),
+        Value::Number(number) => {
+            if let Some(value) = number.as_i64() {
+                value.into_py_any(py)
+            } else if let Some(value) = number.as_u64() {
+                value.into_py_any(py)
+            } else {
+                number.as_f64().into_py_any(py)
+            }
+        }
+        Value::String(value) => value.into_py_any(py),
+        Value::Array(values) => {
+            let items = values
+                .into_iter()
+                .map(|value| json_to_py(py, value))
+                .collect::<PyResult<Vec<_>>>()?;
+            PyList::new(py, items)?.into_py_any(py)
+        }
+        Value::Object(map) => {
+            let dict = PyDict::new(py);
+            for (key, value) in map {
+                dict.set_item(key, json_to_py(py, value)?)?;
+            }
+            dict.into_py_any(py)
+        }
+    }
 }
 
 #[allow(clippy::too_many_arguments)]
@@ -112,8 +222,92 @@ pub fn parse_node_link_data<Ty: EdgeType>(
     Ok(())
 }
 
+/// Build a graph from node-link data, placing each node and link at the index
+/// given by its ``id`` so that holes in the index space are recreated. Nodes
+/// and links without an ``id`` are added after the ones with an ``id``.
+///
+/// Returns whether the output graph has holes in its node indices.
+pub fn parse_node_link_data_with_indices<Ty: EdgeType>(
+    py: Python,
+    graph: GraphInput<JsonPayload>,
+    out_graph: &mut StablePyGraph<Ty>,
+) -> PyResult<bool> {
+    let payload = |data: Option<JsonPayload>| -> PyResult<PyObject> {
+        match data {
+            Some(data) => data.into_py_object(py),
+            None => Ok(py.None()),
+        }
+    };
+
+    let (mut indexed_nodes, unindexed_nodes): (Vec<NodeInput<_>>, Vec<NodeInput<_>>) =
+        graph.nodes.into_iter().partition(|node| node.id.is_some());
+    indexed_nodes.sort_unstable_by_key(|node| node.id);
+    // Temporary nodes filling the holes in the index space
+    let mut tmp_nodes: Vec<NodeIndex> = Vec::new();
+    for node in indexed_nodes {
+        let index = node.id.unwrap();
+        if index < out_graph.node_bound() {
+            return Err(JSONDeserializationError::new_err(format!(
+                "Duplicate node id {index}"
+            )));
+        }
+        while index > out_graph.node_bound() {
+            tmp_nodes.push(out_graph.add_node(py.None()));
+        }
+        out_graph.add_node(payload(node.data)?);
+    }
+    let node_removed = !tmp_nodes.is_empty();
+    for tmp_node in tmp_nodes {
+        out_graph.remove_node(tmp_node);
+    }
+    for node in unindexed_nodes {
+        out_graph.add_node(payload(node.data)?);
+    }
+
+    let endpoint = |index: usize| -> PyResult<NodeIndex> {
+        let node = NodeIndex::new(index);
+        if out_graph.contains_node(node) {
+            Ok(node)
+        } else {
+            Err(JSONDeserializationError::new_err(format!(
+                "Link endpoint {index} is not a node id"
+            )))
+        }
+    };
+    let mut links: Vec<(Option<usize>, NodeIndex, NodeIndex, PyObject)> =
+        Vec::with_capacity(graph.links.len());
+    for link in graph.links {
+        links.push((
+            link.id,
+            endpoint(link.source)?,
+            endpoint(link.target)?,
+            payload(link.data)?,
+        ));
+    }
+    // Links without an id sort first, so move them to the end
+    links.sort_by_key(|link| (link.0.is_none(), link.0));
+    // Temporary edges on a temporary node fill the holes in the index space
+    // and are all removed at once with the node.
+    let tmp_node = out_graph.add_node(py.None());
+    for (id, source, target, weight) in links {
+        if let Some(index) = id {
+            if index < out_graph.edge_bound() {
+                return Err(JSONDeserializationError::new_err(format!(
+                    "Duplicate link id {index}"
+                )));
+            }
+            while index > out_graph.edge_bound() {
+                out_graph.add_edge(tmp_node, tmp_node, py.None());
+            }
+        }
+        out_graph.add_edge(source, target, weight);
+    }
+    out_graph.remove_node(tmp_node);
+    Ok(node_removed)
+}
+
 #[allow(clippy::too_many_arguments)]
-pub fn node_link_data<Ty: EdgeType>(
+pub fn node_link_data<Ty: EdgeType, D: Serialize + for<'py> FromPyObject<'py>>(
     py: Python,
     graph: &StablePyGraph<Ty>,
     multigraph: bool,
@@ -122,27 +316,37 @@ pub fn node_link_data<Ty: EdgeType>(
     graph_attrs: Option<PyObject>,
     node_attrs: Option<PyObject>,
     edge_attrs: Option<PyObject>,
+    payload_as_data: bool,
 ) -> PyResult<Option<String>> {
-    let attr_callable = |attrs: &PyObject, obj: &PyObject| -> PyResult<BTreeMap<String, String>> {
+    let attr_callable = |attrs: &PyObject, obj: &PyObject| -> PyResult<D> {
         let res = attrs.call1(py, (obj,))?;
         res.extract(py)
     };
-    let mut nodes: Vec<Node> = Vec::with_capacity(graph.node_count());
+    // Without a callback, payloads are written as is if ``payload_as_data``
+    // is set.
+    let default_data = |obj: &PyObject| -> PyResult<Option<D>> {
+        if payload_as_data && !obj.is_none(py) {
+            obj.extract(py).map(Some)
+        } else {
+            Ok(None)
+        }
+    };
+    let mut nodes: Vec<Node<D>> = Vec::with_capacity(graph.node_count());
     for n in graph.node_indices() {
         let data = match node_attrs {
             Some(ref callback) => Some(attr_callable(callback, &graph[n])?),
-            None => None,
+            None => default_data(&graph[n])?,
         };
         nodes.push(Node {
             id: n.index(),
             data,
         });
     }
-    let mut links: Vec<Link> = Vec::with_capacity(graph.edge_count());
+    let mut links: Vec<Link<D>> = Vec::with_capacity(graph.edge_count());
     for e in graph.edge_references() {
         let data = match edge_attrs {
             Some(ref callback) => Some(attr_callable(callback, e.weight())?),
-            None => None,
+            None => default_data(e.weight())?,
         };
         links.push(Link {
             source: e.source().index(),
@@ -154,7 +358,7 @@ pub fn node_link_data<Ty: EdgeType>(
 
     let graph_attrs = match graph_attrs {
         Some(ref callback) => Some(attr_callable(callback, attrs)?),
-        None => None,
+        None => default_data(attrs)?,
     };
 
     let output_struct = Graph {
@@ -20,6 +20,7 @@ mod digraph;
 mod dominance;
 mod dot_utils;
 mod generators;
+mod gexf;
 mod graph;
 mod graphml;
 mod isomorphism;
@@ -29,6 +30,7 @@ mod layout;
 mod line_graph;
 mod link_analysis;
 mod matching;
+mod pajek;
 mod planar;
 mod random_graph;
 mod score;
@@ -192,6 +194,13 @@ impl IsNan for Complex64 {
         self.re.is_nan() || self.im.is_nan()
     }
 }
+
+impl IsNan for i64 {
+    #[inline]
+    fn is_nan(&self) -> bool {
+        false
+    }
+}
 pub type StablePyGraph<Ty> = StableGraph<PyObject, PyObject, Ty>;
 
 pub trait NodesRemoved {
@@ -351,6 +360,12 @@ impl CostFn {
     }
 }
 
+fn weight_equals(py: Python, obj: &PyObject, weight: &PyObject) -> PyResult<bool> {
+    obj.bind(py)
+        .rich_compare(weight, pyo3::basic::CompareOp::Eq)?
+        .is_truthy()
+}
+
 fn find_node_by_weight<Ty: EdgeType>(
     py: Python,
     graph: &StablePyGraph<Ty>,
@@ -359,11 +374,7 @@ fn find_node_by_weight<Ty: EdgeType>(
     let mut index = None;
     for node in graph.node_indices() {
         let weight = graph.node_weight(node).unwrap();
-        if obj
-            .bind(py)
-            .rich_compare(weight, pyo3::basic::CompareOp::Eq)?
-            .is_truthy()?
-        {
+        if weight_equals(py, obj, weight)? {
             index = Some(node);
             break;
         }
@@ -371,6 +382,21 @@ fn find_node_by_weight<Ty: EdgeType>(
     Ok(index)
 }
 
+fn find_nodes_by_weight<Ty: EdgeType>(
+    py: Python,
+    graph: &StablePyGraph<Ty>,
+    obj: &PyObject,
+) -> PyResult<Vec<NodeIndex>> {
+    let mut indices = Vec::new();
+    for node in graph.node_indices() {
+        let weight = graph.node_weight(node).unwrap();
+        if weight_equals(py, obj, weight)? {
+            indices.push(node);
+        }
+    }
+    Ok(indices)
+}
+
 create_exception!(
     rustworkx,
     InvalidNode,
@@ -689,6 +715,8 @@ fn rustworkx(py: Python<'_>, m: &Bound<PyModule>) -> PyResult<()> {
     m.add_class::<iterators::Chains>()?;
     m.add_class::<iterators::NodeIndices>()?;
     m.add_class::<iterators::EdgeIndices>()?;
+    m.add_class::<iterators::LazyNodeIndices>()?;
+    m.add_class::<iterators::LazyEdgeIndices>()?;
     m.add_class::<iterators::EdgeList>()?;
     m.add_class::<iterators::EdgeIndexMap>()?;
     m.add_class::<iterators::WeightedEdgeList>()?;
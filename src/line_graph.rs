@@ -73,6 +73,7 @@ pub fn graph_line_graph(
         node_removed: false,
         multigraph: false,
         attrs: py.None(),
+        connectivity: None,
     };
 
     let mut output_edge_to_node_map_py: DictMap<usize, usize> = DictMap::new();
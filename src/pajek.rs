@@ -0,0 +1,204 @@
+// Licensed under the Apache License, Version 2.0 (the "License"); you may
+// not use this file except in compliance with the License. You may obtain
+// a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+
+use std::fs::File;
+use std::io::{BufRead, BufReader, BufWriter, Write};
+
+use hashbrown::HashMap;
+
+use petgraph::graph::NodeIndex;
+use petgraph::visit::{EdgeRef, IntoEdgeReferences, IntoNodeReferences};
+use petgraph::Undirected;
+
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use pyo3::IntoPyObjectExt;
+
+use crate::graph::{check_header_node_count, PyGraph};
+use crate::StablePyGraph;
+
+/// The section of a Pajek file the current line belongs to
+enum Section {
+    Header,
+    Vertices,
+    Edges,
+}
+
+/// Split a line of a Pajek file into its fields, treating a double quoted
+/// string as a single field.
+fn split_fields(line: &str, line_number: usize) -> PyResult<Vec<&str>> {
+    let mut fields = Vec::new();
+    let mut rest = line.trim_start();
+    while !rest.is_empty() {
+        if let Some(quoted) = rest.strip_prefix('"') {
+            let end = quoted.find('"').ok_or_else(|| {
+                PyValueError::new_err(format!(
+                    "Unterminated quoted label on line {line_number} of the Pajek file"
+                ))
+            })?;
+            fields.push(&quoted[..end]);
+            rest = quoted[end + 1..].trim_start();
+        } else {
+            let end = rest.find(char::is_whitespace).unwrap_or(rest.len());
+            fields.push(&rest[..end]);
+            rest = rest[end..].trim_start();
+        }
+    }
+    Ok(fields)
+}
+
+/// Parse a 1-based Pajek vertex number into a node index of a graph with
+/// ``num_nodes`` nodes.
+fn parse_vertex(field: &str, num_nodes: usize, line_number: usize) -> PyResult<NodeIndex> {
+    match field.parse::<usize>() {
+        Ok(vertex) if vertex >= 1 && vertex <= num_nodes => Ok(NodeIndex::new(vertex - 1)),
+        _ => Err(PyValueError::new_err(format!(
+            "Invalid vertex {field:?} on line {line_number} of the Pajek file"
+        ))),
+    }
+}
+
+/// Read the ``*Vertices`` and ``*Edges``/``*Arcs`` sections of a Pajek file
+/// into a :class:`~rustworkx.PyGraph`.
+pub fn read_pajek_to_pygraph(py: Python<'_>, path: &str) -> PyResult<PyGraph> {
+    let file = File::open(path)?;
+    let mut out_graph = StablePyGraph::<Undirected>::default();
+    let mut section = Section::Header;
+    for (line_index, line) in BufReader::new(file).lines().enumerate() {
+        let line_number = line_index + 1;
+        let line = line?;
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('%') {
+            continue;
+        }
+        if line.starts_with('*') {
+            let mut fields = line.split_whitespace();
+            let keyword = fields.next().unwrap_or_default().to_lowercase();
+            section = match keyword.as_str() {
+                "*network" => Section::Header,
+                "*vertices" => {
+                    if out_graph.node_count() > 0 {
+                        return Err(PyValueError::new_err(format!(
+                            "Duplicate *Vertices section on line {line_number} of the Pajek file"
+                        )));
+                    }
+                    let num_nodes = fields
+                        .next()
+                        .and_then(|field| field.parse::<usize>().ok())
+                        .ok_or_else(|| {
+                            PyValueError::new_err(format!(
+                                "Missing number of vertices on line {line_number} of the Pajek file"
+                            ))
+                        })?;
+                    check_header_node_count(num_nodes, "Pajek", line_number)?;
+                    // Vertices without a line of their own are labeled by
+                    // their number
+                    out_graph.reserve_nodes(num_nodes);
+                    for vertex in 1..=num_nodes {
+                        out_graph.add_node(vertex.to_string().into_py_any(py)?);
+                    }
+                    Section::Vertices
+                }
+                "*edges" | "*arcs" => Section::Edges,
+                _ => {
+                    return Err(PyValueError::new_err(format!(
+                        "Unsupported section {keyword:?} on line {line_number} of the Pajek file"
+                    )))
+                }
+            };
+            continue;
+        }
+        let num_nodes = out_graph.node_count();
+        match section {
+            Section::Header => {
+                return Err(PyValueError::new_err(format!(
+                    "Line {line_number} of the Pajek file is not in a *Vertices, *Edges or *Arcs section"
+                )))
+            }
+            Section::Vertices => {
+                let fields = split_fields(line, line_number)?;
+                let node = parse_vertex(fields[0], num_nodes, line_number)?;
+                if let Some(label) = fields.get(1) {
+                    out_graph[node] = label.into_py_any(py)?;
+                }
+            }
+            Section::Edges => {
+                let fields = split_fields(line, line_number)?;
+                if fields.len() < 2 {
+                    return Err(PyValueError::new_err(format!(
+                        "Line {line_number} of the Pajek file has fewer than 2 fields: {line:?}"
+                    )));
+                }
+                let source = parse_vertex(fields[0], num_nodes, line_number)?;
+                let target = parse_vertex(fields[1], num_nodes, line_number)?;
+                let weight = match fields.get(2) {
+                    Some(field) => field
+                        .parse::<f64>()
+                        .map_err(|err| {
+                            PyValueError::new_err(format!(
+                                "Invalid weight {field:?} on line {line_number} of the Pajek file: {err}"
+                            ))
+                        })?
+                        .into_py_any(py)?,
+                    None => py.None(),
+                };
+                out_graph.add_edge(source, target, weight);
+            }
+        }
+    }
+    Ok(PyGraph {
+        graph: out_graph,
+        node_removed: false,
+        multigraph: true,
+        attrs: py.None(),
+        connectivity: None,
+    })
+}
+
+/// Write a :class:`~rustworkx.PyGraph` as a Pajek file, numbering the
+/// vertices ``1..=n`` in node index order.
+pub fn write_pygraph_pajek(
+    py: Python<'_>,
+    graph: &PyGraph,
+    path: &str,
+    label_fn: Option<PyObject>,
+) -> PyResult<()> {
+    let file = File::create(path)?;
+    let mut buf_writer = BufWriter::new(file);
+    writeln!(buf_writer, "*Vertices {}", graph.graph.node_count())?;
+    let mut vertices: HashMap<NodeIndex, usize> = HashMap::with_capacity(graph.graph.node_count());
+    for (vertex, (index, weight)) in (1..).zip(graph.graph.node_references()) {
+        let label: String = match &label_fn {
+            Some(label_fn) => label_fn.call1(py, (weight,))?.extract(py)?,
+            None => weight.bind(py).str()?.to_string(),
+        };
+        if label.contains(['"', '\n', '\r']) {
+            return Err(PyValueError::new_err(format!(
+                "Label {label:?} of node {} can not be written to a Pajek file",
+                index.index()
+            )));
+        }
+        writeln!(buf_writer, "{vertex} \"{label}\"")?;
+        vertices.insert(index, vertex);
+    }
+    writeln!(buf_writer, "*Edges")?;
+    for edge in graph.graph.edge_references() {
+        let source = vertices[&edge.source()];
+        let target = vertices[&edge.target()];
+        match edge.weight().extract::<f64>(py) {
+            Ok(weight) => writeln!(buf_writer, "{source} {target} {weight:?}")?,
+            Err(_) => writeln!(buf_writer, "{source} {target}")?,
+        }
+    }
+    buf_writer.flush()?;
+    Ok(())
+}
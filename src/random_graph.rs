@@ -163,6 +163,7 @@ pub fn undirected_gnp_random_graph(
         node_removed: false,
         multigraph: true,
         attrs: py.None(),
+        connectivity: None,
     })
 }
 
@@ -273,6 +274,7 @@ pub fn undirected_gnm_random_graph(
         node_removed: false,
         multigraph: true,
         attrs: py.None(),
+        connectivity: None,
     })
 }
 
@@ -383,6 +385,7 @@ pub fn undirected_sbm_random_graph<'p>(
         node_removed: false,
         multigraph: false,
         attrs: py.None(),
+        connectivity: None,
     })
 }
 
@@ -490,6 +493,7 @@ pub fn random_geometric_graph(
         node_removed: false,
         multigraph: true,
         attrs: py.None(),
+        connectivity: None,
     };
     Ok(graph)
 }
@@ -549,6 +553,7 @@ pub fn hyperbolic_random_graph(
         node_removed: false,
         multigraph: false,
         attrs: py.None(),
+        connectivity: None,
     })
 }
 
@@ -609,6 +614,7 @@ pub fn barabasi_albert_graph(
         node_removed: false,
         multigraph: true,
         attrs: py.None(),
+        connectivity: None,
     })
 }
 
@@ -777,5 +783,6 @@ pub fn undirected_random_bipartite_graph(
         node_removed: false,
         multigraph: true,
         attrs: py.None(),
+        connectivity: None,
     })
 }
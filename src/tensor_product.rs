@@ -152,6 +152,7 @@ pub fn graph_tensor_product(
             multigraph: true,
             node_removed: false,
             attrs: py.None(),
+            connectivity: None,
         },
         out_node_map,
     ))
@@ -175,7 +175,7 @@ pub fn minimum_spanning_tree(
         .edges
         .iter()
     {
-        spanning_tree.add_edge(u, v, weight.clone_ref(py))?;
+        spanning_tree.add_edge_checked(u, v, weight.clone_ref(py))?;
     }
 
     Ok(spanning_tree)
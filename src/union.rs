@@ -140,6 +140,7 @@ pub fn graph_union(
         node_removed: first.node_removed,
         multigraph: true,
         attrs: py.None(),
+        connectivity: None,
     })
 }
 
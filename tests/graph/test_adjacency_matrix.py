@@ -15,6 +15,13 @@ import unittest
 import rustworkx
 import numpy as np
 
+try:
+    import scipy.sparse
+
+    HAS_SCIPY = True
+except ImportError:
+    HAS_SCIPY = False
+
 
 class TestGraphAdjacencyMatrix(unittest.TestCase):
     def test_single_neighbor(self):
@@ -190,6 +197,17 @@ class TestGraphAdjacencyMatrix(unittest.TestCase):
         expected_matrix = np.array([[0, 1, 0], [1, 0, 1], [0, 1, 0]], dtype=np.float64)
         self.assertTrue(np.array_equal(adj_matrix, expected_matrix))
 
+    def test_parallel_threshold(self):
+        graph = rustworkx.undirected_gnp_random_graph(50, 0.5, seed=42)
+        graph.add_edge(3, 3, None)
+        adjacency_matrix = rustworkx.graph_adjacency_matrix(graph)
+        serial_graph = rustworkx.PyGraph.from_adjacency_matrix(adjacency_matrix)
+        parallel_graph = rustworkx.PyGraph.from_adjacency_matrix(
+            adjacency_matrix, parallel_threshold=0
+        )
+        self.assertEqual(serial_graph.weighted_edge_list(), parallel_graph.weighted_edge_list())
+        self.assertEqual(serial_graph.nodes(), parallel_graph.nodes())
+
 
 class TestFromComplexAdjacencyMatrix(unittest.TestCase):
     def test_from_adjacency_matrix(self):
@@ -220,6 +238,11 @@ class TestFromComplexAdjacencyMatrix(unittest.TestCase):
         ]
         self.assertEqual(graph.weighted_edge_list(), expected)
 
+    def test_parallel_threshold(self):
+        input_matrix = np.array([[1j, 1, 0], [1, 0, 2], [0, 2, 0]], dtype=complex)
+        graph = rustworkx.PyGraph.from_complex_adjacency_matrix(input_matrix, parallel_threshold=0)
+        self.assertEqual([(0, 0, 1j), (0, 1, 1 + 0j), (1, 2, 2 + 0j)], graph.weighted_edge_list())
+
     def test_random_graph_complex_dtype(self):
         input_matrix = np.array([[0, 1, 0], [1, 0, 1], [0, 1, 0]], dtype=complex)
         graph = rustworkx.PyGraph.from_complex_adjacency_matrix(input_matrix)
@@ -311,3 +334,227 @@ class TestFromComplexAdjacencyMatrix(unittest.TestCase):
             rustworkx.graph_adjacency_matrix(
                 graph, weight_fn=lambda x: float(x), parallel_edge="error"
             )
+
+
+class TestFromIntAdjacencyMatrix(unittest.TestCase):
+    def test_from_int_adjacency_matrix(self):
+        input_array = np.array([[0, 4, 0], [4, 0, 7], [0, 7, 2]], dtype=np.int64)
+        graph = rustworkx.PyGraph.from_int_adjacency_matrix(input_array)
+        self.assertEqual([0, 1, 2], graph.nodes())
+        self.assertEqual([(0, 1, 4), (1, 2, 7), (2, 2, 2)], graph.weighted_edge_list())
+        for weight in graph.edges():
+            self.assertIsInstance(weight, int)
+
+    def test_null_value(self):
+        input_array = np.array([[-1, 0], [0, -1]], dtype=np.int64)
+        graph = rustworkx.PyGraph.from_int_adjacency_matrix(input_array, null_value=-1)
+        self.assertEqual([(0, 1, 0)], graph.weighted_edge_list())
+
+    def test_large_values(self):
+        value = 2**62 + 1
+        input_array = np.array([[0, value], [value, 0]], dtype=np.int64)
+        graph = rustworkx.PyGraph.from_int_adjacency_matrix(input_array)
+        self.assertEqual([(0, 1, value)], graph.weighted_edge_list())
+
+    def test_parallel_threshold(self):
+        input_matrix = np.array([[1, 1, 0], [1, 0, 2], [0, 2, 0]], dtype=np.int64)
+        graph = rustworkx.PyGraph.from_int_adjacency_matrix(input_matrix, parallel_threshold=0)
+        self.assertEqual([(0, 0, 1), (0, 1, 1), (1, 2, 2)], graph.weighted_edge_list())
+
+    def test_different_dtype(self):
+        input_matrix = np.array([[0, 1], [1, 0]], dtype=np.float64)
+        with self.assertRaises(TypeError):
+            rustworkx.PyGraph.from_int_adjacency_matrix(input_matrix)
+
+
+class TestGraphIncidenceMatrix(unittest.TestCase):
+    def test_path_graph(self):
+        graph = rustworkx.generators.path_graph(3)
+        res = graph.incidence_matrix()
+        self.assertIsInstance(res, np.ndarray)
+        np.testing.assert_array_equal(res, [[1.0, 0.0], [1.0, 1.0], [0.0, 1.0]])
+
+    def test_self_loop(self):
+        graph = rustworkx.PyGraph()
+        graph.add_nodes_from([0, 1])
+        graph.add_edge(0, 0, None)
+        graph.add_edge(0, 1, None)
+        res = graph.incidence_matrix()
+        np.testing.assert_array_equal(res, [[2.0, 1.0], [0.0, 1.0]])
+        self.assertEqual(res[0].sum(), graph.degree(0))
+
+    def test_parallel_edges(self):
+        graph = rustworkx.PyGraph()
+        graph.add_nodes_from([0, 1])
+        graph.add_edges_from_no_data([(0, 1), (1, 0)])
+        res = graph.incidence_matrix()
+        np.testing.assert_array_equal(res, [[1.0, 1.0], [1.0, 1.0]])
+
+    def test_holes(self):
+        graph = rustworkx.generators.path_graph(4)
+        graph.remove_edge_from_index(1)
+        graph.remove_node(3)
+        res = graph.incidence_matrix()
+        self.assertEqual(res.shape, (4, 3))
+        np.testing.assert_array_equal(
+            res, [[1.0, 0.0, 0.0], [1.0, 0.0, 0.0], [0.0, 0.0, 0.0], [0.0, 0.0, 0.0]]
+        )
+
+    def test_empty(self):
+        res = rustworkx.PyGraph().incidence_matrix()
+        self.assertEqual(res.shape, (0, 0))
+
+
+class TestGraphEdgeArrays(unittest.TestCase):
+    def test_edge_endpoints_array(self):
+        graph = rustworkx.generators.path_graph(4)
+        graph.add_edge(3, 3, None)
+        res = graph.edge_endpoints_array()
+        self.assertIsInstance(res, np.ndarray)
+        self.assertEqual(res.dtype, np.uint32)
+        np.testing.assert_array_equal(res, [[0, 1], [1, 2], [2, 3], [3, 3]])
+
+    def test_edge_weights_array(self):
+        graph = rustworkx.PyGraph()
+        graph.add_nodes_from([0, 1, 2])
+        graph.add_edges_from([(0, 1, 2), (1, 2, 3.5)])
+        res = graph.edge_weights_array(weight_fn=float)
+        self.assertIsInstance(res, np.ndarray)
+        self.assertEqual(res.dtype, np.float64)
+        np.testing.assert_array_equal(res, [2.0, 3.5])
+        np.testing.assert_array_equal(graph.edge_weights_array(), [1.0, 1.0])
+        np.testing.assert_array_equal(graph.edge_weights_array(default=4.0), [4.0, 4.0])
+
+    def test_holes(self):
+        graph = rustworkx.PyGraph()
+        graph.add_nodes_from([0, 1, 2, 3])
+        graph.add_edges_from([(0, 1, 1.0), (1, 2, 2.0), (2, 3, 3.0), (3, 0, 4.0)])
+        graph.remove_edge_from_index(1)
+        graph.remove_node(0)
+        self.assertEqual(graph.edge_indices(), [2])
+        np.testing.assert_array_equal(graph.edge_endpoints_array(), [[2, 3]])
+        np.testing.assert_array_equal(graph.edge_weights_array(weight_fn=float), [3.0])
+
+    def test_matches_weighted_edge_list(self):
+        graph = rustworkx.generators.complete_graph(5)
+        graph.remove_node(2)
+        for edge in graph.edge_indices():
+            graph.update_edge_by_index(edge, float(edge))
+        endpoints = graph.edge_endpoints_array()
+        weights = graph.edge_weights_array(weight_fn=lambda weight: weight)
+        self.assertEqual(
+            [(int(u), int(v), w) for (u, v), w in zip(endpoints, weights)],
+            list(graph.weighted_edge_list()),
+        )
+
+    def test_empty(self):
+        graph = rustworkx.PyGraph()
+        self.assertEqual(graph.edge_endpoints_array().shape, (0, 2))
+        self.assertEqual(graph.edge_weights_array().shape, (0,))
+
+    def test_invalid_weight(self):
+        graph = rustworkx.generators.path_graph(2)
+        with self.assertRaises(TypeError):
+            graph.edge_weights_array(weight_fn=lambda weight: weight)
+
+
+@unittest.skipUnless(HAS_SCIPY, "scipy is required for running these tests")
+class TestGraphFromScipySparseMatrix(unittest.TestCase):
+    def test_from_csr_matrix(self):
+        input_array = np.array(
+            [[0.0, 4.0, 0.0], [4.0, 0.0, 4.0], [0.0, 4.0, 0.0]],
+            dtype=np.float64,
+        )
+        graph = rustworkx.PyGraph.from_scipy_sparse_matrix(scipy.sparse.csr_matrix(input_array))
+        self.assertEqual(graph.nodes(), [0, 1, 2])
+        self.assertEqual(graph.weighted_edge_list(), [(0, 1, 4.0), (1, 2, 4.0)])
+        out_array = rustworkx.graph_adjacency_matrix(graph, lambda x: x)
+        self.assertTrue(np.array_equal(input_array, out_array))
+
+    def test_from_coo_matrix_self_loop(self):
+        input_array = np.array([[2.0, 1.0], [1.0, 0.0]], dtype=np.float64)
+        graph = rustworkx.PyGraph.from_scipy_sparse_matrix(scipy.sparse.coo_matrix(input_array))
+        self.assertEqual(graph.weighted_edge_list(), [(0, 0, 2.0), (0, 1, 1.0)])
+
+    def test_matches_dense(self):
+        graph = rustworkx.undirected_gnp_random_graph(50, 0.2, seed=42)
+        adjacency_matrix = rustworkx.graph_adjacency_matrix(graph)
+        sparse_graph = rustworkx.PyGraph.from_scipy_sparse_matrix(
+            scipy.sparse.csr_matrix(adjacency_matrix)
+        )
+        dense_graph = rustworkx.PyGraph.from_adjacency_matrix(adjacency_matrix)
+        self.assertEqual(sparse_graph.weighted_edge_list(), dense_graph.weighted_edge_list())
+
+    def test_explicit_null_value(self):
+        matrix = scipy.sparse.csr_matrix(
+            (np.array([1.0, 5.0]), (np.array([0, 1]), np.array([1, 2]))), shape=(3, 3)
+        )
+        graph = rustworkx.PyGraph.from_scipy_sparse_matrix(matrix, null_value=5.0)
+        self.assertEqual(graph.edge_list(), [(0, 1)])
+
+    def test_non_square(self):
+        matrix = scipy.sparse.csr_matrix(np.ones((2, 3), dtype=np.float64))
+        with self.assertRaises(ValueError):
+            rustworkx.PyGraph.from_scipy_sparse_matrix(matrix)
+
+
+class CSRMatrix:
+    """A minimal stand in for a scipy.sparse CSR matrix with arbitrary arrays."""
+
+    def __init__(self, shape, indptr, indices, data):
+        self.shape = shape
+        self.indptr = np.array(indptr, dtype=np.int32)
+        self.indices = np.array(indices, dtype=np.int32)
+        self.data = np.array(data, dtype=np.float64)
+
+    def tocsr(self):
+        return self
+
+
+class TestGraphFromMalformedSparseMatrix(unittest.TestCase):
+    def test_valid(self):
+        matrix = CSRMatrix((2, 2), [0, 1, 2], [1, 0], [3.0, 3.0])
+        graph = rustworkx.PyGraph.from_scipy_sparse_matrix(matrix)
+        self.assertEqual(graph.weighted_edge_list(), [(0, 1, 3.0)])
+
+    def test_int64_indices(self):
+        matrix = CSRMatrix((2, 2), [0, 1, 2], [1, 0], [3.0, 3.0])
+        matrix.indptr = matrix.indptr.astype(np.int64)
+        matrix.indices = matrix.indices.astype(np.int64)
+        graph = rustworkx.PyGraph.from_scipy_sparse_matrix(matrix)
+        self.assertEqual(graph.weighted_edge_list(), [(0, 1, 3.0)])
+
+    def test_short_indptr(self):
+        matrix = CSRMatrix((2, 2), [0, 1], [1], [3.0])
+        with self.assertRaises(ValueError):
+            rustworkx.PyGraph.from_scipy_sparse_matrix(matrix)
+
+    def test_negative_indptr(self):
+        matrix = CSRMatrix((2, 2), [-1, 1, 2], [1, 0], [3.0, 3.0])
+        with self.assertRaises(ValueError):
+            rustworkx.PyGraph.from_scipy_sparse_matrix(matrix)
+
+    def test_decreasing_indptr(self):
+        matrix = CSRMatrix((2, 2), [0, 2, 1], [1, 0], [3.0, 3.0])
+        with self.assertRaises(ValueError):
+            rustworkx.PyGraph.from_scipy_sparse_matrix(matrix)
+
+    def test_indptr_past_indices(self):
+        matrix = CSRMatrix((2, 2), [0, 1, 3], [1, 0], [3.0, 3.0])
+        with self.assertRaises(ValueError):
+            rustworkx.PyGraph.from_scipy_sparse_matrix(matrix)
+
+    def test_negative_index(self):
+        matrix = CSRMatrix((2, 2), [0, 1, 2], [-1, 0], [3.0, 3.0])
+        with self.assertRaises(ValueError):
+            rustworkx.PyGraph.from_scipy_sparse_matrix(matrix)
+
+    def test_index_out_of_range(self):
+        matrix = CSRMatrix((2, 2), [0, 1, 2], [2, 0], [3.0, 3.0])
+        with self.assertRaises(ValueError):
+            rustworkx.PyGraph.from_scipy_sparse_matrix(matrix)
+
+    def test_short_data(self):
+        matrix = CSRMatrix((2, 2), [0, 1, 2], [1, 0], [3.0])
+        with self.assertRaises(ValueError):
+            rustworkx.PyGraph.from_scipy_sparse_matrix(matrix)
@@ -0,0 +1,76 @@
+# Licensed under the Apache License, Version 2.0 (the "License"); you may
+# not use this file except in compliance with the License. You may obtain
+# a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+# WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+# License for the specific language governing permissions and limitations
+# under the License.
+
+import math
+import unittest
+
+import rustworkx
+
+
+def pearson_assortativity(graph):
+    """Pearson correlation of the endpoint degrees over both orientations
+    of every edge"""
+    xs = []
+    ys = []
+    for source, target in graph.edge_list():
+        degree_source = graph.degree(source)
+        degree_target = graph.degree(target)
+        xs.extend([degree_source, degree_target])
+        ys.extend([degree_target, degree_source])
+    mean_x = sum(xs) / len(xs)
+    mean_y = sum(ys) / len(ys)
+    cov = sum((x - mean_x) * (y - mean_y) for x, y in zip(xs, ys))
+    var_x = sum((x - mean_x) ** 2 for x in xs)
+    var_y = sum((y - mean_y) ** 2 for y in ys)
+    return cov / math.sqrt(var_x * var_y)
+
+
+class TestDegreeAssortativity(unittest.TestCase):
+    def test_star_graph(self):
+        graph = rustworkx.generators.star_graph(5)
+        self.assertAlmostEqual(-1.0, graph.degree_assortativity())
+
+    def test_path_graph(self):
+        graph = rustworkx.generators.path_graph(4)
+        self.assertAlmostEqual(-0.5, graph.degree_assortativity())
+
+    def test_matches_pearson_correlation(self):
+        graph = rustworkx.generators.path_graph(6)
+        graph.add_edges_from_no_data([(0, 2), (2, 4), (1, 5)])
+        graph.add_node(None)
+        graph.add_edge(6, 0, None)
+        self.assertAlmostEqual(pearson_assortativity(graph), graph.degree_assortativity())
+
+    def test_karate_club(self):
+        graph = rustworkx.generators.karate_club_graph()
+        # The value computed by networkx.degree_assortativity_coefficient
+        self.assertAlmostEqual(-0.47561309768461413, graph.degree_assortativity())
+
+    def test_assortative(self):
+        # Two stars joined at their centers and two triangles
+        graph = rustworkx.PyGraph()
+        graph.add_nodes_from(range(14))
+        graph.add_edges_from_no_data([(0, i) for i in range(1, 4)] + [(4, i) for i in range(5, 8)])
+        graph.add_edges_from_no_data([(0, 4), (8, 9), (9, 10), (10, 8), (11, 12), (12, 13)])
+        self.assertAlmostEqual(pearson_assortativity(graph), graph.degree_assortativity())
+
+    def test_removed_nodes(self):
+        graph = rustworkx.generators.star_graph(6)
+        graph.add_edges_from_no_data([(1, 2), (3, 4)])
+        graph.remove_node(5)
+        self.assertAlmostEqual(pearson_assortativity(graph), graph.degree_assortativity())
+
+    def test_undefined(self):
+        self.assertTrue(math.isnan(rustworkx.PyGraph().degree_assortativity()))
+        self.assertTrue(math.isnan(rustworkx.generators.path_graph(2).degree_assortativity()))
+        self.assertTrue(math.isnan(rustworkx.generators.cycle_graph(5).degree_assortativity()))
+        self.assertTrue(math.isnan(rustworkx.generators.complete_graph(4).degree_assortativity()))
@@ -87,3 +87,49 @@ class TestBipartite(unittest.TestCase):
                             2 * n + 1, 2 * k + 1
                         )
                         self.assertFalse(rustworkx.is_bipartite(graph))
+
+
+class TestBipartiteMethods(unittest.TestCase):
+    def test_is_bipartite(self):
+        graph = rustworkx.generators.heavy_square_graph(5)
+        self.assertTrue(graph.is_bipartite())
+
+    def test_not_bipartite(self):
+        graph = rustworkx.generators.complete_graph(5)
+        self.assertFalse(graph.is_bipartite())
+        self.assertIsNone(graph.bipartite_coloring())
+
+    def test_bipartite_coloring_with_isolates(self):
+        graph = rustworkx.generators.star_graph(5)
+        graph.add_nodes_from(range(3))
+        self.assertEqual(
+            graph.bipartite_coloring(), {0: 1, 1: 0, 2: 0, 3: 0, 4: 0, 5: 1, 6: 1, 7: 1}
+        )
+
+    def test_bipartite_coloring_disconnected(self):
+        graph = rustworkx.PyGraph()
+        graph.add_nodes_from(range(6))
+        graph.add_edges_from_no_data([(0, 1), (1, 2), (3, 4), (4, 5), (5, 3)])
+        self.assertFalse(graph.is_bipartite())
+        graph.remove_edge(5, 3)
+        coloring = graph.bipartite_coloring()
+        for source, target in graph.edge_list():
+            self.assertNotEqual(coloring[source], coloring[target])
+
+    def test_parallel_edges(self):
+        graph = rustworkx.PyGraph()
+        graph.add_nodes_from(range(2))
+        graph.add_edges_from_no_data([(0, 1), (1, 0), (0, 1)])
+        self.assertTrue(graph.is_bipartite())
+        self.assertEqual(graph.bipartite_coloring(), {0: 1, 1: 0})
+
+    def test_self_loop(self):
+        graph = rustworkx.generators.path_graph(2)
+        graph.add_edge(1, 1, None)
+        self.assertFalse(graph.is_bipartite())
+        self.assertIsNone(graph.bipartite_coloring())
+
+    def test_empty_graph(self):
+        graph = rustworkx.PyGraph()
+        self.assertTrue(graph.is_bipartite())
+        self.assertEqual(graph.bipartite_coloring(), {})
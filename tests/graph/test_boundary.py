@@ -0,0 +1,68 @@
+# Licensed under the Apache License, Version 2.0 (the "License"); you may
+# not use this file except in compliance with the License. You may obtain
+# a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+# WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+# License for the specific language governing permissions and limitations
+# under the License.
+
+import unittest
+
+import rustworkx
+
+
+class TestBoundary(unittest.TestCase):
+    def setUp(self):
+        # Two triangles joined by the edge 2 - 3
+        self.graph = rustworkx.PyGraph()
+        self.graph.add_nodes_from(range(6))
+        self.graph.add_edges_from_no_data([(0, 1), (1, 2), (2, 0), (2, 3), (3, 4), (4, 5), (5, 3)])
+
+    def test_edge_boundary(self):
+        res = self.graph.edge_boundary([0, 1, 2])
+        self.assertIsInstance(res, rustworkx.EdgeIndices)
+        self.assertEqual([3], res)
+        self.assertEqual([0, 2, 3], self.graph.edge_boundary([2, 1]))
+
+    def test_node_boundary(self):
+        res = self.graph.node_boundary([0, 1, 2])
+        self.assertIsInstance(res, rustworkx.NodeIndices)
+        self.assertEqual([3], res)
+        self.assertEqual([0, 1, 4, 5], self.graph.node_boundary([2, 3]))
+
+    def test_empty_set(self):
+        self.assertEqual([], self.graph.edge_boundary([]))
+        self.assertEqual([], self.graph.node_boundary([]))
+
+    def test_all_nodes(self):
+        nodes = self.graph.node_indices()
+        self.assertEqual([], self.graph.edge_boundary(nodes))
+        self.assertEqual([], self.graph.node_boundary(nodes))
+
+    def test_duplicate_and_missing_nodes(self):
+        self.assertEqual([3], self.graph.edge_boundary([0, 1, 2, 2, 42]))
+        self.assertEqual([3], self.graph.node_boundary([0, 1, 2, 2, 42]))
+
+    def test_parallel_edges_and_self_loops(self):
+        graph = rustworkx.PyGraph()
+        graph.add_nodes_from(range(3))
+        graph.add_edges_from_no_data([(0, 0), (0, 1), (1, 0), (1, 2)])
+        self.assertEqual([1, 2], graph.edge_boundary([0]))
+        self.assertEqual([1], graph.node_boundary([0]))
+        self.assertEqual([1, 2, 3], graph.edge_boundary([1]))
+        self.assertEqual([0, 2], graph.node_boundary([1]))
+
+    def test_removed_nodes(self):
+        self.graph.remove_node(0)
+        self.assertEqual([3], self.graph.edge_boundary([0, 1, 2]))
+        self.assertEqual([1, 3], self.graph.node_boundary([2]))
+
+    def test_large_set(self):
+        graph = rustworkx.generators.path_graph(100)
+        nodes = list(range(50))
+        self.assertEqual([49], graph.edge_boundary(nodes))
+        self.assertEqual([50], graph.node_boundary(nodes))
@@ -74,3 +74,49 @@ class TestClear(unittest.TestCase):
         self.assertEqual(graph.num_edges(), 2)
         self.assertEqual(graph.nodes(), ["a", "b", "c"])
         self.assertEqual(graph.edges(), [{"e1", 1}, {"e2", 2}])
+
+    def test_clear_edges_of_nodes(self):
+        graph = rustworkx.generators.complete_graph(4)
+        graph.add_edge(0, 0, None)
+        graph.clear_edges_of_nodes([0, 1, 42])
+        self.assertEqual(graph.num_nodes(), 4)
+        self.assertEqual(graph.edge_list(), [(0, 2), (0, 3), (1, 2), (1, 3), (2, 3)])
+
+    def test_clear_edges_of_nodes_one_endpoint(self):
+        graph = rustworkx.generators.complete_graph(4)
+        graph.clear_edges_of_nodes([0, 1], both_endpoints=False)
+        self.assertEqual(graph.num_nodes(), 4)
+        self.assertEqual(graph.edge_list(), [(2, 3)])
+
+    def test_clear_edges_of_nodes_empty(self):
+        graph = rustworkx.generators.path_graph(3)
+        graph.clear_edges_of_nodes([])
+        graph.clear_edges_of_nodes([], both_endpoints=False)
+        self.assertEqual(graph.edge_list(), [(0, 1), (1, 2)])
+
+    def test_clear_node_data(self):
+        graph = rustworkx.PyGraph(attrs="attrs")
+        graph.add_nodes_from(["a", "b", "c"])
+        graph.add_edges_from([(0, 1, "x"), (1, 2, "y")])
+        graph.remove_node(1)
+        graph.clear_node_data()
+        self.assertEqual([None, None], graph.nodes())
+        self.assertEqual([0, 2], graph.node_indices())
+        self.assertEqual([], graph.edges())
+        self.assertEqual("attrs", graph.attrs)
+
+    def test_clear_node_data_value(self):
+        graph = rustworkx.generators.path_graph(3)
+        graph.clear_node_data(value=0)
+        self.assertEqual([0, 0, 0], graph.nodes())
+        self.assertEqual([(0, 1), (1, 2)], graph.edge_list())
+
+    def test_clear_edge_data(self):
+        graph = rustworkx.PyGraph()
+        graph.add_nodes_from(["a", "b", "c"])
+        graph.add_edges_from([(0, 1, "x"), (1, 2, "y")])
+        graph.clear_edge_data()
+        self.assertEqual([(0, 1, None), (1, 2, None)], graph.weighted_edge_list())
+        self.assertEqual(["a", "b", "c"], graph.nodes())
+        graph.clear_edge_data(1.0)
+        self.assertEqual([1.0, 1.0], graph.edges())
@@ -81,3 +81,40 @@ class TestComplement(unittest.TestCase):
                 complement_graph,
             )
         )
+
+    def test_method_path_graph(self):
+        graph = rustworkx.generators.path_graph(4)
+        complement_graph = graph.complement()
+        self.assertEqual(graph.nodes(), complement_graph.nodes())
+        self.assertEqual(
+            [(0, 2, None), (0, 3, None), (1, 3, None)],
+            list(complement_graph.weighted_edge_list()),
+        )
+
+    def test_method_keeps_holes_and_payloads(self):
+        graph = rustworkx.PyGraph(multigraph=False, attrs="attrs")
+        payload = {"label": "c"}
+        graph.add_nodes_from(["a", "b", payload, "d"])
+        graph.add_edge(0, 2, None)
+        graph.remove_node(1)
+        complement_graph = graph.complement()
+        self.assertEqual([0, 2, 3], complement_graph.node_indices())
+        self.assertIs(payload, complement_graph[2])
+        self.assertEqual([(0, 3), (2, 3)], list(complement_graph.edge_list()))
+        self.assertFalse(complement_graph.multigraph)
+        self.assertEqual("attrs", complement_graph.attrs)
+        self.assertEqual([(0, 2)], list(graph.edge_list()))
+
+    def test_method_parallel_edges(self):
+        graph = rustworkx.PyGraph()
+        graph.add_nodes_from([0, 1])
+        graph.add_edges_from_no_data([(0, 1), (1, 0)])
+        with self.assertRaises(ValueError):
+            graph.complement()
+
+    def test_method_self_loops(self):
+        graph = rustworkx.PyGraph()
+        graph.add_node(0)
+        graph.add_edge(0, 0, None)
+        with self.assertRaises(ValueError):
+            graph.complement()
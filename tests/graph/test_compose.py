@@ -95,3 +95,46 @@ class TestCompose(unittest.TestCase):
         graph = rustworkx.PyGraph()
         with self.assertRaises(TypeError):
             graph.compose(digraph, {})
+
+    def test_compose_with_edge_map(self):
+        graph = rustworkx.PyGraph()
+        graph.add_nodes_from(["a", "b"])
+        graph.add_edge(0, 1, "a-b")
+        other = rustworkx.PyGraph()
+        other.add_nodes_from(["c", "d", "e"])
+        other.add_edges_from([(0, 1, "c-d"), (1, 2, "d-e"), (0, 2, "c-e")])
+        other.remove_edge_from_index(1)
+        node_map, edge_map, node_map_edges = graph.compose_with_edge_map(
+            other, {1: (0, "b-c")}, edge_map_func=str.upper
+        )
+        self.assertEqual({0: 2, 1: 3, 2: 4}, node_map)
+        self.assertEqual({0: 1, 2: 2}, edge_map)
+        self.assertEqual({1: 3}, node_map_edges)
+        for old_edge, new_edge in edge_map.items():
+            self.assertEqual(
+                other.get_edge_data_by_index(old_edge).upper(),
+                graph.get_edge_data_by_index(new_edge),
+            )
+        self.assertEqual((1, 2), graph.get_edge_endpoints_by_index(3))
+        self.assertEqual("b-c", graph.get_edge_data_by_index(3))
+
+    def test_compose_with_edge_map_matches_compose(self):
+        graph = rustworkx.generators.path_graph(3)
+        other = rustworkx.generators.cycle_graph(4)
+        expected = graph.copy()
+        expected_node_map = expected.compose(other, {0: (1, None)})
+        node_map, _, _ = graph.compose_with_edge_map(other, {0: (1, None)})
+        self.assertEqual(expected_node_map, node_map)
+        self.assertEqual(expected.weighted_edge_list(), graph.weighted_edge_list())
+
+    def test_compose_other_with_removed_nodes(self):
+        graph = rustworkx.PyGraph()
+        graph.add_node("a")
+        other = rustworkx.PyGraph()
+        other.add_nodes_from(["x", "y", "z"])
+        other.add_edges_from([(0, 1, "xy"), (1, 2, "yz"), (0, 2, "xz")])
+        other.remove_node(1)
+        node_map = graph.compose(other, {0: (2, "az")})
+        self.assertEqual({0: 1, 2: 2}, node_map)
+        self.assertEqual(["a", "x", "z"], graph.nodes())
+        self.assertEqual([(1, 2, "xz"), (0, 2, "az")], graph.weighted_edge_list())
@@ -58,6 +58,25 @@ class TestConnectedComponents(unittest.TestCase):
         with self.assertRaises(rustworkx.InvalidNode):
             rustworkx.node_connected_component(graph, 10)
 
+    def test_node_connected_component_method(self):
+        graph = rustworkx.PyGraph()
+        graph.extend_from_edge_list(
+            [(0, 1), (1, 2), (2, 3), (3, 0), (4, 5), (5, 6), (6, 7), (7, 4)]
+        )
+        graph.add_node(None)
+        self.assertEqual([4, 5, 6, 7], graph.node_connected_component(6))
+        self.assertEqual([0, 1, 2, 3], graph.node_connected_component(0))
+        self.assertEqual([8], graph.node_connected_component(8))
+
+    def test_node_connected_component_method_invalid_node(self):
+        graph = rustworkx.PyGraph()
+        graph.extend_from_edge_list([(0, 1), (1, 2)])
+        graph.remove_node(1)
+        with self.assertRaises(IndexError):
+            graph.node_connected_component(1)
+        with self.assertRaises(IndexError):
+            graph.node_connected_component(10)
+
     def test_is_connected_false(self):
         graph = rustworkx.PyGraph()
         graph.extend_from_edge_list(
@@ -86,3 +105,36 @@ class TestConnectedComponents(unittest.TestCase):
         graph = rustworkx.PyGraph()
         with self.assertRaises(rustworkx.NullGraph):
             rustworkx.is_connected(graph)
+
+    def test_is_connected_method(self):
+        graph = rustworkx.PyGraph()
+        graph.extend_from_edge_list([(0, 1), (1, 2), (3, 4)])
+        self.assertFalse(graph.is_connected())
+        graph.add_edge(2, 3, None)
+        self.assertTrue(graph.is_connected())
+        graph.add_node(None)
+        self.assertFalse(graph.is_connected())
+
+    def test_is_connected_method_null_graph(self):
+        graph = rustworkx.PyGraph()
+        with self.assertRaises(rustworkx.NullGraph):
+            graph.is_connected()
+
+    def test_number_connected_components_method(self):
+        graph = rustworkx.PyGraph()
+        self.assertEqual(0, graph.number_connected_components())
+        graph.extend_from_edge_list([(0, 1), (1, 2), (3, 4), (4, 3), (5, 5)])
+        graph.add_node(None)
+        self.assertEqual(4, graph.number_connected_components())
+
+    def test_methods_match_functions(self):
+        for seed in range(10):
+            graph = rustworkx.undirected_gnp_random_graph(30, 0.05, seed=seed)
+            graph.remove_nodes_from([seed, seed + 10])
+            graph.add_edges_from_no_data([(20, 21), (20, 21), (22, 22)])
+            with self.subTest(seed=seed):
+                self.assertEqual(
+                    rustworkx.number_connected_components(graph),
+                    graph.number_connected_components(),
+                )
+                self.assertEqual(rustworkx.is_connected(graph), graph.is_connected())
@@ -0,0 +1,82 @@
+# Licensed under the Apache License, Version 2.0 (the "License"); you may
+# not use this file except in compliance with the License. You may obtain
+# a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+# WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+# License for the specific language governing permissions and limitations
+# under the License.
+
+import copy
+import pickle
+import unittest
+
+import rustworkx
+
+
+class TestConnectivityTracking(unittest.TestCase):
+    def setUp(self):
+        self.graph = rustworkx.PyGraph()
+        self.graph.add_nodes_from(range(6))
+        self.graph.add_edges_from_no_data([(0, 1), (1, 2), (3, 4)])
+
+    def test_are_connected_without_tracking(self):
+        self.assertFalse(self.graph.connectivity_tracking)
+        self.assertTrue(self.graph.are_connected(0, 2))
+        self.assertTrue(self.graph.are_connected(4, 3))
+        self.assertFalse(self.graph.are_connected(0, 3))
+        self.assertTrue(self.graph.are_connected(5, 5))
+
+    def test_tracking_edge_additions(self):
+        self.graph.enable_connectivity_tracking()
+        self.assertTrue(self.graph.connectivity_tracking)
+        self.assertTrue(self.graph.are_connected(0, 2))
+        self.assertFalse(self.graph.are_connected(2, 3))
+        self.graph.add_edge(2, 3, None)
+        self.assertTrue(self.graph.are_connected(0, 4))
+        self.assertFalse(self.graph.are_connected(0, 5))
+        self.graph.add_edges_from([(5, 0, None)])
+        self.assertTrue(self.graph.are_connected(5, 4))
+        self.assertTrue(self.graph.connectivity_tracking)
+
+    def test_tracking_node_additions(self):
+        self.graph.enable_connectivity_tracking()
+        node = self.graph.add_node(6)
+        self.assertFalse(self.graph.are_connected(node, 0))
+        self.graph.extend_from_edge_list([(node, 0), (8, 9)])
+        self.assertTrue(self.graph.are_connected(node, 2))
+        self.assertTrue(self.graph.are_connected(8, 9))
+        self.assertFalse(self.graph.are_connected(7, 8))
+        new_node = self.graph.subdivide_edge(0, None)
+        self.assertTrue(self.graph.are_connected(new_node, 1))
+        self.assertTrue(self.graph.connectivity_tracking)
+
+    def test_removal_disables_tracking(self):
+        self.graph.enable_connectivity_tracking()
+        self.graph.remove_edge(1, 2)
+        self.assertFalse(self.graph.connectivity_tracking)
+        self.assertFalse(self.graph.are_connected(0, 2))
+        self.graph.enable_connectivity_tracking()
+        self.graph.remove_node(4)
+        self.assertFalse(self.graph.connectivity_tracking)
+        self.graph.enable_connectivity_tracking()
+        self.graph.remove_edge_from_index(42)
+        self.assertTrue(self.graph.connectivity_tracking)
+        self.graph.contract_nodes([0, 1], None)
+        self.assertFalse(self.graph.connectivity_tracking)
+
+    def test_copies_do_not_track(self):
+        self.graph.enable_connectivity_tracking()
+        self.assertFalse(self.graph.copy().connectivity_tracking)
+        self.assertFalse(copy.deepcopy(self.graph).connectivity_tracking)
+        self.assertFalse(pickle.loads(pickle.dumps(self.graph)).connectivity_tracking)
+
+    def test_invalid_node(self):
+        for tracking in (False, True):
+            if tracking:
+                self.graph.enable_connectivity_tracking()
+            with self.assertRaises(IndexError):
+                self.graph.are_connected(0, 42)
@@ -12,6 +12,8 @@
 
 import unittest
 
+import numpy as np
+
 import rustworkx
 
 
@@ -167,6 +169,37 @@ class TestContractNodes(unittest.TestCase):
             set(UndirectedEdge(e) for e in dag.weighted_edge_list()),
         )
 
+    def test_numpy_array(self):
+        for dtype in [np.int64, np.uint64]:
+            with self.subTest(dtype=dtype):
+                graph = rustworkx.generators.path_graph(5)
+                labels = np.array([0, 0, 1, 1, 1])
+                nodes = np.flatnonzero(labels == 1).astype(dtype)
+                node_m = graph.contract_nodes(nodes, "m")
+                self.assertEqual([0, 1, node_m], graph.node_indexes())
+                self.assertEqual(
+                    {UndirectedEdge((0, 1)), UndirectedEdge((1, node_m))},
+                    set(UndirectedEdge(e) for e in graph.edge_list()),
+                )
+
+    def test_numpy_array_matches_list(self):
+        graph = rustworkx.generators.cycle_graph(6)
+        other = graph.copy()
+        node_m = graph.contract_nodes(np.array([1, 2, 42], dtype=np.int64), "m")
+        other_m = other.contract_nodes([1, 2, 42], "m")
+        self.assertEqual(node_m, other_m)
+        self.assertEqual(graph.nodes(), other.nodes())
+        self.assertEqual(
+            set(UndirectedEdge(e) for e in graph.edge_list()),
+            set(UndirectedEdge(e) for e in other.edge_list()),
+        )
+
+    def test_numpy_array_negative_index(self):
+        graph = rustworkx.generators.path_graph(3)
+        with self.assertRaises(OverflowError):
+            graph.contract_nodes(np.array([0, -1], dtype=np.int64), "m")
+        self.assertEqual(3, len(graph))
+
 
 class TestContractNodesSimpleGraph(unittest.TestCase):
     def setUp(self):
@@ -240,3 +273,144 @@ class TestContractNodesSimpleGraph(unittest.TestCase):
         self.dag.contract_nodes(self.dag.node_indexes(), "m")
         self.assertEqual(set(self.dag.nodes()), {"m"})
         self.assertFalse(self.dag.edges())
+
+
+    def test_internal_edges_dropped_by_default(self):
+        graph = rustworkx.generators.cycle_graph(4)
+        node = graph.contract_nodes([0, 1, 2], "m")
+        self.assertEqual(
+            [UndirectedEdge((node, 3, None))] * 2,
+            [UndirectedEdge(e) for e in graph.weighted_edge_list()],
+        )
+
+    def test_keep_internal_edges_as_self_loops(self):
+        graph = rustworkx.PyGraph()
+        graph.add_nodes_from(["a", "b", "c", "d"])
+        graph.add_edges_from([(0, 1, 1), (1, 2, 2), (2, 2, 3), (2, 3, 4), (0, 1, 5)])
+        node = graph.contract_nodes([0, 1, 2, 7], "m", keep_internal_edges_as_self_loops=True)
+        self_loops = [weight for (a, b, weight) in graph.weighted_edge_list() if a == b == node]
+        self.assertEqual([1, 2, 3, 5], sorted(self_loops))
+        self.assertEqual(5, graph.num_edges())
+
+    def test_keep_internal_edges_as_self_loops_weight_combo_fn(self):
+        graph = rustworkx.PyGraph()
+        graph.add_nodes_from(["a", "b", "c", "d"])
+        graph.add_edges_from([(0, 1, 1), (1, 2, 2), (2, 2, 3), (2, 3, 4), (0, 3, 5)])
+        node = graph.contract_nodes(
+            [0, 1, 2],
+            "m",
+            weight_combo_fn=lambda a, b: a + b,
+            keep_internal_edges_as_self_loops=True,
+        )
+        self.assertEqual(
+            {UndirectedEdge((node, node, 6)), UndirectedEdge((node, 3, 9))},
+            {UndirectedEdge(e) for e in graph.weighted_edge_list()},
+        )
+        self.assertEqual(15, sum(graph.edges()))
+
+    def test_keep_internal_edges_as_self_loops_not_multigraph(self):
+        graph = rustworkx.PyGraph(multigraph=False)
+        graph.add_nodes_from(["a", "b", "c"])
+        graph.add_edges_from([(0, 1, 1), (1, 2, 2)])
+        node = graph.contract_nodes([0, 1, 2], "m", keep_internal_edges_as_self_loops=True)
+        self.assertEqual([(node, node, 1)], graph.weighted_edge_list())
+
+
+class TestContractEdge(unittest.TestCase):
+    def test_contract_edge(self):
+        graph = rustworkx.PyGraph()
+        graph.add_nodes_from(["a", "b", "c", "d"])
+        edge = graph.add_edge(1, 2, "bc")
+        graph.add_edges_from([(0, 1, "ab"), (2, 3, "cd")])
+        node = graph.contract_edge(edge, "m")
+        self.assertEqual(set(graph.nodes()), {"a", "m", "d"})
+        self.assertEqual(
+            {UndirectedEdge(e) for e in graph.weighted_edge_list()},
+            {UndirectedEdge((0, node, "ab")), UndirectedEdge((node, 3, "cd"))},
+        )
+
+    def test_contract_edge_drops_parallel_and_self_loops(self):
+        graph = rustworkx.PyGraph()
+        graph.add_nodes_from(["a", "b"])
+        edge = graph.add_edge(0, 1, 1)
+        graph.add_edges_from([(0, 1, 2), (0, 0, 3)])
+        node = graph.contract_edge(edge, "m")
+        self.assertEqual([node], graph.node_indices())
+        self.assertEqual([], graph.edge_list())
+
+    def test_contract_edge_keep_self_loops(self):
+        graph = rustworkx.PyGraph()
+        graph.add_nodes_from(["a", "b", "c"])
+        edge = graph.add_edge(0, 1, 1)
+        graph.add_edges_from([(0, 1, 2), (0, 0, 3), (1, 2, 4)])
+        node = graph.contract_edge(edge, "m", keep_self_loops=True)
+        self.assertEqual(
+            sorted(weight for (a, b, weight) in graph.weighted_edge_list() if a == b == node),
+            [1, 2, 3],
+        )
+        self.assertEqual(list(graph.edge_list()).count((node, node)), 3)
+
+    def test_contract_edge_keep_self_loops_weight_combo_fn(self):
+        graph = rustworkx.PyGraph()
+        graph.add_nodes_from(["a", "b"])
+        edge = graph.add_edge(0, 1, 1)
+        graph.add_edges_from([(0, 1, 2), (0, 0, 3)])
+        node = graph.contract_edge(
+            edge, "m", weight_combo_fn=lambda a, b: a + b, keep_self_loops=True
+        )
+        self.assertEqual([(node, node, 6)], graph.weighted_edge_list())
+
+    def test_contract_edge_weight_combo_fn(self):
+        graph = rustworkx.PyGraph(multigraph=False)
+        graph.add_nodes_from(["a", "b", "c"])
+        edge = graph.add_edge(0, 1, 1)
+        graph.add_edges_from([(0, 2, 2), (1, 2, 3)])
+        node = graph.contract_edge(edge, "m", weight_combo_fn=lambda a, b: a + b)
+        self.assertEqual(
+            [UndirectedEdge((node, 2, 5))], [UndirectedEdge(e) for e in graph.weighted_edge_list()]
+        )
+
+    def test_contract_edge_invalid_index(self):
+        graph = rustworkx.generators.path_graph(2)
+        with self.assertRaises(IndexError):
+            graph.contract_edge(5, "m")
+
+    def test_contract_nodes_with_map(self):
+        graph = rustworkx.PyGraph()
+        graph.add_nodes_from(["a", "b", "c", "d"])
+        graph.add_edges_from([(0, 1, 1), (1, 2, 2), (2, 3, 3)])
+        node, edge_map = graph.contract_nodes_with_map([1, 2], "m")
+        self.assertEqual(node, 4)
+        self.assertEqual(graph.node_indices(), [0, 3, 4])
+        self.assertEqual(len(edge_map), 2)
+        for new_edge, orig_edge in edge_map.items():
+            self.assertEqual(graph.get_edge_data_by_index(new_edge), orig_edge + 1)
+
+    def test_contract_nodes_with_map_merged(self):
+        graph = rustworkx.PyGraph()
+        graph.add_nodes_from(["a", "b", "c"])
+        graph.add_edges_from([(0, 1, 1), (0, 2, 2)])
+        node, edge_map = graph.contract_nodes_with_map([1, 2], "m", weight_combo_fn=max)
+        self.assertEqual(graph.weighted_edge_list(), [(0, node, 2)])
+        self.assertEqual(list(edge_map.values()), [[0, 1]])
+
+    def test_contract_nodes_with_map_multigraph_keeps_parallel(self):
+        graph = rustworkx.PyGraph()
+        graph.add_nodes_from(["a", "b", "c"])
+        graph.add_edges_from([(0, 1, 1), (0, 2, 2)])
+        _, edge_map = graph.contract_nodes_with_map([1, 2], "m")
+        self.assertEqual(sorted(edge_map.values()), [0, 1])
+        self.assertEqual(graph.num_edges(), 2)
+
+    def test_contract_nodes_with_map_combo_fn_error(self):
+        graph = rustworkx.PyGraph()
+        graph.add_nodes_from(["a", "b", "c"])
+        graph.add_edges_from([(0, 1, 1), (0, 2, "x")])
+
+        def combo(a, b):
+            return a + b
+
+        with self.assertRaises(TypeError):
+            graph.contract_nodes_with_map([1, 2], "m", weight_combo_fn=combo)
+        self.assertEqual(graph.node_indices(), [0, 1, 2])
+        self.assertEqual(graph.num_edges(), 2)
@@ -53,3 +53,73 @@ class TestCopy(unittest.TestCase):
         graph_b.get_edge_data(0, 1)["edge"] = 162
         self.assertEqual(graph_b[0]["a"], 42)
         self.assertEqual(graph_a.get_edge_data(0, 1), {"edge": 162})
+
+    def test_shallow_copy_shares_payloads(self):
+        graph_a = rustworkx.PyGraph(attrs={"name": "a"})
+        graph_a.add_nodes_from([{"label": "x"}, {"label": "y"}])
+        graph_a.add_edge(0, 1, {"weight": 1})
+        graph_b = graph_a.copy()
+        self.assertIs(graph_a[0], graph_b[0])
+        self.assertIs(graph_a.get_edge_data(0, 1), graph_b.get_edge_data(0, 1))
+        self.assertIs(graph_a.attrs, graph_b.attrs)
+
+    def test_deep_copy(self):
+        graph_a = rustworkx.PyGraph(attrs={"name": "a"})
+        node_a = graph_a.add_node({"label": "x"})
+        node_b = graph_a.add_node({"label": "y"})
+        node_c = graph_a.add_node({"label": "z"})
+        graph_a.add_edge(node_a, node_b, {"weight": 1})
+        graph_a.add_edge(node_b, node_c, {"weight": 2})
+        graph_a.remove_node(node_a)
+        graph_b = graph_a.copy(deep=True)
+        self.assertEqual(graph_a.nodes(), graph_b.nodes())
+        self.assertEqual(graph_a.node_indices(), graph_b.node_indices())
+        self.assertEqual(graph_a.weighted_edge_list(), graph_b.weighted_edge_list())
+        self.assertEqual(graph_a.attrs, graph_b.attrs)
+        graph_b[node_b]["label"] = "changed"
+        graph_b.get_edge_data(node_b, node_c)["weight"] = 42
+        graph_b.attrs["name"] = "b"
+        self.assertEqual(graph_a[node_b], {"label": "y"})
+        self.assertEqual(graph_a.get_edge_data(node_b, node_c), {"weight": 2})
+        self.assertEqual(graph_a.attrs, {"name": "a"})
+
+    def test_deep_copy_preserves_shared_payloads(self):
+        shared = ["shared"]
+        graph_a = rustworkx.PyGraph()
+        graph_a.add_nodes_from([shared, shared])
+        graph_a.add_edge(0, 1, shared)
+        graph_b = graph_a.copy(deep=True)
+        self.assertIsNot(graph_b[0], shared)
+        self.assertIs(graph_b[0], graph_b[1])
+        self.assertIs(graph_b[0], graph_b.get_edge_data(0, 1))
+
+    def test_copy_compact(self):
+        graph_a = rustworkx.PyGraph(attrs="attrs")
+        graph_a.add_nodes_from(["a", "b", "c", "d"])
+        graph_a.add_edges_from([(0, 1, "x"), (1, 2, "y"), (2, 3, "z")])
+        graph_a.remove_node(1)
+        graph_b = graph_a.copy(compact=True)
+        self.assertEqual([0, 1, 2], graph_b.node_indices())
+        self.assertEqual(["a", "c", "d"], graph_b.nodes())
+        self.assertEqual([(1, 2, "z")], graph_b.weighted_edge_list())
+        self.assertEqual([0], graph_b.edge_indices())
+        self.assertEqual("attrs", graph_b.attrs)
+        self.assertEqual([0, 2, 3], graph_a.node_indices())
+
+    def test_copy_default_keeps_holes(self):
+        graph_a = rustworkx.generators.path_graph(3)
+        graph_a.remove_node(1)
+        self.assertEqual([0, 2], graph_a.copy().node_indices())
+
+    def test_copy_with_nodemap(self):
+        graph_a = rustworkx.PyGraph()
+        graph_a.add_nodes_from([["a"], ["b"], ["c"]])
+        graph_a.add_edge(0, 2, None)
+        graph_a.remove_node(1)
+        graph_b, node_map = graph_a.copy_with_nodemap()
+        self.assertEqual({0: 0, 2: 1}, dict(node_map))
+        self.assertEqual([(0, 1)], graph_b.edge_list())
+        self.assertIs(graph_a[2], graph_b[1])
+        graph_c, _ = graph_a.copy_with_nodemap(deep=True)
+        self.assertEqual(["c"], graph_c[1])
+        self.assertIsNot(graph_a[2], graph_c[1])
@@ -94,3 +94,61 @@ class TestCoreNumber(unittest.TestCase):
         res = rustworkx.core_number(graph)
         self.assertIsInstance(res, dict)
         self.assertEqual(res, self.example_core)
+
+
+class TestCoreNumberMethods(unittest.TestCase):
+    def setUp(self):
+        # Reuse the example graph from the paper
+        TestCoreNumber.setUp(self)
+        self.graph = rustworkx.PyGraph()
+        self.graph.add_nodes_from(list(range(21)))
+        self.graph.add_edges_from_no_data(self.example_edges)
+
+    def test_core_number_empty(self):
+        graph = rustworkx.PyGraph()
+        self.assertEqual({}, graph.core_number())
+
+    def test_core_number_paper_example(self):
+        self.assertEqual(self.example_core, self.graph.core_number())
+        self.assertEqual(list(range(21)), list(self.graph.core_number()))
+
+    def test_core_number_removed_nodes(self):
+        self.graph.remove_nodes_from([3, 20])
+        res = self.graph.core_number()
+        self.assertEqual(rustworkx.core_number(self.graph), res)
+        self.assertNotIn(3, res)
+        self.assertEqual(2, res[0])
+
+    def test_core_number_random_graph(self):
+        graph = rustworkx.undirected_gnp_random_graph(100, 0.1, seed=42)
+        self.assertEqual(rustworkx.core_number(graph), graph.core_number())
+
+    def test_core_number_parallel_edges_and_self_loops(self):
+        graph = rustworkx.generators.path_graph(3)
+        graph.add_edges_from_no_data([(0, 1), (0, 1), (1, 1), (2, 2)])
+        self.assertEqual({0: 1, 1: 1, 2: 1}, graph.core_number())
+
+    def test_k_core(self):
+        subgraph, node_map = self.graph.k_core(2)
+        self.assertEqual(list(range(16)), list(node_map.values()))
+        expected, _ = self.graph.subgraph_with_nodemap(list(range(16)))
+        self.assertEqual(expected.edge_list(), subgraph.edge_list())
+
+    def test_k_core_zero(self):
+        subgraph, node_map = self.graph.k_core(0)
+        self.assertEqual(self.graph.node_indices(), list(node_map.values()))
+        self.assertEqual(self.graph.num_edges(), subgraph.num_edges())
+
+    def test_k_core_too_large(self):
+        subgraph, node_map = self.graph.k_core(4)
+        self.assertEqual(0, len(subgraph))
+        self.assertEqual({}, dict(node_map))
+
+    def test_k_core_keeps_parallel_edges(self):
+        graph = rustworkx.generators.complete_graph(3)
+        graph.add_edges_from_no_data([(0, 1), (2, 2)])
+        graph.add_node(None)
+        graph.add_edge(2, 3, None)
+        subgraph, node_map = graph.k_core(2)
+        self.assertEqual([0, 1, 2], list(node_map.values()))
+        self.assertEqual(5, subgraph.num_edges())
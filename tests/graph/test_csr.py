@@ -0,0 +1,71 @@
+# Licensed under the Apache License, Version 2.0 (the "License"); you may
+# not use this file except in compliance with the License. You may obtain
+# a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+# WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+# License for the specific language governing permissions and limitations
+# under the License.
+
+import unittest
+
+import numpy as np
+
+import rustworkx
+
+
+def csr_to_dense(indptr, indices, data, size):
+    matrix = np.zeros((size, size))
+    for row in range(size):
+        for position in range(indptr[row], indptr[row + 1]):
+            matrix[row, indices[position]] += data[position]
+    return matrix
+
+
+class TestToCSR(unittest.TestCase):
+    def test_path_graph(self):
+        graph = rustworkx.generators.path_graph(4)
+        indptr, indices, data, node_map = graph.to_csr()
+        np.testing.assert_array_equal(indptr, [0, 1, 3, 5, 6])
+        np.testing.assert_array_equal(indices, [1, 0, 2, 1, 3, 2])
+        np.testing.assert_array_equal(data, [1.0] * 6)
+        self.assertEqual(indptr.dtype, np.int64)
+        self.assertEqual(indices.dtype, np.int64)
+        self.assertEqual(data.dtype, np.float64)
+        self.assertEqual(dict(node_map), {0: 0, 1: 1, 2: 2, 3: 3})
+
+    def test_matches_adjacency_matrix(self):
+        graph = rustworkx.undirected_gnp_random_graph(30, 0.3, seed=1234)
+        for edge in graph.edge_indices():
+            graph.update_edge_by_index(edge, float(edge))
+        graph.add_edge(3, 3, 7.0)
+        graph.add_edge(0, 1, 2.5)
+        indptr, indices, data, _ = graph.to_csr(weight_fn=float)
+        np.testing.assert_array_equal(
+            csr_to_dense(indptr, indices, data, len(graph)),
+            rustworkx.graph_adjacency_matrix(graph, weight_fn=float),
+        )
+        for row in range(len(graph)):
+            row_indices = indices[indptr[row] : indptr[row + 1]]
+            self.assertEqual(list(row_indices), sorted(set(row_indices)))
+
+    def test_removed_nodes_are_compacted(self):
+        graph = rustworkx.PyGraph()
+        graph.add_nodes_from(["a", "b", "c", "d"])
+        graph.add_edges_from([(0, 3, 2.0), (3, 3, 1.0), (0, 2, 4.0)])
+        graph.remove_node(1)
+        indptr, indices, data, node_map = graph.to_csr(default_weight=0.5)
+        self.assertEqual(dict(node_map), {0: 0, 1: 2, 2: 3})
+        np.testing.assert_array_equal(indptr, [0, 2, 3, 5])
+        np.testing.assert_array_equal(indices, [1, 2, 0, 0, 2])
+        np.testing.assert_array_equal(data, [0.5] * 5)
+
+    def test_empty_graph(self):
+        indptr, indices, data, node_map = rustworkx.PyGraph().to_csr()
+        np.testing.assert_array_equal(indptr, [0])
+        self.assertEqual(len(indices), 0)
+        self.assertEqual(len(data), 0)
+        self.assertEqual(dict(node_map), {})
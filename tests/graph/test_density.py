@@ -0,0 +1,49 @@
+# Licensed under the Apache License, Version 2.0 (the "License"); you may
+# not use this file except in compliance with the License. You may obtain
+# a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+# WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+# License for the specific language governing permissions and limitations
+# under the License.
+
+
+import unittest
+
+import rustworkx
+
+
+class TestDensity(unittest.TestCase):
+    def test_complete_graph(self):
+        graph = rustworkx.generators.complete_graph(5)
+        self.assertEqual(graph.density(), 1.0)
+
+    def test_path_graph(self):
+        graph = rustworkx.generators.path_graph(4)
+        self.assertAlmostEqual(graph.density(), 0.5)
+
+    def test_no_edges(self):
+        graph = rustworkx.PyGraph()
+        graph.add_nodes_from(range(3))
+        self.assertEqual(graph.density(), 0.0)
+
+    def test_fewer_than_two_nodes(self):
+        graph = rustworkx.PyGraph()
+        self.assertEqual(graph.density(), 0.0)
+        graph.add_node(None)
+        graph.add_edge(0, 0, None)
+        self.assertEqual(graph.density(), 0.0)
+
+    def test_multigraph_counts_all_edges(self):
+        graph = rustworkx.PyGraph()
+        graph.add_nodes_from(range(2))
+        graph.add_edges_from_no_data([(0, 1), (0, 1), (1, 1)])
+        self.assertEqual(graph.density(), 3.0)
+
+    def test_removed_nodes(self):
+        graph = rustworkx.generators.complete_graph(4)
+        graph.remove_node(0)
+        self.assertEqual(graph.density(), 1.0)
@@ -0,0 +1,101 @@
+# Licensed under the Apache License, Version 2.0 (the "License"); you may
+# not use this file except in compliance with the License. You may obtain
+# a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+# WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+# License for the specific language governing permissions and limitations
+# under the License.
+
+import os
+import tempfile
+import unittest
+
+import rustworkx
+
+
+class TestReadDimacs(unittest.TestCase):
+    def setUp(self):
+        self.tempdir = tempfile.TemporaryDirectory()
+        self.path = os.path.join(self.tempdir.name, "graph.col")
+
+    def tearDown(self):
+        self.tempdir.cleanup()
+
+    def write(self, contents):
+        with open(self.path, "w") as fd:
+            fd.write(contents)
+
+    def test_read(self):
+        self.write("c A comment\nc\np edge 4 3\ne 1 2\n\ne 2 3\ne 4 1\n")
+        graph = rustworkx.PyGraph.read_dimacs(self.path)
+        self.assertEqual([None] * 4, graph.nodes())
+        self.assertEqual([(0, 1), (1, 2), (3, 0)], list(graph.edge_list()))
+        self.assertEqual([None] * 3, graph.edges())
+
+    def test_read_isolated_nodes(self):
+        self.write("p col 5 1\ne 5 5\n")
+        graph = rustworkx.PyGraph.read_dimacs(self.path)
+        self.assertEqual(5, len(graph))
+        self.assertEqual([(4, 4)], list(graph.edge_list()))
+
+    def test_read_weights(self):
+        self.write("p edge 3 2\ne 1 2 1.5\ne 2 3 4\n")
+        graph = rustworkx.PyGraph.read_dimacs(self.path)
+        self.assertEqual([(0, 1, 1.5), (1, 2, 4.0)], list(graph.weighted_edge_list()))
+
+    def test_read_parallel_edges(self):
+        self.write("p edge 2 2\ne 1 2\ne 2 1\n")
+        graph = rustworkx.PyGraph.read_dimacs(self.path)
+        self.assertEqual(2, graph.num_edges())
+
+    def test_read_shortest_path_arcs(self):
+        self.write("c 9th DIMACS challenge\np sp 3 3\na 1 2 7\na 2 1 7\na 2 3 2\n")
+        graph = rustworkx.PyGraph.read_dimacs(self.path)
+        self.assertEqual([(0, 1, 7.0), (1, 0, 7.0), (1, 2, 2.0)], list(graph.weighted_edge_list()))
+
+    def test_huge_problem_line(self):
+        self.write("p edge 99999999999 0\n")
+        with self.assertRaisesRegex(ValueError, "line 1"):
+            rustworkx.PyGraph.read_dimacs(self.path)
+        self.write("p edge 2 99999999999\ne 1 2\n")
+        graph = rustworkx.PyGraph.read_dimacs(self.path)
+        self.assertEqual([(0, 1)], list(graph.edge_list()))
+
+    def test_missing_problem_line(self):
+        self.write("c no problem line\n")
+        with self.assertRaisesRegex(ValueError, "problem line"):
+            rustworkx.PyGraph.read_dimacs(self.path)
+
+    def test_edge_before_problem_line(self):
+        self.write("e 1 2\np edge 2 1\n")
+        with self.assertRaisesRegex(ValueError, "line 1"):
+            rustworkx.PyGraph.read_dimacs(self.path)
+
+    def test_node_id_out_of_range(self):
+        for edge in ["e 0 1", "e 1 3"]:
+            with self.subTest(edge=edge):
+                self.write(f"p edge 2 1\n{edge}\n")
+                with self.assertRaisesRegex(ValueError, "line 2"):
+                    rustworkx.PyGraph.read_dimacs(self.path)
+
+    def test_invalid_lines(self):
+        for contents in [
+            "p edge 2\n",
+            "p edge two 1\n",
+            "p edge 2 1\np edge 2 1\n",
+            "p edge 2 1\ne 1\n",
+            "p edge 2 1\ne 1 2 heavy\n",
+            "p edge 2 1\nx 1 2\n",
+        ]:
+            with self.subTest(contents=contents):
+                self.write(contents)
+                with self.assertRaises(ValueError):
+                    rustworkx.PyGraph.read_dimacs(self.path)
+
+    def test_invalid_path(self):
+        with self.assertRaises(FileNotFoundError):
+            rustworkx.PyGraph.read_dimacs(os.path.join(self.tempdir.name, "missing.col"))
@@ -10,6 +10,8 @@
 # License for the specific language governing permissions and limitations
 # under the License.
 
+import gzip
+import io
 import os
 import tempfile
 import unittest
@@ -128,3 +130,110 @@ class TestDot(unittest.TestCase):
         graph = rustworkx.undirected_gnp_random_graph(3, 0.95, seed=24)
         dot_str = graph.to_dot()
         self.assertEqual("graph {\n0 ;\n1 ;\n2 ;\n2 -- 0 ;\n2 -- 1 ;\n}\n", dot_str)
+
+    def test_graph_use_attrs_as_graph_attr(self):
+        graph = rustworkx.PyGraph(attrs={"bgcolor": "red", "label": "graph"})
+        graph.add_nodes_from([0, 1])
+        graph.add_edge(0, 1, None)
+        dot_str = graph.to_dot(graph_attr={"label": "other"}, use_attrs_as_graph_attr=True)
+        self.assertEqual(
+            "graph {\nbgcolor=red ;\nlabel=other ;\n0 ;\n1 ;\n0 -- 1 ;\n}\n",
+            dot_str,
+        )
+
+    def test_graph_attrs_ignored_by_default(self):
+        graph = rustworkx.PyGraph(attrs={"bgcolor": "red"})
+        graph.add_node(0)
+        self.assertEqual("graph {\n0 ;\n}\n", graph.to_dot())
+
+    def test_graph_use_attrs_as_graph_attr_no_attrs(self):
+        graph = rustworkx.PyGraph()
+        graph.add_node(0)
+        dot_str = graph.to_dot(graph_attr={"bgcolor": "red"}, use_attrs_as_graph_attr=True)
+        self.assertEqual("graph {\nbgcolor=red ;\n0 ;\n}\n", dot_str)
+
+    def test_graph_use_attrs_as_graph_attr_invalid_attrs(self):
+        graph = rustworkx.PyGraph(attrs={"size": 1})
+        with self.assertRaises(TypeError):
+            graph.to_dot(use_attrs_as_graph_attr=True)
+
+    def test_graph_show_stats(self):
+        graph = rustworkx.generators.path_graph(3)
+        dot_str = graph.to_dot(graph_attr={"bgcolor": "red"}, show_stats=True)
+        self.assertEqual(
+            'graph {\nbgcolor=red ;\nlabel="|V|=3 |E|=2" ;\n0 ;\n1 ;\n2 ;\n0 -- 1 ;\n1 -- 2 ;\n}\n',
+            dot_str,
+        )
+
+    def test_graph_show_stats_user_label(self):
+        graph = rustworkx.generators.path_graph(2)
+        dot_str = graph.to_dot(graph_attr={"label": "mine"}, show_stats=True)
+        self.assertEqual("graph {\nlabel=mine ;\n0 ;\n1 ;\n0 -- 1 ;\n}\n", dot_str)
+
+    def test_graph_rank_groups(self):
+        graph = rustworkx.generators.path_graph(4)
+        dot_str = graph.to_dot(rank_groups=[[0, 2], [1, 3]])
+        self.assertEqual(
+            "graph {\n0 ;\n1 ;\n2 ;\n3 ;\n{rank=same; 0; 2;}\n{rank=same; 1; 3;}\n"
+            "0 -- 1 ;\n1 -- 2 ;\n2 -- 3 ;\n}\n",
+            dot_str,
+        )
+
+    def test_graph_rank_groups_invalid_node(self):
+        graph = rustworkx.generators.path_graph(2)
+        with self.assertRaises(IndexError):
+            graph.to_dot(rank_groups=[[0, 5]])
+
+    def test_graph_edge_ports(self):
+        graph = rustworkx.PyGraph()
+        graph.add_nodes_from([0, 1])
+        graph.add_edge(0, 1, {"tailport": "e", "headport": "w", "color": "red"})
+        graph.add_edge(0, 1, {"headport": "n"})
+        dot_str = graph.to_dot(edge_attr=lambda edge: edge)
+        self.assertEqual("graph {\n0 ;\n1 ;\n0:e -- 1:w [color=red];\n0 -- 1:n ;\n}\n", dot_str)
+
+    def test_graph_to_dot_file_object(self):
+        graph = rustworkx.generators.path_graph(3)
+        expected = graph.to_dot(node_attr=lambda _: {"label": "ü"})
+        buf = io.StringIO()
+        res = graph.to_dot(node_attr=lambda _: {"label": "ü"}, file=buf)
+        self.assertIsNone(res)
+        self.assertEqual(expected, buf.getvalue())
+
+    def test_graph_to_dot_file_object_chunks(self):
+        class ChunkWriter:
+            def __init__(self):
+                self.chunks = []
+
+            def write(self, data):
+                self.chunks.append(data)
+
+        graph = rustworkx.generators.path_graph(5000)
+        writer = ChunkWriter()
+        graph.to_dot(node_attr=lambda _: {"label": '"€"'}, file=writer)
+        self.assertGreater(len(writer.chunks), 1)
+        self.assertTrue(all(isinstance(chunk, str) for chunk in writer.chunks))
+        expected = graph.to_dot(node_attr=lambda _: {"label": '"€"'})
+        self.assertEqual(expected, "".join(writer.chunks))
+
+    def test_graph_to_dot_gzip_file_object(self):
+        graph = rustworkx.generators.path_graph(3)
+        with gzip.open(self.path, "wt") as fd:
+            graph.to_dot(file=fd)
+        self.addCleanup(os.remove, self.path)
+        with gzip.open(self.path, "rt") as fd:
+            self.assertEqual(graph.to_dot(), fd.read())
+
+    def test_graph_to_dot_file_object_error(self):
+        class FailingWriter:
+            def write(self, data):
+                raise RuntimeError("write failed")
+
+        graph = rustworkx.generators.path_graph(3)
+        with self.assertRaisesRegex(RuntimeError, "write failed"):
+            graph.to_dot(file=FailingWriter())
+
+    def test_graph_to_dot_filename_and_file(self):
+        graph = rustworkx.generators.path_graph(3)
+        with self.assertRaises(ValueError):
+            graph.to_dot(filename=self.path, file=io.StringIO())
@@ -10,6 +10,7 @@
 # License for the specific language governing permissions and limitations
 # under the License.
 
+import io
 import os
 import tempfile
 import unittest
@@ -143,6 +144,95 @@ class TestEdgeList(unittest.TestCase):
         self.assertTrue(graph.has_edge(0, 2))
         self.assertEqual(graph.edges(), ["0", "1", None])
 
+    def test_multigraph_default(self):
+        with tempfile.NamedTemporaryFile("wt") as fd:
+            fd.write("0 1 a\n")
+            fd.write("1 0 b\n")
+            fd.flush()
+            graph = rustworkx.PyGraph.read_edge_list(fd.name)
+        self.assertTrue(graph.multigraph)
+        self.assertEqual(graph.weighted_edge_list(), [(0, 1, "a"), (1, 0, "b")])
+
+    def test_not_multigraph(self):
+        with tempfile.NamedTemporaryFile("wt") as fd:
+            fd.write("0 1 a\n")
+            fd.write("1 2 b\n")
+            fd.write("1 0 c\n")
+            fd.flush()
+            graph = rustworkx.PyGraph.read_edge_list(fd.name, multigraph=False)
+        self.assertFalse(graph.multigraph)
+        self.assertEqual(graph.weighted_edge_list(), [(0, 1, "c"), (1, 2, "b")])
+
+    def test_not_multigraph_labels(self):
+        with tempfile.NamedTemporaryFile("wt") as fd:
+            fd.write("a,b\n")
+            fd.write("a,b,1\n")
+            fd.flush()
+            graph = rustworkx.PyGraph.read_edge_list(
+                fd.name, deliminator=",", labels=True, multigraph=False
+            )
+        self.assertEqual(graph.weighted_edge_list(), [(0, 1, "1")])
+
+    def test_weight_fn(self):
+        with tempfile.NamedTemporaryFile("wt") as fd:
+            fd.write("0 1 0.5\n")
+            fd.write("1 2\n")
+            fd.write("2 3 1.5 # test comments\n")
+            fd.flush()
+            graph = rustworkx.PyGraph.read_edge_list(fd.name, comment="#", weight_fn=float)
+        self.assertEqual(graph.weighted_edge_list(), [(0, 1, 0.5), (1, 2, None), (2, 3, 1.5)])
+
+    def test_weight_fn_labels(self):
+        with tempfile.NamedTemporaryFile("wt") as fd:
+            fd.write("a|b|1|2\n")
+            fd.write("b|c|3\n")
+            fd.flush()
+            graph = rustworkx.PyGraph.read_edge_list(
+                fd.name, deliminator="|", labels=True, weight_fn=lambda x: x.split("|")
+            )
+        self.assertEqual(graph.edges(), [["1", "2"], ["3"]])
+
+    def test_weight_fn_invalid_weight(self):
+        with tempfile.NamedTemporaryFile("wt") as fd:
+            fd.write("0 1 a\n")
+            fd.flush()
+            with self.assertRaises(ValueError):
+                rustworkx.PyGraph.read_edge_list(fd.name, weight_fn=float)
+
+    def test_missing_target_field(self):
+        with tempfile.NamedTemporaryFile("wt") as fd:
+            fd.write("0 1\n")
+            fd.write("2\n")
+            fd.flush()
+            with self.assertRaisesRegex(ValueError, "Line 2"):
+                rustworkx.PyGraph.read_edge_list(fd.name)
+
+    def test_missing_target_field_labels(self):
+        with tempfile.NamedTemporaryFile("wt") as fd:
+            fd.write("a,b\n")
+            fd.write("c\n")
+            fd.flush()
+            with self.assertRaisesRegex(ValueError, "Line 2"):
+                rustworkx.PyGraph.read_edge_list(fd.name, deliminator=",", labels=True)
+
+    def test_invalid_node_index(self):
+        with tempfile.NamedTemporaryFile("wt") as fd:
+            fd.write("0 1\n")
+            fd.write("1 2\n")
+            fd.write("1 b\n")
+            fd.flush()
+            with self.assertRaisesRegex(ValueError, 'target node index "b" on line 3'):
+                rustworkx.PyGraph.read_edge_list(fd.name)
+
+    def test_blank_line_with_comment_graph(self):
+        with tempfile.NamedTemporaryFile("wt") as fd:
+            fd.write("0 1\n")
+            fd.write("\n")
+            fd.write("1 2 # test comments\n")
+            fd.flush()
+            graph = rustworkx.PyGraph.read_edge_list(fd.name, comment="#")
+        self.assertEqual(graph.edge_list(), [(0, 1), (1, 2)])
+
     def test_write_edge_list_empty_digraph(self):
         path = os.path.join(tempfile.gettempdir(), "empty.txt")
         graph = rustworkx.PyGraph()
@@ -151,6 +241,18 @@ class TestEdgeList(unittest.TestCase):
         with open(path) as edge_file:
             self.assertEqual("", edge_file.read())
 
+    def test_write_edge_list_without_weight_fn(self):
+        graph = rustworkx.PyGraph()
+        graph.add_nodes_from(range(4))
+        graph.add_edges_from([(0, 1, object()), (1, 2, {"a": [1, 2]}), (2, 3, None)])
+        graph.remove_edge_from_index(1)
+        self.assertEqual("0,1\n2,3\n", graph.write_edge_list(deliminator=","))
+
+    def test_write_edge_list_weight_fn_returns_none(self):
+        graph = rustworkx.generators.path_graph(3)
+        res = graph.write_edge_list(weight_fn=lambda _: None)
+        self.assertEqual("0 1\n1 2\n", res)
+
     def test_write_edge_list_round_trip(self):
         path = os.path.join(tempfile.gettempdir(), "round_trip.txt")
         graph = rustworkx.generators.star_graph(5)
@@ -183,6 +285,111 @@ class TestEdgeList(unittest.TestCase):
         with open(path) as edge_file:
             self.assertEqual(edge_file.read(), expected)
 
+    def test_write_edge_list_quoted_weights_round_trip(self):
+        weights = [
+            "plain",
+            "two words",
+            "tab\tseparated",
+            "a,b",
+            "line\nbreak\r\n",
+            '"quoted"',
+            "back\\slash \\n",
+            " padded ",
+            "",
+        ]
+        for deliminator in [None, ",", " "]:
+            with self.subTest(deliminator=deliminator):
+                path = os.path.join(tempfile.gettempdir(), "quoted_weights.txt")
+                self.addCleanup(cleanup_file, path)
+                graph = rustworkx.PyGraph()
+                graph.add_nodes_from(range(len(weights) + 1))
+                graph.add_edges_from([(0, i + 1, weight) for i, weight in enumerate(weights)])
+                graph.write_edge_list(path, deliminator=deliminator, weight_fn=lambda x: x)
+                new_graph = rustworkx.PyGraph.read_edge_list(path, deliminator=deliminator)
+                self.assertEqual(graph.weighted_edge_list(), new_graph.weighted_edge_list())
+
+    def test_write_edge_list_quotes_only_when_needed(self):
+        path = os.path.join(tempfile.gettempdir(), "quoted_weights.txt")
+        self.addCleanup(cleanup_file, path)
+        graph = rustworkx.PyGraph()
+        graph.add_nodes_from(range(3))
+        graph.add_edges_from([(0, 1, "a b"), (1, 2, 'say "hi"\n')])
+        graph.write_edge_list(path, deliminator=",", weight_fn=lambda x: x)
+        with open(path) as edge_file:
+            self.assertEqual(edge_file.read(), '0,1,a b\n1,2,"say \\"hi\\"\\n"\n')
+
+    def test_read_edge_list_quoted_weight(self):
+        with tempfile.NamedTemporaryFile("wt") as fd:
+            fd.write('0 1 "a  b"\n')
+            fd.write("1 2 a  b\n")
+            fd.flush()
+            graph = rustworkx.PyGraph.read_edge_list(fd.name)
+        self.assertEqual([(0, 1, "a  b"), (1, 2, "a b")], graph.weighted_edge_list())
+
+    def test_read_edge_list_unterminated_quoted_weight(self):
+        for line in ['0 1 "a\n', '0 1 "a"b\n', '0 1 "a\\"\n']:
+            with self.subTest(line=line):
+                with tempfile.NamedTemporaryFile("wt") as fd:
+                    fd.write(line)
+                    fd.flush()
+                    with self.assertRaisesRegex(ValueError, "line 1"):
+                        rustworkx.PyGraph.read_edge_list(fd.name)
+
+    def test_read_edge_list_file_object(self):
+        for mode, data in [("rt", "0 1 a\n1 2\n"), ("rb", b"0 1 a\n1 2\n")]:
+            with self.subTest(mode=mode):
+                path = os.path.join(tempfile.gettempdir(), "file_object.txt")
+                self.addCleanup(cleanup_file, path)
+                with open(path, "wb") as fd:
+                    fd.write(b"0 1 a\n1 2\n")
+                with open(path, mode) as fd:
+                    graph = rustworkx.PyGraph.read_edge_list(fd)
+                self.assertEqual([(0, 1, "a"), (1, 2, None)], graph.weighted_edge_list())
+                graph = rustworkx.PyGraph.read_edge_list(
+                    io.StringIO(data) if mode == "rt" else io.BytesIO(data)
+                )
+                self.assertEqual([(0, 1, "a"), (1, 2, None)], graph.weighted_edge_list())
+
+    def test_read_edge_list_invalid_type(self):
+        with self.assertRaises(TypeError):
+            rustworkx.PyGraph.read_edge_list(42)
+
+    def test_read_edge_list_from_string(self):
+        graph = rustworkx.PyGraph.read_edge_list_from_string(
+            "# comment\na,b,1.5\nb,c,2\n",
+            comment="#",
+            deliminator=",",
+            labels=True,
+            weight_fn=float,
+        )
+        self.assertEqual(["a", "b", "c"], graph.nodes())
+        self.assertEqual([(0, 1, 1.5), (1, 2, 2.0)], graph.weighted_edge_list())
+
+    def test_read_edge_list_from_string_invalid(self):
+        with self.assertRaisesRegex(ValueError, "Line 2"):
+            rustworkx.PyGraph.read_edge_list_from_string("0 1\n0\n")
+
+    def test_write_edge_list_to_string(self):
+        graph = rustworkx.generators.path_graph(3)
+        self.assertEqual("0 1\n1 2\n", graph.write_edge_list())
+        self.assertEqual(
+            "0,1,a\n1,2,a\n", graph.write_edge_list(deliminator=",", weight_fn=lambda _: "a")
+        )
+        self.assertEqual("", rustworkx.PyGraph().write_edge_list())
+
+    def test_write_edge_list_file_object(self):
+        graph = rustworkx.generators.path_graph(3)
+        buf = io.StringIO()
+        self.assertIsNone(graph.write_edge_list(buf, deliminator=","))
+        self.assertEqual("0,1\n1,2\n", buf.getvalue())
+        new_graph = rustworkx.PyGraph.read_edge_list(io.StringIO(buf.getvalue()), deliminator=",")
+        self.assertEqual(graph.edge_list(), new_graph.edge_list())
+
+    def test_write_edge_list_invalid_type(self):
+        graph = rustworkx.generators.path_graph(3)
+        with self.assertRaises(TypeError):
+            graph.write_edge_list(42)
+
     def test_invalid_return_type_weight_fn(self):
         path = os.path.join(tempfile.gettempdir(), "fail.txt")
         graph = rustworkx.undirected_gnm_random_graph(5, 4)
@@ -10,7 +10,9 @@
 # License for the specific language governing permissions and limitations
 # under the License.
 
+import gc
 import unittest
+import weakref
 
 import rustworkx
 
@@ -34,12 +36,51 @@ class TestEdges(unittest.TestCase):
         self.assertIn("b", res)
         self.assertIn("Edgy", res)
 
+    def test_get_all_edge_data_with_index(self):
+        graph = rustworkx.PyGraph()
+        node_a = graph.add_node("a")
+        node_b = graph.add_node("b")
+        node_c = graph.add_node("c")
+        graph.add_edge(node_a, node_b, "Edgy")
+        graph.add_edge(node_b, node_c, "c")
+        graph.add_edge(node_b, node_a, "b")
+        res = graph.get_all_edge_data_with_index(node_a, node_b)
+        self.assertEqual([(0, "Edgy"), (2, "b")], sorted(res))
+        self.assertEqual([data for _, data in res], graph.get_all_edge_data(node_a, node_b))
+        self.assertEqual([(1, "c")], graph.get_all_edge_data_with_index(node_c, node_b))
+
+    def test_no_edge_get_all_edge_data_with_index(self):
+        graph = rustworkx.PyGraph()
+        node_a = graph.add_node("a")
+        node_b = graph.add_node("b")
+        with self.assertRaises(rustworkx.NoEdgeBetweenNodes):
+            graph.get_all_edge_data_with_index(node_a, node_b)
+
     def test_no_edge(self):
         graph = rustworkx.PyGraph()
         node_a = graph.add_node("a")
         node_b = graph.add_node("b")
         self.assertRaises(rustworkx.NoEdgeBetweenNodes, graph.get_edge_data, node_a, node_b)
 
+    def test_get_edge_data_default(self):
+        graph = rustworkx.PyGraph()
+        node_a = graph.add_node("a")
+        node_b = graph.add_node("b")
+        node_c = graph.add_node("c")
+        graph.add_edge(node_a, node_b, "Edgy")
+        self.assertEqual("Edgy", graph.get_edge_data(node_a, node_b, default="missing"))
+        self.assertEqual("missing", graph.get_edge_data(node_a, node_c, default="missing"))
+        self.assertIsNone(graph.get_edge_data(node_a, node_c, default=None))
+
+    def test_get_edge_data_invalid_keyword(self):
+        graph = rustworkx.PyGraph()
+        node_a = graph.add_node("a")
+        node_b = graph.add_node("b")
+        with self.assertRaises(TypeError):
+            graph.get_edge_data(node_a, node_b, fallback=None)
+        with self.assertRaises(TypeError):
+            graph.get_edge_data(node_a, node_b, "missing")
+
     def test_num_edges(self):
         graph = rustworkx.PyGraph()
         graph.add_node(1)
@@ -54,6 +95,19 @@ class TestEdges(unittest.TestCase):
         graph.add_node(42)
         self.assertEqual(0, graph.num_edges())
 
+    def test_num_self_loops(self):
+        graph = rustworkx.PyGraph()
+        graph.add_nodes_from(range(3))
+        graph.add_edges_from_no_data([(0, 0), (0, 1), (1, 1), (1, 1), (1, 2)])
+        self.assertEqual(3, graph.num_self_loops())
+        self.assertEqual(5, graph.num_edges())
+        degree_sum = sum(graph.degree(node) for node in graph.node_indices())
+        self.assertEqual(2 * graph.num_edges(), degree_sum)
+
+    def test_num_self_loops_no_self_loops(self):
+        graph = rustworkx.generators.cycle_graph(4)
+        self.assertEqual(0, graph.num_self_loops())
+
     def test_update_edge(self):
         graph = rustworkx.PyGraph()
         node_a = graph.add_node("a")
@@ -94,6 +148,27 @@ class TestEdges(unittest.TestCase):
             list(graph.weighted_edge_list()),
         )
 
+    def test_update_edges_from(self):
+        graph = rustworkx.generators.path_graph(4)
+        graph.update_edges_from((edge, f"e{edge}") for edge in graph.edge_indices())
+        self.assertEqual([(0, 1, "e0"), (1, 2, "e1"), (2, 3, "e2")], graph.weighted_edge_list())
+
+    def test_update_edges_from_repeated_index(self):
+        graph = rustworkx.generators.path_graph(2)
+        graph.update_edges_from([(0, "a"), (0, "b")])
+        self.assertEqual([(0, 1, "b")], graph.weighted_edge_list())
+
+    def test_update_edges_from_missing(self):
+        graph = rustworkx.generators.path_graph(3)
+        with self.assertRaisesRegex(IndexError, r"\[5, 7\]"):
+            graph.update_edges_from([(5, "a"), (1, "b"), (7, "c")])
+        self.assertEqual([(0, 1, None), (1, 2, "b")], graph.weighted_edge_list())
+
+    def test_update_edges_from_ignore_missing(self):
+        graph = rustworkx.generators.path_graph(3)
+        graph.update_edges_from([(5, "a"), (0, "b")], ignore_missing=True)
+        self.assertEqual([(0, 1, "b"), (1, 2, None)], graph.weighted_edge_list())
+
     def test_no_edge_get_all_edge_data(self):
         graph = rustworkx.PyGraph()
         node_a = graph.add_node("a")
@@ -114,6 +189,35 @@ class TestEdges(unittest.TestCase):
         node_b = graph.add_node("b")
         self.assertFalse(graph.has_edge(node_a, node_b))
 
+    def test_has_edge_by_index(self):
+        graph = rustworkx.generators.path_graph(4)
+        self.assertTrue(graph.has_edge_by_index(1))
+        graph.remove_edge_from_index(1)
+        self.assertFalse(graph.has_edge_by_index(1))
+        self.assertTrue(graph.has_edge_by_index(2))
+        self.assertFalse(graph.has_edge_by_index(42))
+
+    def test_find_edge_index(self):
+        graph = rustworkx.PyGraph()
+        graph.add_nodes_from(["a", "b", "c"])
+        graph.add_edges_from([(0, 1, "ab"), (1, 2, "bc"), (2, 2, "cc")])
+        self.assertEqual(1, graph.find_edge_index(1, 2))
+        self.assertEqual(1, graph.find_edge_index(2, 1))
+        self.assertEqual(2, graph.find_edge_index(2, 2))
+        self.assertEqual("ab", graph.get_edge_data_by_index(graph.find_edge_index(0, 1)))
+
+    def test_find_edge_index_no_edge(self):
+        graph = rustworkx.PyGraph()
+        graph.add_nodes_from(["a", "b"])
+        self.assertIsNone(graph.find_edge_index(0, 1))
+        self.assertIsNone(graph.find_edge_index(0, 42))
+
+    def test_find_edge_index_parallel_edges(self):
+        graph = rustworkx.PyGraph()
+        graph.add_nodes_from(["a", "b"])
+        graph.add_edges_from([(0, 1, "first"), (1, 0, "second")])
+        self.assertIn(graph.find_edge_index(0, 1), {0, 1})
+
     def test_edges(self):
         graph = rustworkx.PyGraph()
         node_a = graph.add_node("a")
@@ -123,6 +227,16 @@ class TestEdges(unittest.TestCase):
         graph.add_edge(node_b, node_c, "Super edgy")
         self.assertEqual(["Edgy", "Super edgy"], graph.edges())
 
+    def test_edges_data(self):
+        graph = rustworkx.PyGraph()
+        graph.add_nodes_from(["a", "b", "c"])
+        graph.add_edges_from([(0, 1, "ab"), (2, 1, "cb"), (0, 2, "ac")])
+        graph.remove_edge_from_index(0)
+        self.assertEqual(["cb", "ac"], graph.edges())
+        self.assertEqual(["cb", "ac"], graph.edges(data=False))
+        self.assertEqual([(2, 1, "cb"), (0, 2, "ac")], graph.edges(data=True))
+        self.assertEqual(graph.weighted_edge_list(), graph.edges(data=True))
+
     def test_edges_empty(self):
         graph = rustworkx.PyGraph()
         graph.add_node("a")
@@ -137,6 +251,31 @@ class TestEdges(unittest.TestCase):
         graph.add_edge(node_b, node_c, "Super edgy")
         self.assertEqual([0, 1], graph.edge_indices())
 
+    def test_iter_edge_indices(self):
+        graph = rustworkx.generators.path_graph(5)
+        graph.remove_edges_from([(0, 1), (2, 3)])
+        self.assertEqual([1, 3], list(graph.iter_edge_indices()))
+        self.assertEqual(list(graph.edge_indices()), list(graph.iter_edge_indices()))
+        self.assertIsInstance(graph.iter_edge_indices(), rustworkx.LazyEdgeIndices)
+
+    def test_iter_edge_indices_reference_cycle(self):
+        class Payload:
+            pass
+
+        graph = rustworkx.generators.path_graph(2)
+        payload = Payload()
+        payload.indices = graph.iter_edge_indices()
+        graph.update_edge(0, 1, payload)
+        payload_ref = weakref.ref(payload)
+        del graph, payload
+        gc.collect()
+        self.assertIsNone(payload_ref())
+
+    def test_iter_edge_indices_empty(self):
+        graph = rustworkx.PyGraph()
+        graph.add_node("a")
+        self.assertEqual([], list(graph.iter_edge_indices()))
+
     def test_get_edge_indices_empty(self):
         graph = rustworkx.PyGraph()
         graph.add_node("a")
@@ -231,6 +370,47 @@ class TestEdges(unittest.TestCase):
         graph.extend_from_edge_list([(0, 0), (0, 1), (0, 0)])
         self.assertEqual(5, graph.degree(0))
 
+    def test_degree_sequence(self):
+        graph = rustworkx.PyGraph()
+        graph.extend_from_edge_list([(0, 0), (0, 1), (0, 0), (1, 2), (1, 2), (3, 4)])
+        graph.add_node(None)
+        graph.remove_node(3)
+        self.assertEqual([5, 3, 2, 0, 0], graph.degree_sequence())
+        self.assertEqual([0, 0, 2, 3, 5], graph.degree_sequence(descending=False))
+        self.assertEqual(
+            sorted((graph.degree(node) for node in graph.node_indices()), reverse=True),
+            graph.degree_sequence(),
+        )
+
+    def test_degree_sequence_empty(self):
+        self.assertEqual([], rustworkx.PyGraph().degree_sequence())
+
+    def test_degree_histogram(self):
+        graph = rustworkx.generators.star_graph(5)
+        graph.add_node(None)
+        graph.add_edge(1, 1, None)
+        self.assertEqual({0: 1, 1: 3, 3: 1, 4: 1}, graph.degree_histogram())
+        self.assertEqual([0, 1, 3, 4], list(graph.degree_histogram()))
+
+    def test_degree_histogram_matches_degree(self):
+        graph = rustworkx.generators.grid_graph(3, 4)
+        graph.remove_node(5)
+        expected = {}
+        for node in graph.node_indices():
+            expected[graph.degree(node)] = expected.get(graph.degree(node), 0) + 1
+        self.assertEqual(expected, graph.degree_histogram())
+
+    def test_degree_histogram_empty(self):
+        self.assertEqual({}, rustworkx.PyGraph().degree_histogram())
+
+    def test_add_edge_return_status(self):
+        graph = rustworkx.PyGraph()
+        graph.add_nodes_from([0, 1])
+        self.assertEqual(0, graph.add_edge(0, 1, "a"))
+        self.assertEqual((1, True), graph.add_edge(0, 1, "b", return_status=True))
+        with self.assertRaises(IndexError):
+            graph.add_edge(0, 5, "c", return_status=True)
+
     def test_add_edge_from(self):
         graph = rustworkx.PyGraph()
         nodes = list(range(4))
@@ -369,6 +549,44 @@ class TestEdges(unittest.TestCase):
         graph = rustworkx.PyGraph()
         self.assertEqual([], graph.weighted_edge_list())
 
+    def test_weighted_edge_list_nodes(self):
+        graph = rustworkx.PyGraph()
+        graph.add_nodes_from(list(range(4)))
+        graph.add_edges_from([(0, 1, "a"), (1, 2, "b"), (0, 2, "c"), (2, 3, "d"), (0, 0, "e")])
+        self.assertEqual(
+            [(0, 1, "a"), (1, 2, "b"), (0, 2, "c"), (0, 0, "e")],
+            graph.weighted_edge_list(nodes=[0, 1, 2, 42]),
+        )
+        self.assertEqual([(2, 3, "d")], graph.weighted_edge_list(iter([3, 2])))
+        self.assertEqual([], graph.weighted_edge_list(nodes=[]))
+
+    def test_weighted_edge_list_nodes_large_selection(self):
+        graph = rustworkx.generators.path_graph(20)
+        nodes = [node for node in range(20) if node != 10]
+        self.assertEqual(
+            [edge for edge in graph.weighted_edge_list() if 10 not in edge[:2]],
+            graph.weighted_edge_list(nodes),
+        )
+
+    def test_weighted_edge_list_weight_fn(self):
+        graph = rustworkx.PyGraph()
+        graph.add_nodes_from(list(range(3)))
+        graph.add_edges_from([(0, 1, {"weight": 1.5}), (1, 2, {"weight": 2}), (2, 0, {})])
+        self.assertEqual(
+            [(0, 1, 1.5), (1, 2, 2.0), (2, 0, 1.0)],
+            graph.weighted_edge_list(weight_fn=lambda edge: float(edge.get("weight", 1))),
+        )
+        self.assertEqual(
+            [(1, 2, 2)], graph.weighted_edge_list([1, 2], weight_fn=lambda edge: edge["weight"])
+        )
+        # The payloads are not modified
+        self.assertEqual({"weight": 1.5}, graph.get_edge_data(0, 1))
+
+    def test_weighted_edge_list_weight_fn_error(self):
+        graph = rustworkx.generators.path_graph(3)
+        with self.assertRaises(TypeError):
+            graph.weighted_edge_list(weight_fn=lambda edge: edge["weight"])
+
     def test_edge_indices_from_endpoints(self):
         dag = rustworkx.PyGraph()
         dag.add_nodes_from(list(range(4)))
@@ -389,6 +607,18 @@ class TestEdges(unittest.TestCase):
         indices = dag.edge_indices_from_endpoints(0, 2)
         self.assertEqual(set(indices), {2, 5, 6})
 
+    def test_edge_indices_from_endpoints_reversed(self):
+        graph = rustworkx.PyGraph()
+        node_a = graph.add_node("a")
+        node_b = graph.add_node("b")
+        graph.add_edge(node_b, node_a, "ba")
+        graph.add_edge(node_a, node_b, "ab")
+        graph.add_edge(node_a, node_a, "aa")
+        self.assertEqual([0, 1], sorted(graph.edge_indices_from_endpoints(node_a, node_b)))
+        self.assertEqual([0, 1], sorted(graph.edge_indices_from_endpoints(node_b, node_a)))
+        self.assertEqual([2], graph.edge_indices_from_endpoints(node_a, node_a))
+        self.assertEqual([], graph.edge_indices_from_endpoints(node_b, node_b))
+
     def test_extend_from_edge_list(self):
         graph = rustworkx.PyGraph()
         edge_list = [(0, 1), (1, 2), (0, 2), (2, 3), (0, 3)]
@@ -456,6 +686,31 @@ class TestEdges(unittest.TestCase):
         graph.extend_from_weighted_edge_list(edge_gen)
         self.assertEqual(len(graph), 4)
 
+    def test_extend_from_edge_list_node_count_hint(self):
+        graph = rustworkx.PyGraph()
+        graph.extend_from_edge_list([(0, 1), (1, 1000)], node_count_hint=1001)
+        self.assertEqual(len(graph), 1001)
+        self.assertEqual([(0, 1), (1, 1000)], graph.edge_list())
+
+    def test_extend_from_edge_gen_node_count_hint_too_small(self):
+        graph = rustworkx.PyGraph()
+        graph.extend_from_edge_list(((i, i + 1) for i in range(10)), node_count_hint=2)
+        self.assertEqual(len(graph), 11)
+        self.assertEqual(10, graph.num_edges())
+
+    def test_extend_from_weighted_edge_list_node_count_hint(self):
+        graph = rustworkx.PyGraph()
+        graph.extend_from_weighted_edge_list([(0, 1, "a"), (1, 1000, "b")], node_count_hint=1001)
+        self.assertEqual(len(graph), 1001)
+        self.assertEqual([(0, 1, "a"), (1, 1000, "b")], graph.weighted_edge_list())
+
+    def test_extend_from_edge_list_huge_node_count_hint(self):
+        graph = rustworkx.PyGraph()
+        graph.extend_from_edge_list([(0, 1)], node_count_hint=2**40)
+        graph.extend_from_weighted_edge_list([(1, 2, "a")], node_count_hint=2**40)
+        self.assertEqual(3, len(graph))
+        self.assertEqual([(0, 1, None), (1, 2, "a")], graph.weighted_edge_list())
+
     def test_add_edges_from_parallel_edges(self):
         graph = rustworkx.PyGraph()
         graph.add_nodes_from([0, 1])
@@ -470,6 +725,31 @@ class TestEdges(unittest.TestCase):
         self.assertEqual([0, 1], res)
         self.assertEqual([None, None], graph.edges())
 
+    def test_add_edges_from_check_all_first(self):
+        graph = rustworkx.PyGraph()
+        graph.add_nodes_from([0, 1, 2])
+        res = graph.add_edges_from([(0, 1, "a"), (1, 2, "b"), (0, 1, "c")], check_all_first=True)
+        self.assertEqual([0, 1, 2], res)
+        self.assertEqual([(0, 1, "a"), (1, 2, "b"), (0, 1, "c")], graph.weighted_edge_list())
+
+    def test_add_edges_from_check_all_first_missing_endpoint(self):
+        graph = rustworkx.PyGraph()
+        graph.add_nodes_from([0, 1, 2])
+        graph.remove_node(2)
+        with self.assertRaises(IndexError):
+            graph.add_edges_from([(0, 1, "a"), (1, 2, "b")], check_all_first=True)
+        self.assertEqual(0, graph.num_edges())
+        with self.assertRaises(IndexError):
+            graph.add_edges_from([(0, 1, "a"), (1, 2, "b")])
+        self.assertEqual(1, graph.num_edges())
+
+    def test_add_edges_from_check_all_first_invalid_tuple(self):
+        graph = rustworkx.PyGraph()
+        graph.add_nodes_from([0, 1])
+        with self.assertRaises(TypeError):
+            graph.add_edges_from([(0, 1, "a"), ("b", 1, "c")], check_all_first=True)
+        self.assertEqual(0, graph.num_edges())
+
     def test_multigraph_attr(self):
         graph = rustworkx.PyGraph()
         self.assertTrue(graph.multigraph)
@@ -491,6 +771,23 @@ class TestEdges(unittest.TestCase):
         graph = rustworkx.PyGraph()
         self.assertFalse(graph.has_parallel_edges())
 
+    def test_has_parallel_edges_between(self):
+        graph = rustworkx.PyGraph()
+        graph.add_nodes_from([0, 1, 2])
+        graph.add_edges_from([(0, 1, None), (1, 0, None), (1, 2, None), (2, 2, None), (2, 2, None)])
+        self.assertTrue(graph.has_parallel_edges_between(0, 1))
+        self.assertTrue(graph.has_parallel_edges_between(1, 0))
+        self.assertFalse(graph.has_parallel_edges_between(1, 2))
+        self.assertFalse(graph.has_parallel_edges_between(0, 2))
+        self.assertTrue(graph.has_parallel_edges_between(2, 2))
+        self.assertFalse(graph.has_parallel_edges_between(0, 42))
+
+    def test_has_parallel_edges_between_single_self_loop(self):
+        graph = rustworkx.PyGraph()
+        graph.add_node(0)
+        graph.add_edge(0, 0, None)
+        self.assertFalse(graph.has_parallel_edges_between(0, 0))
+
     def test_edge_index_map(self):
         graph = rustworkx.PyGraph()
         node_a = graph.add_node(0)
@@ -509,6 +806,14 @@ class TestEdges(unittest.TestCase):
             graph.edge_index_map(),
         )
 
+    def test_edge_items(self):
+        graph = rustworkx.PyGraph()
+        graph.add_nodes_from(["a", "b", "c"])
+        graph.add_edges_from([(0, 1, "ab"), (2, 1, "cb"), (2, 2, "cc")])
+        graph.remove_edge_from_index(0)
+        self.assertEqual([(1, 2, 1, "cb"), (2, 2, 2, "cc")], graph.edge_items())
+        self.assertEqual([], rustworkx.PyGraph().edge_items())
+
     def test_incident_edges(self):
         graph = rustworkx.PyGraph()
         node_a = graph.add_node(0)
@@ -521,6 +826,16 @@ class TestEdges(unittest.TestCase):
         res = graph.incident_edges(node_d)
         self.assertEqual({1, 2}, set(res))
 
+    def test_incident_edges_exclude_self_loops(self):
+        graph = rustworkx.PyGraph()
+        graph.add_nodes_from(["a", "b"])
+        graph.add_edges_from([(0, 1, "ab"), (1, 1, "bb"), (1, 1, "bb2"), (0, 0, "aa")])
+        self.assertEqual({0, 1, 2}, set(graph.incident_edges(1)))
+        self.assertEqual([0], graph.incident_edges(1, include_self_loops=False))
+        self.assertEqual([0], graph.incident_edges(0, include_self_loops=False))
+        self.assertEqual(set(graph.incident_edges(1)), set(graph.in_edge_indices(1)))
+        self.assertEqual(set(graph.incident_edges(1)), set(graph.out_edge_indices(1)))
+
     def test_incident_edges_invalid_node(self):
         graph = rustworkx.PyGraph()
         res = graph.incident_edges(42)
@@ -599,10 +914,67 @@ class TestEdges(unittest.TestCase):
         res = g.in_edges(node_b)
         self.assertEqual([(node_c, node_b, {"a": 2}), (node_a, node_b, {"a": 1})], res)
 
+    def test_in_out_edges_indexed_parallel_edges(self):
+        graph = rustworkx.PyGraph()
+        graph.add_nodes_from(["a", "b"])
+        graph.add_edges_from([(0, 1, "x"), (1, 0, "y")])
+        self.assertEqual([(0, 1, 0, "x"), (0, 1, 1, "y")], sorted(graph.out_edges_indexed(0)))
+        self.assertEqual([(0, 1, 0, "x"), (0, 1, 1, "y")], sorted(graph.in_edges_indexed(1)))
+        self.assertEqual([(1, 0, 0, "x"), (1, 0, 1, "y")], sorted(graph.out_edges_indexed(1)))
+        self.assertEqual([], graph.in_edges_indexed(42))
+
+    def test_in_out_edges_indexed_match_in_out_edges(self):
+        graph = rustworkx.generators.star_graph(4)
+        for node in graph.node_indices():
+            self.assertEqual(
+                graph.out_edges(node),
+                [(src, tgt, data) for src, tgt, _, data in graph.out_edges_indexed(node)],
+            )
+            self.assertEqual(
+                graph.in_edges(node),
+                [(src, tgt, data) for src, tgt, _, data in graph.in_edges_indexed(node)],
+            )
+            for source, target, edge, _ in graph.out_edges_indexed(node):
+                self.assertEqual({source, target}, set(graph.get_edge_endpoints_by_index(edge)))
+
     def test_edge_index_map_empty(self):
         graph = rustworkx.PyGraph()
         self.assertEqual({}, graph.edge_index_map())
 
+    def test_edge_endpoint_map(self):
+        graph = rustworkx.PyGraph()
+        graph.add_nodes_from(range(4))
+        graph.add_edges_from([(0, 1, "a"), (2, 1, "b"), (3, 3, "c")])
+        self.assertEqual({(0, 1): "a", (1, 2): "b", (3, 3): "c"}, graph.edge_endpoint_map())
+
+    def test_edge_endpoint_map_parallel_edges(self):
+        graph = rustworkx.PyGraph()
+        graph.add_nodes_from(range(3))
+        graph.add_edges_from([(0, 1, "a"), (1, 0, "b"), (1, 2, "c")])
+        self.assertEqual({(0, 1): "b", (1, 2): "c"}, graph.edge_endpoint_map())
+
+    def test_edge_endpoint_multimap(self):
+        graph = rustworkx.PyGraph()
+        graph.add_nodes_from(range(3))
+        graph.add_edges_from([(0, 1, "a"), (1, 0, "b"), (1, 2, "c")])
+        self.assertEqual({(0, 1): ["a", "b"], (1, 2): ["c"]}, graph.edge_endpoint_multimap())
+
+    def test_edge_endpoint_map_empty(self):
+        graph = rustworkx.PyGraph()
+        self.assertEqual({}, graph.edge_endpoint_map())
+        self.assertEqual({}, graph.edge_endpoint_multimap())
+
+    def test_parallel_edge_counts(self):
+        graph = rustworkx.PyGraph()
+        graph.add_nodes_from(range(4))
+        graph.add_edges_from_no_data([(0, 1), (1, 0), (1, 2), (2, 2), (2, 2), (1, 0)])
+        self.assertEqual({(0, 1): 3, (1, 2): 1, (2, 2): 2}, graph.parallel_edge_counts())
+
+    def test_parallel_edge_counts_empty(self):
+        graph = rustworkx.PyGraph()
+        graph.add_nodes_from(range(3))
+        self.assertEqual({}, graph.parallel_edge_counts())
+
     def test_get_edge_data_by_index(self):
         graph = rustworkx.PyGraph()
         edge_list = [
@@ -643,6 +1015,34 @@ class TestEdges(unittest.TestCase):
         ):
             graph.get_edge_endpoints_by_index(2)
 
+    def test_reverse_edge(self):
+        graph = rustworkx.PyGraph()
+        graph.add_nodes_from(range(3))
+        graph.add_edges_from([(0, 1, "a"), (2, 1, "b")])
+        graph.reverse_edge(0)
+        self.assertEqual((1, 0), graph.get_edge_endpoints_by_index(0))
+        self.assertEqual([(1, 0, "a"), (2, 1, "b")], graph.weighted_edge_list())
+        self.assertEqual([0, 1], graph.edge_indices())
+        self.assertEqual(["a"], graph.get_all_edge_data(0, 1))
+
+    def test_reverse_edge_invalid_index(self):
+        graph = rustworkx.PyGraph()
+        with self.assertRaisesRegex(
+            IndexError, "Provided edge index 2 is not present in the graph"
+        ):
+            graph.reverse_edge(2)
+
+    def test_canonicalize_edges(self):
+        graph = rustworkx.PyGraph()
+        graph.add_nodes_from(range(4))
+        graph.add_edges_from([(1, 0, "a"), (1, 2, "b"), (3, 2, "c"), (3, 3, "d"), (2, 1, "e")])
+        graph.remove_edge_from_index(1)
+        graph.canonicalize_edges()
+        self.assertEqual(
+            [(0, 1, "a"), (2, 3, "c"), (3, 3, "d"), (1, 2, "e")], graph.weighted_edge_list()
+        )
+        self.assertEqual([0, 2, 3, 4], graph.edge_indices())
+
 
 class TestEdgesMultigraphFalse(unittest.TestCase):
     def test_multigraph_attr(self):
@@ -656,6 +1056,13 @@ class TestEdgesMultigraphFalse(unittest.TestCase):
         graph.add_edge(1, 0, 0)
         self.assertFalse(graph.has_parallel_edges())
 
+    def test_add_edge_return_status(self):
+        graph = rustworkx.PyGraph(multigraph=False)
+        graph.add_nodes_from([0, 1])
+        self.assertEqual((0, True), graph.add_edge(0, 1, "a", return_status=True))
+        self.assertEqual((0, False), graph.add_edge(1, 0, "b", return_status=True))
+        self.assertEqual(["b"], graph.edges())
+
     def test_parallel_edges_not_in_edge_list(self):
         graph = rustworkx.PyGraph(multigraph=False)
         edge_list = [
@@ -850,6 +1257,13 @@ class TestEdgesMultigraphFalse(unittest.TestCase):
         self.assertEqual([0, 0], res)
         self.assertEqual([None], graph.edges())
 
+    def test_add_edges_from_check_all_first_parallel_edges(self):
+        graph = rustworkx.PyGraph(False)
+        graph.add_nodes_from([0, 1])
+        res = graph.add_edges_from([(0, 1, False), (1, 0, True)], check_all_first=True)
+        self.assertEqual([0, 0], res)
+        self.assertEqual([True], graph.edges())
+
     def test_extend_from_weighted_edge_list_empty(self):
         graph = rustworkx.PyGraph()
         graph.extend_from_weighted_edge_list([])
@@ -943,3 +1357,52 @@ class TestEdgesMultigraphFalse(unittest.TestCase):
         g = rustworkx.PyGraph()
         with self.assertRaises(IndexError):
             g.add_edges_from_no_data([(2, 3)])
+
+
+class TestSubdivideEdge(unittest.TestCase):
+    def test_subdivide_edge(self):
+        graph = rustworkx.PyGraph()
+        graph.add_nodes_from(["u", "v"])
+        edge = graph.add_edge(0, 1, "uv")
+        node = graph.subdivide_edge(edge, "w", "uw", "wv")
+        self.assertEqual("w", graph[node])
+        self.assertEqual([(0, node, "uw"), (node, 1, "wv")], list(graph.weighted_edge_list()))
+
+    def test_subdivide_edge_copies_payload_by_reference(self):
+        graph = rustworkx.PyGraph()
+        graph.add_nodes_from(["u", "v"])
+        payload = {"weight": 1}
+        edge = graph.add_edge(0, 1, payload)
+        node = graph.subdivide_edge(edge, "w")
+        self.assertIs(payload, graph.get_edge_data(0, node))
+        self.assertIs(payload, graph.get_edge_data(node, 1))
+        self.assertFalse(graph.has_edge(0, 1))
+
+    def test_subdivide_edge_only_one_payload(self):
+        graph = rustworkx.PyGraph()
+        graph.add_nodes_from(["u", "v"])
+        edge = graph.add_edge(0, 1, "uv")
+        node = graph.subdivide_edge(edge, "w", edge_data_wv="wv")
+        self.assertEqual("uv", graph.get_edge_data(0, node))
+        self.assertEqual("wv", graph.get_edge_data(node, 1))
+
+    def test_subdivide_edge_none_payloads(self):
+        graph = rustworkx.PyGraph()
+        graph.add_nodes_from(["u", "v"])
+        edge = graph.add_edge(0, 1, "uv")
+        node = graph.subdivide_edge(edge, "w", None, edge_data_wv=None)
+        self.assertEqual([(0, node, None), (node, 1, None)], graph.weighted_edge_list())
+
+    def test_subdivide_edge_keeps_parallel_edges(self):
+        graph = rustworkx.PyGraph()
+        graph.add_nodes_from(["u", "v"])
+        graph.add_edge(0, 1, "a")
+        edge = graph.add_edge(0, 1, "b")
+        node = graph.subdivide_edge(edge, "w")
+        self.assertEqual(["a"], graph.get_all_edge_data(0, 1))
+        self.assertEqual(2, graph.degree(node))
+
+    def test_subdivide_edge_invalid_index(self):
+        graph = rustworkx.PyGraph()
+        with self.assertRaises(IndexError):
+            graph.subdivide_edge(0, "w")
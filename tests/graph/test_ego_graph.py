@@ -0,0 +1,63 @@
+# Licensed under the Apache License, Version 2.0 (the "License"); you may
+# not use this file except in compliance with the License. You may obtain
+# a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+# WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+# License for the specific language governing permissions and limitations
+# under the License.
+
+
+import unittest
+
+import rustworkx
+
+
+class TestEgoGraph(unittest.TestCase):
+    def setUp(self):
+        self.graph = rustworkx.generators.path_graph(6)
+        self.graph.add_edge(2, 2, "loop")
+
+    def test_ego_graph(self):
+        subgraph, node_map = self.graph.ego_graph(2)
+        self.assertEqual({0: 1, 1: 2, 2: 3}, dict(node_map))
+        self.assertEqual(
+            [(0, 1, None), (1, 2, None), (1, 1, "loop")], subgraph.weighted_edge_list()
+        )
+
+    def test_ego_graph_radius(self):
+        subgraph, node_map = self.graph.ego_graph(1, radius=2)
+        self.assertEqual([0, 1, 2, 3], list(node_map.values()))
+        self.assertEqual(4, subgraph.num_edges())
+
+    def test_ego_graph_radius_zero(self):
+        subgraph, node_map = self.graph.ego_graph(4, radius=0)
+        self.assertEqual({0: 4}, dict(node_map))
+        self.assertEqual(0, subgraph.num_edges())
+
+    def test_ego_graph_large_radius(self):
+        subgraph, node_map = self.graph.ego_graph(0, radius=100)
+        self.assertEqual(self.graph.node_indices(), list(node_map.values()))
+        self.assertEqual(self.graph.num_edges(), subgraph.num_edges())
+
+    def test_ego_graph_no_center(self):
+        graph = rustworkx.generators.star_graph(4)
+        graph.add_edge(1, 2, None)
+        subgraph, node_map = graph.ego_graph(0, center=False)
+        self.assertEqual([1, 2, 3], list(node_map.values()))
+        self.assertEqual([(0, 1)], subgraph.edge_list())
+
+    def test_ego_graph_matches_subgraph(self):
+        graph = rustworkx.generators.grid_graph(4, 4)
+        graph.remove_node(5)
+        subgraph, node_map = graph.ego_graph(0, radius=2)
+        expected, expected_map = graph.subgraph_with_nodemap(list(node_map.values()))
+        self.assertEqual(dict(expected_map), dict(node_map))
+        self.assertEqual(expected.weighted_edge_list(), subgraph.weighted_edge_list())
+
+    def test_ego_graph_invalid_node(self):
+        with self.assertRaises(IndexError):
+            self.graph.ego_graph(42)
@@ -0,0 +1,91 @@
+# Licensed under the Apache License, Version 2.0 (the "License"); you may
+# not use this file except in compliance with the License. You may obtain
+# a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+# WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+# License for the specific language governing permissions and limitations
+# under the License.
+
+import unittest
+
+import rustworkx
+
+
+class TestEq(unittest.TestCase):
+    def test_empty_graphs(self):
+        self.assertEqual(rustworkx.PyGraph(), rustworkx.PyGraph())
+
+    def test_same_construction(self):
+        graph_a = rustworkx.generators.path_graph(4)
+        graph_b = rustworkx.generators.path_graph(4)
+        self.assertEqual(graph_a, graph_b)
+        self.assertFalse(graph_a != graph_b)
+
+    def test_same_object(self):
+        graph = rustworkx.generators.cycle_graph(3)
+        self.assertEqual(graph, graph)
+
+    def test_edge_order_and_orientation_ignored(self):
+        graph_a = rustworkx.PyGraph()
+        graph_a.add_nodes_from(["a", "b", "c"])
+        graph_a.add_edges_from([(0, 1, "x"), (1, 2, "y")])
+        graph_b = rustworkx.PyGraph()
+        graph_b.add_nodes_from(["a", "b", "c"])
+        graph_b.add_edges_from([(2, 1, "y"), (1, 0, "x")])
+        self.assertEqual(graph_a, graph_b)
+
+    def test_different_node_payload(self):
+        graph_a = rustworkx.PyGraph()
+        graph_a.add_nodes_from(["a", "b"])
+        graph_b = rustworkx.PyGraph()
+        graph_b.add_nodes_from(["a", "c"])
+        self.assertNotEqual(graph_a, graph_b)
+
+    def test_different_node_indices(self):
+        graph_a = rustworkx.PyGraph()
+        graph_a.add_nodes_from(["a", "b", "c"])
+        graph_a.remove_node(1)
+        graph_b = rustworkx.PyGraph()
+        graph_b.add_nodes_from(["a", "c"])
+        self.assertNotEqual(graph_a, graph_b)
+
+    def test_different_edge_payload(self):
+        graph_a = rustworkx.PyGraph()
+        graph_a.add_nodes_from([0, 1])
+        graph_a.add_edge(0, 1, 1.0)
+        graph_b = rustworkx.PyGraph()
+        graph_b.add_nodes_from([0, 1])
+        graph_b.add_edge(0, 1, 2.0)
+        self.assertNotEqual(graph_a, graph_b)
+
+    def test_parallel_edges_multiset(self):
+        graph_a = rustworkx.PyGraph()
+        graph_a.add_nodes_from([0, 1])
+        graph_a.add_edges_from([(0, 1, "x"), (0, 1, "x"), (0, 1, "y")])
+        graph_b = rustworkx.PyGraph()
+        graph_b.add_nodes_from([0, 1])
+        graph_b.add_edges_from([(0, 1, "x"), (0, 1, "y"), (0, 1, "y")])
+        self.assertNotEqual(graph_a, graph_b)
+        graph_b.remove_edge_from_index(2)
+        graph_b.add_edge(1, 0, "x")
+        self.assertEqual(graph_a, graph_b)
+
+    def test_different_multigraph_flag(self):
+        self.assertNotEqual(rustworkx.PyGraph(multigraph=False), rustworkx.PyGraph())
+
+    def test_attrs_not_compared(self):
+        self.assertEqual(rustworkx.PyGraph(attrs="a"), rustworkx.PyGraph(attrs="b"))
+
+    def test_other_types(self):
+        graph = rustworkx.PyGraph()
+        self.assertNotEqual(graph, rustworkx.PyDiGraph())
+        self.assertNotEqual(graph, None)
+        self.assertFalse(graph == 1)
+
+    def test_unhashable(self):
+        with self.assertRaises(TypeError):
+            hash(rustworkx.PyGraph())
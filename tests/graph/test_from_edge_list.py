@@ -0,0 +1,67 @@
+# Licensed under the Apache License, Version 2.0 (the "License"); you may
+# not use this file except in compliance with the License. You may obtain
+# a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+# WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+# License for the specific language governing permissions and limitations
+# under the License.
+
+
+import unittest
+
+import rustworkx
+
+
+class TestFromEdgeList(unittest.TestCase):
+    def test_unweighted(self):
+        graph = rustworkx.PyGraph.from_edge_list([(0, 1), (1, 3), (3, 0)])
+        self.assertEqual([None] * 4, graph.nodes())
+        self.assertEqual([(0, 1, None), (1, 3, None), (3, 0, None)], graph.weighted_edge_list())
+        self.assertTrue(graph.multigraph)
+        self.assertIsNone(graph.attrs)
+
+    def test_weighted_tuples(self):
+        graph = rustworkx.PyGraph.from_edge_list(iter([(0, 1, "a"), (2, 1), (1, 0, "b")]))
+        self.assertEqual(3, len(graph))
+        self.assertEqual([(0, 1, "a"), (2, 1, None), (1, 0, "b")], graph.weighted_edge_list())
+
+    def test_weights(self):
+        graph = rustworkx.PyGraph.from_edge_list([(0, 1), (1, 2)], weights=(x for x in "ab"))
+        self.assertEqual([(0, 1, "a"), (1, 2, "b")], graph.weighted_edge_list())
+
+    def test_weights_length_mismatch(self):
+        with self.assertRaises(ValueError):
+            rustworkx.PyGraph.from_edge_list([(0, 1), (1, 2)], weights=["a"])
+        with self.assertRaises(ValueError):
+            rustworkx.PyGraph.from_edge_list([(0, 1)], weights=["a", "b"])
+
+    def test_weights_with_weighted_tuples(self):
+        with self.assertRaises(ValueError):
+            rustworkx.PyGraph.from_edge_list([(0, 1, "a")], weights=["b"])
+
+    def test_invalid_tuple_length(self):
+        with self.assertRaises(ValueError):
+            rustworkx.PyGraph.from_edge_list([(0, 1, 2, 3)])
+        with self.assertRaises(TypeError):
+            rustworkx.PyGraph.from_edge_list([("a", 1)])
+
+    def test_not_multigraph(self):
+        graph = rustworkx.PyGraph.from_edge_list(
+            [(0, 1, "a"), (1, 0, "b")], num_nodes=10, multigraph=False
+        )
+        self.assertFalse(graph.multigraph)
+        self.assertEqual(2, len(graph))
+        self.assertEqual([(0, 1, "b")], graph.weighted_edge_list())
+
+    def test_huge_num_nodes(self):
+        graph = rustworkx.PyGraph.from_edge_list([(0, 1)], num_nodes=2**40)
+        self.assertEqual(2, len(graph))
+        self.assertEqual([(0, 1)], graph.edge_list())
+
+    def test_empty(self):
+        graph = rustworkx.PyGraph.from_edge_list([], num_nodes=5)
+        self.assertEqual(0, len(graph))
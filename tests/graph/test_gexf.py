@@ -0,0 +1,134 @@
+# Licensed under the Apache License, Version 2.0 (the "License"); you may
+# not use this file except in compliance with the License. You may obtain
+# a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+# WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+# License for the specific language governing permissions and limitations
+# under the License.
+
+import os
+import tempfile
+import unittest
+import xml.etree.ElementTree as ET
+
+import rustworkx
+
+NS = {"gexf": "http://gexf.net/1.3"}
+
+
+class TestGEXFMethods(unittest.TestCase):
+    def setUp(self):
+        self.tempdir = tempfile.TemporaryDirectory()
+        self.path = os.path.join(self.tempdir.name, "graph.gexf")
+
+    def tearDown(self):
+        self.tempdir.cleanup()
+
+    def read_graph_elem(self):
+        root = ET.parse(self.path).getroot()
+        self.assertEqual("1.3", root.get("version"))
+        return root.find("gexf:graph", NS)
+
+    def attvalues(self, elem, attributes):
+        titles = {
+            attr.get("id"): attr.get("title")
+            for attr in attributes.findall("gexf:attribute", NS)
+        }
+        return {
+            titles[value.get("for")]: value.get("value")
+            for value in elem.findall("gexf:attvalues/gexf:attvalue", NS)
+        }
+
+    def test_write_gexf(self):
+        graph = rustworkx.PyGraph()
+        graph.add_nodes_from([{"label": "a", "size": 1}, {"label": "b", "size": 2}, {}])
+        graph.add_edges_from([(0, 1, {"weight": 1.5, "seen": True}), (1, 2, {"weight": 2.5})])
+        graph.write_gexf(self.path)
+        graph_elem = self.read_graph_elem()
+        self.assertEqual("static", graph_elem.get("mode"))
+        self.assertEqual("undirected", graph_elem.get("defaultedgetype"))
+        node_attributes = graph_elem.find("gexf:attributes[@class='node']", NS)
+        self.assertEqual(
+            {"label": "string", "size": "long"},
+            {
+                attr.get("title"): attr.get("type")
+                for attr in node_attributes.findall("gexf:attribute", NS)
+            },
+        )
+        edge_attributes = graph_elem.find("gexf:attributes[@class='edge']", NS)
+        self.assertEqual(
+            {"weight": "double", "seen": "boolean"},
+            {
+                attr.get("title"): attr.get("type")
+                for attr in edge_attributes.findall("gexf:attribute", NS)
+            },
+        )
+        nodes = graph_elem.findall("gexf:nodes/gexf:node", NS)
+        self.assertEqual(["0", "1", "2"], [node.get("id") for node in nodes])
+        self.assertEqual({"label": "a", "size": "1"}, self.attvalues(nodes[0], node_attributes))
+        self.assertEqual({}, self.attvalues(nodes[2], node_attributes))
+        edges = graph_elem.findall("gexf:edges/gexf:edge", NS)
+        self.assertEqual(
+            [("0", "0", "1"), ("1", "1", "2")],
+            [(edge.get("id"), edge.get("source"), edge.get("target")) for edge in edges],
+        )
+        self.assertEqual(
+            {"weight": "1.5", "seen": "true"}, self.attvalues(edges[0], edge_attributes)
+        )
+
+    def test_write_gexf_preserves_indices(self):
+        graph = rustworkx.generators.path_graph(5)
+        graph.remove_node(1)
+        graph.remove_edge(2, 3)
+        graph.write_gexf(self.path)
+        graph_elem = self.read_graph_elem()
+        self.assertIsNone(graph_elem.find("gexf:attributes", NS))
+        nodes = graph_elem.findall("gexf:nodes/gexf:node", NS)
+        self.assertEqual(["0", "2", "3", "4"], [node.get("id") for node in nodes])
+        edges = graph_elem.findall("gexf:edges/gexf:edge", NS)
+        self.assertEqual(
+            [("3", "3", "4")],
+            [(edge.get("id"), edge.get("source"), edge.get("target")) for edge in edges],
+        )
+
+    def test_attr_fns(self):
+        graph = rustworkx.PyGraph()
+        graph.add_nodes_from(["a", "b"])
+        graph.add_edge(0, 1, 7)
+        graph.write_gexf(
+            self.path,
+            node_attr_fn=lambda node: {"name": node},
+            edge_attr_fn=lambda edge: {"weight": str(edge)},
+        )
+        graph_elem = self.read_graph_elem()
+        node_attributes = graph_elem.find("gexf:attributes[@class='node']", NS)
+        edge_attributes = graph_elem.find("gexf:attributes[@class='edge']", NS)
+        self.assertEqual("string", edge_attributes.find("gexf:attribute", NS).get("type"))
+        nodes = graph_elem.findall("gexf:nodes/gexf:node", NS)
+        self.assertEqual(
+            [{"name": "a"}, {"name": "b"}],
+            [self.attvalues(node, node_attributes) for node in nodes],
+        )
+        edge = graph_elem.find("gexf:edges/gexf:edge", NS)
+        self.assertEqual({"weight": "7"}, self.attvalues(edge, edge_attributes))
+
+    def test_attr_fn_invalid_return(self):
+        graph = rustworkx.PyGraph()
+        graph.add_node("a")
+        with self.assertRaises(TypeError):
+            graph.write_gexf(self.path, node_attr_fn=lambda node: {"label": 1})
+
+    def test_mismatched_types(self):
+        graph = rustworkx.PyGraph()
+        graph.add_nodes_from([{"size": 1}, {"size": "big"}])
+        with self.assertRaises(Exception):
+            graph.write_gexf(self.path)
+
+    def test_invalid_path(self):
+        graph = rustworkx.generators.path_graph(2)
+        with self.assertRaises(OSError):
+            graph.write_gexf(os.path.join(self.tempdir.name, "missing", "graph.gexf"))
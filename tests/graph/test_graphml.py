@@ -0,0 +1,124 @@
+# Licensed under the Apache License, Version 2.0 (the "License"); you may
+# not use this file except in compliance with the License. You may obtain
+# a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+# WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+# License for the specific language governing permissions and limitations
+# under the License.
+
+import os
+import tempfile
+import unittest
+
+import rustworkx
+
+
+class TestGraphMLMethods(unittest.TestCase):
+    def setUp(self):
+        self.tempdir = tempfile.TemporaryDirectory()
+        self.path = os.path.join(self.tempdir.name, "graph.graphml")
+
+    def tearDown(self):
+        self.tempdir.cleanup()
+
+    def test_round_trip(self):
+        graph = rustworkx.PyGraph(attrs={"id": "G", "name": "test"})
+        graph.add_nodes_from([{"label": label, "size": size} for size, label in enumerate("abc")])
+        graph.add_edges_from([(0, 1, {"weight": 1.5}), (1, 2, {"weight": 2.5})])
+        graph.write_graphml(self.path)
+        new_graph = rustworkx.PyGraph.read_graphml(self.path)
+        self.assertEqual(new_graph.attrs, {"id": "G", "name": "test"})
+        self.assertEqual(new_graph.node_indices(), [0, 1, 2])
+        self.assertEqual(new_graph.nodes(), graph.nodes())
+        self.assertEqual(new_graph.weighted_edge_list(), graph.weighted_edge_list())
+
+    def test_round_trip_preserves_indices(self):
+        graph = rustworkx.PyGraph()
+        graph.add_nodes_from([{"n": i} for i in range(5)])
+        graph.add_edges_from([(0, 1, {"e": 0}), (1, 2, {"e": 1}), (2, 3, {"e": 2})])
+        graph.add_edges_from([(3, 4, {"e": 3}), (4, 0, {"e": 4})])
+        graph.remove_node(1)
+        graph.remove_edge_from_index(3)
+        graph.write_graphml(self.path)
+        new_graph = rustworkx.PyGraph.read_graphml(self.path)
+        self.assertEqual(new_graph.node_indices(), [0, 2, 3, 4])
+        self.assertEqual(new_graph.edge_indices(), [2, 4])
+        self.assertEqual(new_graph.nodes(), graph.nodes())
+        self.assertEqual(dict(new_graph.edge_index_map()), dict(graph.edge_index_map()))
+
+    def test_attr_fns(self):
+        graph = rustworkx.PyGraph()
+        graph.add_nodes_from(["a", "b"])
+        graph.add_edge(0, 1, 7)
+        graph.write_graphml(
+            self.path,
+            node_attr_fn=lambda node: {"label": node},
+            edge_attr_fn=lambda edge: {"weight": str(edge)},
+        )
+        new_graph = rustworkx.PyGraph.read_graphml(self.path)
+        self.assertEqual(new_graph.nodes(), [{"label": "a"}, {"label": "b"}])
+        self.assertEqual(new_graph.weighted_edge_list(), [(0, 1, {"weight": "7"})])
+
+    def test_non_dict_payloads_without_attr_fns(self):
+        graph = rustworkx.PyGraph()
+        graph.add_nodes_from(["a", "b"])
+        graph.add_edge(0, 1, None)
+        graph.write_graphml(self.path)
+        new_graph = rustworkx.PyGraph.read_graphml(self.path)
+        self.assertEqual(new_graph.nodes(), [{}, {}])
+        self.assertEqual(new_graph.weighted_edge_list(), [(0, 1, {})])
+
+    def test_attr_fn_invalid_return(self):
+        graph = rustworkx.PyGraph()
+        graph.add_node("a")
+        with self.assertRaises(TypeError):
+            graph.write_graphml(self.path, node_attr_fn=lambda node: {"label": 1})
+
+    def test_compression(self):
+        graph = rustworkx.generators.path_graph(3)
+        graph.write_graphml(self.path, compression="gzip")
+        with self.assertRaises(Exception):
+            rustworkx.PyGraph.read_graphml(self.path)
+        new_graph = rustworkx.PyGraph.read_graphml(self.path, compression="gzip")
+        self.assertEqual(new_graph.edge_list(), [(0, 1), (1, 2)])
+
+    def test_read_arbitrary_ids(self):
+        with open(self.path, "w") as fd:
+            fd.write(
+                """<?xml version="1.0" encoding="UTF-8"?>
+<graphml xmlns="http://graphml.graphdrawing.org/xmlns">
+  <key id="d0" for="node" attr.name="color" attr.type="string"/>
+  <graph id="G" edgedefault="undirected">
+    <node id="b"><data key="d0">blue</data></node>
+    <node id="a"><data key="d0">red</data></node>
+    <edge source="a" target="b"/>
+  </graph>
+</graphml>
+"""
+            )
+        graph = rustworkx.PyGraph.read_graphml(self.path)
+        self.assertEqual(graph.nodes(), [{"color": "blue", "id": "b"}, {"color": "red", "id": "a"}])
+        self.assertEqual(graph.weighted_edge_list(), [(1, 0, {})])
+        self.assertEqual(graph.attrs, {"id": "G"})
+
+    def test_read_directed_graph(self):
+        with open(self.path, "w") as fd:
+            fd.write(
+                """<?xml version="1.0" encoding="UTF-8"?>
+<graphml xmlns="http://graphml.graphdrawing.org/xmlns">
+  <graph id="G" edgedefault="directed">
+    <node id="n0"/>
+  </graph>
+</graphml>
+"""
+            )
+        with self.assertRaises(Exception):
+            rustworkx.PyGraph.read_graphml(self.path)
+
+    def test_invalid_path(self):
+        with self.assertRaises(Exception):
+            rustworkx.PyGraph.read_graphml(os.path.join(self.tempdir.name, "missing.graphml"))
@@ -56,3 +56,30 @@ class TestLineGraph(unittest.TestCase):
         self.assertEqual(out_graph.node_indices(), expected_nodes)
         self.assertEqual(out_graph.edge_list(), expected_edges)
         self.assertEqual(out_edge_map, expected_edge_map)
+
+    def test_line_graph_method(self):
+        graph = rustworkx.PyGraph()
+        graph.add_nodes_from(["a", "b", "c", "d"])
+        edge_ab = graph.add_edge(0, 1, "ab")
+        edge_ac = graph.add_edge(0, 2, "ac")
+        edge_bc = graph.add_edge(1, 2, "bc")
+        edge_ad = graph.add_edge(0, 3, "ad")
+        graph.remove_edge_from_index(edge_ab)
+        out_graph, node_map = graph.line_graph()
+        self.assertEqual({0: edge_ac, 1: edge_bc, 2: edge_ad}, node_map)
+        self.assertEqual(["ac", "bc", "ad"], out_graph.nodes())
+        self.assertEqual(
+            {(0, 1), (0, 2)}, {tuple(sorted(edge)) for edge in out_graph.edge_list()}
+        )
+        self.assertFalse(out_graph.multigraph)
+
+    def test_line_graph_method_parallel_edges_and_self_loops(self):
+        graph = rustworkx.PyGraph()
+        graph.add_nodes_from(["a", "b"])
+        graph.add_edges_from([(0, 1, "x"), (0, 1, "y"), (0, 0, "loop")])
+        out_graph, node_map = graph.line_graph()
+        self.assertEqual({0: 0, 1: 1, 2: 2}, node_map)
+        self.assertEqual(
+            [(0, 1), (0, 2), (1, 2)], sorted(tuple(sorted(edge)) for edge in out_graph.edge_list())
+        )
+        self.assertEqual([None, None, None], [w for (_, _, w) in out_graph.weighted_edge_list()])
@@ -0,0 +1,89 @@
+# Licensed under the Apache License, Version 2.0 (the "License"); you may
+# not use this file except in compliance with the License. You may obtain
+# a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+# WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+# License for the specific language governing permissions and limitations
+# under the License.
+
+import math
+import unittest
+
+import rustworkx
+
+
+class TestLinkPrediction(unittest.TestCase):
+    def setUp(self):
+        # 0 and 1 share the neighbors 2 and 3, and 3 is also adjacent to 4
+        self.graph = rustworkx.PyGraph()
+        self.graph.add_nodes_from(range(6))
+        self.graph.add_edges_from_no_data([(0, 2), (0, 3), (1, 2), (1, 3), (3, 4), (0, 5)])
+
+    def test_common_neighbors(self):
+        res = self.graph.common_neighbors(0, 1)
+        self.assertIsInstance(res, rustworkx.NodeIndices)
+        self.assertEqual([2, 3], res)
+        self.assertEqual([3], self.graph.common_neighbors(1, 4))
+        self.assertEqual([], self.graph.common_neighbors(2, 4))
+
+    def test_jaccard_coefficient(self):
+        self.assertAlmostEqual(2 / 3, self.graph.jaccard_coefficient(0, 1))
+        self.assertAlmostEqual(1 / 2, self.graph.jaccard_coefficient(1, 4))
+        self.assertEqual(0.0, self.graph.jaccard_coefficient(2, 4))
+
+    def test_adamic_adar_index(self):
+        expected = 1 / math.log(2) + 1 / math.log(3)
+        self.assertAlmostEqual(expected, self.graph.adamic_adar_index(0, 1))
+        self.assertAlmostEqual(1 / math.log(3), self.graph.adamic_adar_index(1, 4))
+        self.assertEqual(0.0, self.graph.adamic_adar_index(2, 4))
+
+    def test_adamic_adar_index_same_node(self):
+        # 5 is only adjacent to 0, so it must not contribute 1 / log(1)
+        expected = 1 / math.log(2) + 1 / math.log(3)
+        self.assertAlmostEqual(expected, self.graph.adamic_adar_index(0, 0))
+        self.assertAlmostEqual(1 / math.log(3), self.graph.adamic_adar_index(5, 5))
+
+    def test_adjacent_nodes(self):
+        # Adjacent nodes are not their own common neighbors
+        self.graph.add_edge(0, 1, None)
+        self.assertEqual([2, 3], self.graph.common_neighbors(0, 1))
+        self.assertAlmostEqual(2 / 5, self.graph.jaccard_coefficient(0, 1))
+
+    def test_parallel_edges_and_self_loops(self):
+        self.graph.add_edges_from_no_data([(0, 2), (2, 0), (0, 0), (2, 2), (1, 1)])
+        self.assertEqual([2, 3], self.graph.common_neighbors(0, 1))
+        self.assertAlmostEqual(2 / 3, self.graph.jaccard_coefficient(0, 1))
+        expected = 1 / math.log(2) + 1 / math.log(3)
+        self.assertAlmostEqual(expected, self.graph.adamic_adar_index(0, 1))
+
+    def test_isolated_nodes(self):
+        graph = rustworkx.PyGraph()
+        graph.add_nodes_from([None, None])
+        self.assertEqual([], graph.common_neighbors(0, 1))
+        self.assertEqual(0.0, graph.jaccard_coefficient(0, 1))
+        self.assertEqual(0.0, graph.adamic_adar_index(0, 1))
+
+    def test_jaccard_coefficient_pairs(self):
+        pairs = [(0, 1), (1, 4), (2, 4), (0, 1), (4, 1)]
+        res = self.graph.jaccard_coefficient_pairs(pairs)
+        self.assertEqual([self.graph.jaccard_coefficient(u, v) for u, v in pairs], res)
+        self.assertEqual([], self.graph.jaccard_coefficient_pairs([]))
+
+    def test_invalid_node(self):
+        self.graph.remove_node(5)
+        for method in [
+            self.graph.common_neighbors,
+            self.graph.jaccard_coefficient,
+            self.graph.adamic_adar_index,
+        ]:
+            with self.subTest(method=method.__name__):
+                with self.assertRaises(IndexError):
+                    method(0, 5)
+                with self.assertRaises(IndexError):
+                    method(42, 0)
+        with self.assertRaises(IndexError):
+            self.graph.jaccard_coefficient_pairs([(0, 1), (0, 5)])
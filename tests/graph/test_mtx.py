@@ -0,0 +1,148 @@
+# Licensed under the Apache License, Version 2.0 (the "License"); you may
+# not use this file except in compliance with the License. You may obtain
+# a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+# WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+# License for the specific language governing permissions and limitations
+# under the License.
+
+import os
+import tempfile
+import unittest
+
+import rustworkx
+
+
+class TestMatrixMarket(unittest.TestCase):
+    def setUp(self):
+        self.tempdir = tempfile.TemporaryDirectory()
+        self.path = os.path.join(self.tempdir.name, "graph.mtx")
+
+    def tearDown(self):
+        self.tempdir.cleanup()
+
+    def write(self, contents):
+        with open(self.path, "w") as fd:
+            fd.write(contents)
+
+    def test_read_real(self):
+        self.write(
+            "%%MatrixMarket matrix coordinate real symmetric\n"
+            "% A comment\n"
+            "%\n"
+            "4 4 3\n"
+            "2 1 1.5\n"
+            "3 2 -2\n"
+            "\n"
+            "4 4 1e3\n"
+        )
+        graph = rustworkx.PyGraph.read_mtx(self.path)
+        self.assertEqual([None] * 4, graph.nodes())
+        self.assertEqual(
+            [(1, 0, 1.5), (2, 1, -2.0), (3, 3, 1000.0)], list(graph.weighted_edge_list())
+        )
+        self.assertTrue(graph.multigraph)
+
+    def test_read_pattern(self):
+        self.write("%%MatrixMarket matrix coordinate pattern symmetric\n3 3 2\n2 1\n3 1\n")
+        graph = rustworkx.PyGraph.read_mtx(self.path)
+        self.assertEqual([(1, 0, None), (2, 0, None)], list(graph.weighted_edge_list()))
+
+    def test_read_integer(self):
+        self.write("%%MatrixMarket Matrix Coordinate Integer Symmetric\n2 2 1\n2 1 7\n")
+        graph = rustworkx.PyGraph.read_mtx(self.path)
+        self.assertEqual([(1, 0, 7)], list(graph.weighted_edge_list()))
+        self.assertIsInstance(graph.get_edge_data(0, 1), int)
+
+    def test_read_general(self):
+        self.write("%%MatrixMarket matrix coordinate real general\n2 2 1\n2 1 1.0\n")
+        with self.assertRaisesRegex(ValueError, "symmetry"):
+            rustworkx.PyGraph.read_mtx(self.path)
+
+    def test_read_array(self):
+        self.write("%%MatrixMarket matrix array real symmetric\n2 2\n1.0\n2.0\n3.0\n")
+        with self.assertRaisesRegex(ValueError, "coordinate"):
+            rustworkx.PyGraph.read_mtx(self.path)
+
+    def test_read_not_square(self):
+        self.write("%%MatrixMarket matrix coordinate real symmetric\n2 3 1\n2 1 1.0\n")
+        with self.assertRaisesRegex(ValueError, "square"):
+            rustworkx.PyGraph.read_mtx(self.path)
+
+    def test_read_out_of_bounds(self):
+        self.write("%%MatrixMarket matrix coordinate pattern symmetric\n2 2 1\n3 1\n")
+        with self.assertRaisesRegex(ValueError, "line 3"):
+            rustworkx.PyGraph.read_mtx(self.path)
+
+    def test_read_invalid_value(self):
+        self.write("%%MatrixMarket matrix coordinate real symmetric\n2 2 1\n2 1 abc\n")
+        with self.assertRaisesRegex(ValueError, "line 3"):
+            rustworkx.PyGraph.read_mtx(self.path)
+
+    def test_read_wrong_entry_count(self):
+        self.write("%%MatrixMarket matrix coordinate pattern symmetric\n2 2 2\n2 1\n")
+        with self.assertRaises(ValueError):
+            rustworkx.PyGraph.read_mtx(self.path)
+        self.write("%%MatrixMarket matrix coordinate pattern symmetric\n2 2 1\n2 1\n2 2\n")
+        with self.assertRaises(ValueError):
+            rustworkx.PyGraph.read_mtx(self.path)
+
+    def test_read_huge_size_line(self):
+        header = "%%MatrixMarket matrix coordinate pattern symmetric\n"
+        self.write(header + "10000000000 10000000000 1\n")
+        with self.assertRaisesRegex(ValueError, "line 2"):
+            rustworkx.PyGraph.read_mtx(self.path)
+        self.write(header + "2 2 1000000000000\n2 1\n")
+        with self.assertRaisesRegex(ValueError, "1 entries"):
+            rustworkx.PyGraph.read_mtx(self.path)
+
+    def test_read_missing_size_line(self):
+        self.write("%%MatrixMarket matrix coordinate pattern symmetric\n% only a comment\n")
+        with self.assertRaises(ValueError):
+            rustworkx.PyGraph.read_mtx(self.path)
+
+    def test_write_pattern(self):
+        graph = rustworkx.generators.path_graph(3)
+        graph.write_mtx(self.path)
+        with open(self.path) as fd:
+            contents = fd.read()
+        self.assertEqual(
+            "%%MatrixMarket matrix coordinate pattern symmetric\n3 3 2\n2 1\n3 2\n", contents
+        )
+
+    def test_write_real(self):
+        graph = rustworkx.PyGraph()
+        graph.add_nodes_from([None] * 3)
+        graph.add_edges_from([(0, 2, 0.5), (1, 1, 2)])
+        graph.write_mtx(self.path, weight_fn=float)
+        with open(self.path) as fd:
+            contents = fd.read()
+        self.assertEqual(
+            "%%MatrixMarket matrix coordinate real symmetric\n3 3 2\n3 1 0.5\n2 2 2.0\n", contents
+        )
+
+    def test_round_trip(self):
+        graph = rustworkx.PyGraph()
+        graph.add_nodes_from(["a", "b", "c", "d"])
+        graph.add_edges_from([(0, 1, 1.25), (1, 0, 3.0), (1, 3, -1.0), (3, 3, 1e-300)])
+        graph.remove_node(2)
+        graph.write_mtx(self.path, weight_fn=lambda weight: weight)
+        new_graph = rustworkx.PyGraph.read_mtx(self.path)
+        self.assertEqual([0, 1, 2, 3], new_graph.node_indices())
+        self.assertEqual(
+            [(1, 0, 1.25), (1, 0, 3.0), (3, 1, -1.0), (3, 3, 1e-300)],
+            list(new_graph.weighted_edge_list()),
+        )
+
+    def test_write_invalid_weight(self):
+        graph = rustworkx.generators.path_graph(2)
+        with self.assertRaises(TypeError):
+            graph.write_mtx(self.path, weight_fn=lambda _: "not a float")
+
+    def test_invalid_path(self):
+        with self.assertRaises(FileNotFoundError):
+            rustworkx.PyGraph.read_mtx(os.path.join(self.tempdir.name, "missing.mtx"))
@@ -204,3 +204,97 @@ class TestNodeLinkJSON(unittest.TestCase):
         self.assertEqual(new.nodes(), graph.nodes())
         self.assertEqual(new.weighted_edge_list(), graph.weighted_edge_list())
         self.assertEqual(new.attrs, {"label": graph.attrs})
+
+    def test_write_node_link_json_method(self):
+        graph = rustworkx.PyGraph(multigraph=False, attrs={"name": "test"})
+        graph.add_nodes_from([{"label": "a"}, "b"])
+        graph.add_edge(0, 1, {"label": "ab"})
+        res = graph.write_node_link_json()
+        expected = {
+            "attrs": {"name": "test"},
+            "directed": False,
+            "links": [{"data": {"label": "ab"}, "id": 0, "source": 0, "target": 1}],
+            "multigraph": False,
+            "nodes": [{"data": {"label": "a"}, "id": 0}, {"data": "b", "id": 1}],
+        }
+        self.assertEqual(json.loads(res), expected)
+
+    def test_node_link_json_method_round_trip_payloads(self):
+        payloads = [None, True, -3, 2**64 - 1, 1.5, 2.0, "a", [1, "b", None], {"x": {"y": [0.5]}}]
+        graph = rustworkx.PyGraph(attrs={"name": "test", "version": 2})
+        graph.add_nodes_from(payloads)
+        graph.add_edges_from([(i, i + 1, payload) for i, payload in enumerate(payloads[:-1])])
+        new = rustworkx.PyGraph.read_node_link_json(graph.write_node_link_json())
+        self.assertEqual(new.attrs, graph.attrs)
+        old_payloads = graph.nodes() + graph.edges()
+        for old_payload, new_payload in zip(old_payloads, new.nodes() + new.edges()):
+            self.assertEqual(new_payload, old_payload)
+            self.assertIs(type(new_payload), type(old_payload))
+
+    def test_write_node_link_json_method_lossy_payload(self):
+        for payload in [(1, 2), {1: "a"}, float("nan"), 2**64, object(), {"a": {"b": (1,)}}]:
+            with self.subTest(payload=payload):
+                graph = rustworkx.PyGraph()
+                graph.add_node(payload)
+                with self.assertRaises(TypeError):
+                    graph.write_node_link_json()
+                graph = rustworkx.generators.path_graph(2)
+                graph.update_edge(0, 1, payload)
+                with self.assertRaises(TypeError):
+                    graph.write_node_link_json()
+                with self.assertRaises(TypeError):
+                    rustworkx.PyGraph(attrs=payload).write_node_link_json()
+
+    def test_write_node_link_json_method_attr_fns(self):
+        graph = rustworkx.generators.path_graph(2)
+        res = graph.write_node_link_json(
+            node_attr_fn=lambda _: {"kind": "node"}, edge_attr_fn=lambda _: {"kind": "edge"}
+        )
+        parsed = json.loads(res)
+        self.assertEqual([node["data"] for node in parsed["nodes"]], [{"kind": "node"}] * 2)
+        self.assertEqual([link["data"] for link in parsed["links"]], [{"kind": "edge"}])
+
+    def test_read_node_link_json_method_round_trip_with_holes(self):
+        graph = rustworkx.PyGraph(multigraph=False, attrs={"name": "test"})
+        graph.add_nodes_from([{"n": str(i)} for i in range(5)])
+        graph.add_edges_from([(0, 1, {"e": "0"}), (1, 2, {"e": "1"}), (2, 3, {"e": "2"})])
+        graph.add_edges_from([(3, 4, {"e": "3"}), (4, 0, {"e": "4"})])
+        graph.remove_node(1)
+        graph.remove_edge_from_index(3)
+        new = rustworkx.PyGraph.read_node_link_json(graph.write_node_link_json())
+        self.assertFalse(new.multigraph)
+        self.assertEqual(new.attrs, {"name": "test"})
+        self.assertEqual(new.node_indices(), [0, 2, 3, 4])
+        self.assertEqual(new.edge_indices(), [2, 4])
+        self.assertEqual(new.nodes(), graph.nodes())
+        self.assertEqual(dict(new.edge_index_map()), dict(graph.edge_index_map()))
+        self.assertEqual(new.add_node(None), 1)
+
+    def test_read_node_link_json_method_from_file(self):
+        graph = rustworkx.generators.path_graph(3)
+        graph.remove_node(0)
+        with tempfile.NamedTemporaryFile() as fd:
+            graph.write_node_link_json(path=fd.name)
+            new = rustworkx.PyGraph.read_node_link_json(fd.name)
+        self.assertEqual(new.node_indices(), [1, 2])
+        self.assertEqual(new.edge_index_map(), {1: (1, 2, None)})
+
+    def test_read_node_link_json_method_directed(self):
+        graph = rustworkx.generators.directed_path_graph(2)
+        with self.assertRaises(rustworkx.JSONDeserializationError):
+            rustworkx.PyGraph.read_node_link_json(rustworkx.node_link_json(graph))
+
+    def test_read_node_link_json_method_invalid(self):
+        data = {
+            "directed": False,
+            "multigraph": True,
+            "attrs": None,
+            "nodes": [{"id": 0, "data": None}, {"id": 0, "data": None}],
+            "links": [],
+        }
+        with self.assertRaises(rustworkx.JSONDeserializationError):
+            rustworkx.PyGraph.read_node_link_json(json.dumps(data))
+        data["nodes"] = [{"id": 0, "data": None}]
+        data["links"] = [{"source": 0, "target": 3, "id": 0, "data": None}]
+        with self.assertRaises(rustworkx.JSONDeserializationError):
+            rustworkx.PyGraph.read_node_link_json(json.dumps(data))
@@ -10,7 +10,11 @@
 # License for the specific language governing permissions and limitations
 # under the License.
 
+import gc
 import unittest
+import weakref
+
+import numpy as np
 
 import rustworkx
 
@@ -30,11 +34,71 @@ class TestNodes(unittest.TestCase):
         graph.add_node("b")
         self.assertEqual([0, 1], graph.node_indices())
 
+    def test_find_nodes_by_weight(self):
+        graph = rustworkx.PyGraph()
+        graph.add_nodes_from(["a", "b", "a", "c", "a"])
+        graph.remove_node(2)
+        self.assertEqual([0, 4], graph.find_nodes_by_weight("a"))
+        self.assertEqual([1], graph.find_nodes_by_weight("b"))
+        self.assertEqual(0, graph.find_node_by_weight("a"))
+
+    def test_find_nodes_by_weight_no_match(self):
+        graph = rustworkx.PyGraph()
+        graph.add_nodes_from(list(range(10)))
+        self.assertEqual([], graph.find_nodes_by_weight(42))
+
+    def test_iter_node_indices(self):
+        graph = rustworkx.PyGraph()
+        graph.add_nodes_from(range(5))
+        graph.remove_nodes_from([0, 3])
+        self.assertEqual([1, 2, 4], list(graph.iter_node_indices()))
+        self.assertEqual(list(graph.node_indices()), list(graph.iter_node_indices()))
+
+    def test_iter_node_indices_lazy(self):
+        graph = rustworkx.PyGraph()
+        graph.add_nodes_from(range(3))
+        it = graph.iter_node_indices()
+        self.assertIs(it, iter(it))
+        self.assertEqual(0, next(it))
+        graph.remove_node(1)
+        self.assertEqual([2], list(it))
+        self.assertEqual([], list(it))
+        self.assertIsInstance(it, rustworkx.LazyNodeIndices)
+
+    def test_iter_node_indices_reference_cycle(self):
+        class Payload:
+            pass
+
+        graph = rustworkx.PyGraph()
+        payload = Payload()
+        payload.indices = graph.iter_node_indices()
+        graph.add_node(payload)
+        payload_ref = weakref.ref(payload)
+        del graph, payload
+        gc.collect()
+        self.assertIsNone(payload_ref())
+
+    def test_nodes_data(self):
+        graph = rustworkx.PyGraph()
+        graph.add_nodes_from(["a", "b", "c"])
+        graph.remove_node(1)
+        self.assertEqual(["a", "c"], graph.nodes(data=False))
+        self.assertEqual([(0, "a"), (2, "c")], graph.nodes(data=True))
+        self.assertEqual([], rustworkx.PyGraph().nodes(data=True))
+
     def test_no_nodes(self):
         graph = rustworkx.PyGraph()
         self.assertEqual([], graph.nodes())
         self.assertEqual([], graph.node_indexes())
         self.assertEqual([], graph.node_indices())
+        self.assertEqual([], graph.node_items())
+
+    def test_node_items(self):
+        graph = rustworkx.PyGraph()
+        graph.add_nodes_from(["a", "b", "c"])
+        graph.remove_node(1)
+        self.assertEqual([(0, "a"), (2, "c")], graph.node_items())
+        self.assertEqual({0: "a", 2: "c"}, dict(graph.node_items()))
 
     def test_remove_node(self):
         graph = rustworkx.PyGraph()
@@ -92,6 +156,30 @@ class TestNodes(unittest.TestCase):
         self.assertEqual(["a"], res)
         self.assertEqual([0], graph.node_indexes())
 
+    def test_remove_nodes_from_mask(self):
+        graph = rustworkx.PyGraph()
+        graph.add_nodes_from([0.5, 0.1, 0.9, 0.2])
+        graph.add_edges_from_no_data([(0, 1), (1, 2), (2, 3)])
+        scores = np.array(graph.nodes())
+        graph.remove_nodes_from_mask(scores < 0.3)
+        self.assertEqual([0.5, 0.9], graph.nodes())
+        self.assertEqual([0, 2], graph.node_indexes())
+        self.assertEqual([], graph.edge_list())
+
+    def test_remove_nodes_from_mask_removed_node(self):
+        graph = rustworkx.generators.path_graph(4)
+        graph.remove_node(1)
+        graph.remove_nodes_from_mask(np.array([False, True, True, False]))
+        self.assertEqual([0, 3], graph.node_indexes())
+        graph.remove_nodes_from_mask(np.zeros(4, dtype=bool))
+        self.assertEqual([0, 3], graph.node_indexes())
+
+    def test_remove_nodes_from_mask_wrong_length(self):
+        graph = rustworkx.generators.path_graph(4)
+        with self.assertRaises(ValueError):
+            graph.remove_nodes_from_mask(np.array([True, True, True]))
+        self.assertEqual(4, len(graph))
+
     def test_get_node_data(self):
         graph = rustworkx.PyGraph()
         graph.add_node("a")
@@ -104,6 +192,22 @@ class TestNodes(unittest.TestCase):
         graph.add_node("b")
         self.assertRaises(IndexError, graph.get_node_data, 42)
 
+    def test_get_nodes_data(self):
+        graph = rustworkx.PyGraph()
+        graph.add_nodes_from(["a", "b", "c", "d"])
+        graph.remove_node(1)
+        self.assertEqual(["d", "a", "d"], graph.get_nodes_data([3, 0, 3]))
+        self.assertEqual(["c"], graph.get_nodes_data(node for node in [2]))
+        self.assertEqual([], graph.get_nodes_data([]))
+
+    def test_get_nodes_data_missing(self):
+        graph = rustworkx.PyGraph()
+        graph.add_nodes_from(["a", "b", "c"])
+        graph.remove_node(1)
+        with self.assertRaisesRegex(IndexError, "No node found for index: 1"):
+            graph.get_nodes_data([0, 1, 42])
+        self.assertEqual(["a", "c"], graph.get_nodes_data([0, 1, 2, 42], ignore_missing=True))
+
     def test_pygraph_length(self):
         graph = rustworkx.PyGraph()
         node_a = graph.add_node("a")
@@ -122,6 +226,30 @@ class TestNodes(unittest.TestCase):
         graph = rustworkx.PyGraph()
         self.assertEqual(0, len(graph))
 
+    def test_pygraph_repr(self):
+        graph = rustworkx.PyGraph(multigraph=False)
+        graph.add_nodes_from(range(3))
+        graph.add_edges_from_no_data([(0, 1), (1, 2)])
+        self.assertEqual("PyGraph(multigraph=False, nodes=3, edges=2)", repr(graph))
+        self.assertEqual("PyGraph(multigraph=True, nodes=0, edges=0)", repr(rustworkx.PyGraph()))
+
+    def test_pygraph_repr_skips_payloads(self):
+        class BadRepr:
+            def __repr__(self):
+                raise RuntimeError("payload repr called")
+
+        graph = rustworkx.PyGraph()
+        graph.add_node(BadRepr())
+        self.assertEqual("PyGraph(multigraph=True, nodes=1, edges=0)", repr(graph))
+
+    def test_pygraph_repr_subclass(self):
+        class MyGraph(rustworkx.PyGraph):
+            pass
+
+        graph = MyGraph()
+        graph.add_node(None)
+        self.assertEqual("MyGraph(multigraph=True, nodes=1, edges=0)", repr(graph))
+
     def test_pygraph_num_nodes_empty(self):
         graph = rustworkx.PyGraph()
         self.assertEqual(0, graph.num_nodes())
@@ -141,6 +269,44 @@ class TestNodes(unittest.TestCase):
         self.assertEqual(len(res), 100)
         self.assertEqual(res, nodes)
 
+    def test_add_nodes_from_range(self):
+        graph = rustworkx.PyGraph()
+        graph.add_node("a")
+        res = graph.add_nodes_from(range(1000))
+        self.assertEqual(list(range(1, 1001)), res)
+        self.assertEqual(list(range(1000)), graph.nodes()[1:])
+
+    def test_add_nodes_from_count_hint(self):
+        for count_hint in [0, 10, 100, 1000]:
+            with self.subTest(count_hint=count_hint):
+                graph = rustworkx.PyGraph()
+                res = graph.add_nodes_from((i for i in range(100)), count_hint=count_hint)
+                self.assertEqual(list(range(100)), res)
+                self.assertEqual(list(range(100)), graph.nodes())
+
+    def test_add_nodes_from_huge_count_hint(self):
+        class LyingLength:
+            def __len__(self):
+                return 2**62
+
+            def __iter__(self):
+                return iter(["a", "b"])
+
+        graph = rustworkx.PyGraph()
+        self.assertEqual([0, 1], graph.add_nodes_from(LyingLength()))
+        res = graph.add_nodes_from((i for i in range(3)), count_hint=2**62)
+        self.assertEqual([2, 3, 4], res)
+        self.assertEqual(["a", "b", 0, 1, 2], graph.nodes())
+
+    def test_add_nodes_from_reuses_removed_indices(self):
+        graph = rustworkx.PyGraph()
+        graph.add_nodes_from(range(5))
+        graph.remove_nodes_from([1, 3])
+        res = graph.add_nodes_from(["x", "y", "z"])
+        self.assertEqual(6, len(graph))
+        self.assertEqual(3, len(set(res)))
+        self.assertEqual(["x", "y", "z"], [graph[node] for node in res])
+
     def test_add_node_from_empty(self):
         graph = rustworkx.PyGraph()
         res = graph.add_nodes_from([])
@@ -177,6 +343,33 @@ class TestNodes(unittest.TestCase):
         with self.assertRaises(IndexError):
             graph[42] = "Oh so cool"
 
+    def test_set_node_data_from(self):
+        graph = rustworkx.PyGraph()
+        graph.add_nodes_from(range(4))
+        graph.remove_node(1)
+        graph.set_node_data_from((node, str(node)) for node in graph.node_indices())
+        self.assertEqual(["0", "2", "3"], graph.nodes())
+
+    def test_set_node_data_from_repeated_index(self):
+        graph = rustworkx.PyGraph()
+        graph.add_node("a")
+        graph.set_node_data_from([(0, "b"), (0, "c")])
+        self.assertEqual(["c"], graph.nodes())
+
+    def test_set_node_data_from_missing(self):
+        graph = rustworkx.PyGraph()
+        graph.add_nodes_from(["a", "b"])
+        graph.remove_node(0)
+        with self.assertRaisesRegex(IndexError, r"\[0, 42\]"):
+            graph.set_node_data_from([(0, "x"), (1, "y"), (42, "z")])
+        self.assertEqual(["y"], graph.nodes())
+
+    def test_set_node_data_from_ignore_missing(self):
+        graph = rustworkx.PyGraph()
+        graph.add_nodes_from(["a", "b"])
+        graph.set_node_data_from([(42, "z"), (1, "y")], ignore_missing=True)
+        self.assertEqual(["a", "y"], graph.nodes())
+
     def test_remove_node_delitem(self):
         graph = rustworkx.PyGraph()
         node_a = graph.add_node("a")
@@ -205,3 +398,105 @@ class TestNodes(unittest.TestCase):
         node_a = graph.add_node("a")
         self.assertTrue(graph.has_node(node_a))
         self.assertFalse(graph.has_node(node_a + 1))
+
+    def test_contains(self):
+        graph = rustworkx.PyGraph()
+        node_a = graph.add_node("a")
+        node_b = graph.add_node("b")
+        graph.remove_node(node_a)
+        self.assertNotIn(node_a, graph)
+        self.assertIn(node_b, graph)
+        self.assertNotIn(node_b + 1, graph)
+
+    def test_contains_non_index(self):
+        graph = rustworkx.PyGraph()
+        graph.add_node("a")
+        for operand in [-1, "a", 0.0, None, 2**32, 2**64]:
+            with self.subTest(operand=operand):
+                self.assertNotIn(operand, graph)
+
+    def test_iter(self):
+        graph = rustworkx.PyGraph()
+        graph.add_nodes_from(list(range(5)))
+        graph.remove_node(2)
+        self.assertEqual([0, 1, 3, 4], list(graph))
+        self.assertEqual(len(list(graph)), len(graph))
+        self.assertEqual(list(graph), list(graph.node_indices()))
+
+    def test_iter_empty(self):
+        graph = rustworkx.PyGraph()
+        self.assertEqual([], list(graph))
+
+    def test_remove_node_retain_edges_path(self):
+        graph = rustworkx.PyGraph()
+        graph.add_nodes_from(["a", "b", "c"])
+        graph.add_edges_from([(0, 1, "ab"), (1, 2, "bc")])
+        graph.remove_node_retain_edges(1)
+        self.assertEqual([0, 2], graph.node_indices())
+        self.assertEqual([(0, 2, "ab")], graph.weighted_edge_list())
+
+    def test_remove_node_retain_edges_all_pairs(self):
+        graph = rustworkx.generators.star_graph(4)
+        graph.remove_node_retain_edges(0)
+        self.assertEqual(
+            {(1, 2), (1, 3), (2, 3)}, {tuple(sorted(edge)) for edge in graph.edge_list()}
+        )
+
+    def test_remove_node_retain_edges_ignores_self_loops(self):
+        graph = rustworkx.PyGraph()
+        graph.add_nodes_from(["a", "b", "c"])
+        graph.add_edges_from([(0, 1, "ab"), (1, 1, "bb"), (1, 2, "bc")])
+        graph.remove_node_retain_edges(1)
+        self.assertEqual([(0, 2, "ab")], graph.weighted_edge_list())
+
+    def test_remove_node_retain_edges_multigraph(self):
+        graph = rustworkx.PyGraph()
+        graph.add_nodes_from(["a", "b", "c"])
+        graph.add_edges_from([(0, 1, 1), (1, 2, 2), (0, 2, 3)])
+        graph.remove_node_retain_edges(1)
+        self.assertEqual([(0, 2, 1), (0, 2, 3)], sorted(graph.weighted_edge_list()))
+
+    def test_remove_node_retain_edges_not_multigraph(self):
+        graph = rustworkx.PyGraph(multigraph=False)
+        graph.add_nodes_from(["a", "b", "c"])
+        graph.add_edges_from([(0, 1, 1), (1, 2, 2), (0, 2, 3)])
+        graph.remove_node_retain_edges(1)
+        self.assertEqual([(0, 2, 3)], graph.weighted_edge_list())
+
+    def test_remove_node_retain_edges_weight_combo_fn(self):
+        graph = rustworkx.PyGraph(multigraph=False)
+        graph.add_nodes_from(["a", "b", "c"])
+        graph.add_edges_from([(0, 1, 1), (1, 2, 2), (0, 2, 3)])
+        graph.remove_node_retain_edges(1, weight_combo_fn=lambda a, b: a + b)
+        self.assertEqual([(0, 2, 4)], graph.weighted_edge_list())
+
+    def test_remove_node_retain_edges_invalid_index(self):
+        graph = rustworkx.generators.path_graph(3)
+        graph.remove_node_retain_edges(7)
+        self.assertEqual([(0, 1), (1, 2)], graph.edge_list())
+
+    def test_compact(self):
+        graph = rustworkx.PyGraph(multigraph=False, attrs="attrs")
+        graph.add_nodes_from(["a", "b", "c", "d", "e"])
+        graph.add_edges_from([(0, 1, "ab"), (1, 2, "bc"), (2, 4, "ce"), (4, 0, "ea")])
+        graph.remove_node(1)
+        graph.remove_node(3)
+        node_map = graph.compact()
+        self.assertEqual({0: 0, 2: 1, 4: 2}, dict(node_map))
+        self.assertEqual([0, 1, 2], graph.node_indices())
+        self.assertEqual(["a", "c", "e"], graph.nodes())
+        self.assertEqual([(1, 2, "ce"), (2, 0, "ea")], list(graph.weighted_edge_list()))
+        self.assertEqual([0, 1], graph.edge_indices())
+        self.assertFalse(graph.multigraph)
+        self.assertEqual("attrs", graph.attrs)
+        self.assertEqual(3, graph.add_node("f"))
+
+    def test_compact_no_holes(self):
+        graph = rustworkx.generators.path_graph(3)
+        node_map = graph.compact()
+        self.assertEqual({0: 0, 1: 1, 2: 2}, dict(node_map))
+        self.assertEqual([(0, 1), (1, 2)], list(graph.edge_list()))
+
+    def test_compact_empty(self):
+        graph = rustworkx.PyGraph()
+        self.assertEqual({}, dict(graph.compact()))
@@ -0,0 +1,127 @@
+# Licensed under the Apache License, Version 2.0 (the "License"); you may
+# not use this file except in compliance with the License. You may obtain
+# a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+# WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+# License for the specific language governing permissions and limitations
+# under the License.
+
+import os
+import tempfile
+import unittest
+
+import rustworkx
+
+
+class TestPajekMethods(unittest.TestCase):
+    def setUp(self):
+        self.tempdir = tempfile.TemporaryDirectory()
+        self.path = os.path.join(self.tempdir.name, "graph.net")
+
+    def tearDown(self):
+        self.tempdir.cleanup()
+
+    def write(self, contents):
+        with open(self.path, "w") as fd:
+            fd.write(contents)
+
+    def test_read(self):
+        self.write(
+            """% A comment
+*Network test
+*Vertices 4
+1 "Jane Doe" 0.1 0.2 0.5
+2 b
+3 "c"
+*Edges
+1 2 1.5
+2 3
+*Arcs
+4 1 2
+"""
+        )
+        graph = rustworkx.PyGraph.read_pajek(self.path)
+        self.assertEqual(graph.nodes(), ["Jane Doe", "b", "c", "4"])
+        self.assertEqual(graph.weighted_edge_list(), [(0, 1, 1.5), (1, 2, None), (3, 0, 2.0)])
+
+    def test_read_case_insensitive_sections(self):
+        self.write("*vertices 2\n*edges\n1 2\n")
+        graph = rustworkx.PyGraph.read_pajek(self.path)
+        self.assertEqual(graph.nodes(), ["1", "2"])
+        self.assertEqual(graph.edge_list(), [(0, 1)])
+
+    def test_read_invalid_vertex(self):
+        for contents in ["*Vertices 2\n*Edges\n1 3\n", "*Vertices 2\n*Edges\n0 1\n", "1 2\n"]:
+            with self.subTest(contents=contents):
+                self.write(contents)
+                with self.assertRaises(ValueError):
+                    rustworkx.PyGraph.read_pajek(self.path)
+
+    def test_read_unterminated_label(self):
+        self.write('*Vertices 1\n1 "a b\n')
+        with self.assertRaisesRegex(ValueError, "line 2"):
+            rustworkx.PyGraph.read_pajek(self.path)
+
+    def test_read_invalid_weight(self):
+        self.write("*Vertices 2\n*Edges\n1 2 heavy\n")
+        with self.assertRaisesRegex(ValueError, "line 3"):
+            rustworkx.PyGraph.read_pajek(self.path)
+
+    def test_read_huge_vertex_count(self):
+        self.write("*Network huge\n*Vertices 99999999999\n")
+        with self.assertRaisesRegex(ValueError, "line 2"):
+            rustworkx.PyGraph.read_pajek(self.path)
+
+    def test_read_unsupported_section(self):
+        self.write("*Vertices 2\n*Matrix\n0 1\n1 0\n")
+        with self.assertRaises(ValueError):
+            rustworkx.PyGraph.read_pajek(self.path)
+
+    def test_invalid_path(self):
+        with self.assertRaises(OSError):
+            rustworkx.PyGraph.read_pajek(os.path.join(self.tempdir.name, "missing.net"))
+
+    def test_round_trip(self):
+        graph = rustworkx.PyGraph()
+        graph.add_nodes_from(["Jane Doe", "b", "c"])
+        graph.add_edges_from([(0, 1, 1.5), (1, 2, None), (2, 0, 3)])
+        graph.write_pajek(self.path)
+        new_graph = rustworkx.PyGraph.read_pajek(self.path)
+        self.assertEqual(new_graph.nodes(), ["Jane Doe", "b", "c"])
+        self.assertEqual(new_graph.weighted_edge_list(), [(0, 1, 1.5), (1, 2, None), (2, 0, 3.0)])
+
+    def test_write_removed_nodes(self):
+        graph = rustworkx.PyGraph()
+        graph.add_nodes_from(["a", "b", "c"])
+        graph.add_edge(0, 2, None)
+        graph.remove_node(1)
+        graph.write_pajek(self.path)
+        with open(self.path) as fd:
+            self.assertEqual(fd.read(), '*Vertices 2\n1 "a"\n2 "c"\n*Edges\n1 2\n')
+
+    def test_write_label_fn(self):
+        graph = rustworkx.PyGraph()
+        graph.add_nodes_from([{"name": "a"}, {"name": "b"}])
+        graph.add_edge(0, 1, "not a weight")
+        graph.write_pajek(self.path, label_fn=lambda node: node["name"])
+        new_graph = rustworkx.PyGraph.read_pajek(self.path)
+        self.assertEqual(new_graph.nodes(), ["a", "b"])
+        self.assertEqual(new_graph.weighted_edge_list(), [(0, 1, None)])
+
+    def test_write_default_label(self):
+        graph = rustworkx.PyGraph()
+        graph.add_nodes_from([1, None])
+        graph.write_pajek(self.path)
+        self.assertEqual(rustworkx.PyGraph.read_pajek(self.path).nodes(), ["1", "None"])
+
+    def test_write_invalid_label(self):
+        graph = rustworkx.PyGraph()
+        graph.add_node('a "quoted" label')
+        with self.assertRaises(ValueError):
+            graph.write_pajek(self.path)
+        with self.assertRaises(TypeError):
+            graph.write_pajek(self.path, label_fn=lambda node: 1)
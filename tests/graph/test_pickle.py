@@ -39,3 +39,88 @@ class TestPickleGraph(unittest.TestCase):
         self.assertEqual([1, 2, 3], gprime.node_indices())
         self.assertEqual(["B", "C", "D"], gprime.nodes())
         self.assertEqual({1: (1, 2, "B -> C"), 3: (3, 1, "D -> B")}, dict(gprime.edge_index_map()))
+
+    def test_graph_with_edge_holes(self):
+        g = rx.PyGraph(attrs={"name": "holes"})
+        g.add_nodes_from(list(range(6)))
+        g.add_edges_from([(0, 1, "a"), (1, 2, "b"), (2, 3, "c"), (3, 4, "d"), (4, 5, "e")])
+        g.add_edge(5, 5, "loop")
+        g.remove_edge_from_index(1)
+        g.remove_node(4)
+        g.remove_node(5)
+
+        gprime = pickle.loads(pickle.dumps(g))
+        self.assertEqual([0, 1, 2, 3], gprime.node_indices())
+        self.assertEqual({0: (0, 1, "a"), 2: (2, 3, "c")}, dict(gprime.edge_index_map()))
+        self.assertEqual({"name": "holes"}, gprime.attrs)
+        self.assertEqual(4, gprime.add_node("new"))
+        self.assertEqual(1, gprime.add_edge(0, 4, "new"))
+
+    def test_large_graph(self):
+        g = rx.undirected_gnm_random_graph(1000, 5000, seed=42)
+        for node in range(0, 1000, 7):
+            g.remove_node(node)
+        gprime = pickle.loads(pickle.dumps(g))
+        self.assertEqual(g.node_indices(), gprime.node_indices())
+        self.assertEqual(dict(g.edge_index_map()), dict(gprime.edge_index_map()))
+
+    def test_edge_weight_count_mismatch(self):
+        g = rx.PyGraph()
+        g.add_nodes_from(["A", "B", "C"])
+        g.add_edges_from([(0, 1, "A - B"), (1, 2, "B - C")])
+        g.remove_edge(0, 1)
+        for edge_weights in [[], ["B - C", "extra"]]:
+            with self.subTest(edge_weights=edge_weights):
+                state = g.__getstate__()
+                state["edge_weights"] = edge_weights
+                with self.assertRaises(ValueError):
+                    rx.PyGraph().__setstate__(state)
+
+    def test_legacy_state(self):
+        state = {
+            "nodes": [(1, "B"), (2, "C")],
+            "edges": [None, (1, 2, "B -> C")],
+            "nodes_removed": True,
+        }
+        g = rx.PyGraph()
+        g.__setstate__(state)
+        self.assertEqual([1, 2], g.node_indices())
+        self.assertEqual({1: (1, 2, "B -> C")}, dict(g.edge_index_map()))
+
+
+class PickleSubclassGraph(rx.PyGraph):
+    def __init__(self, *args, **kwargs):
+        # The graph itself is built by PyGraph.__new__ from the same arguments
+        self.label = "default"
+
+    def node_payloads(self):
+        return self.nodes()
+
+
+class TestPickleGraphSubclass(unittest.TestCase):
+    def test_subclass_round_trip(self):
+        g = PickleSubclassGraph(multigraph=False, attrs={"a": 1})
+        g.add_nodes_from(["A", "B", "C"])
+        g.add_edges_from([(0, 1, "A - B"), (1, 2, "B - C")])
+        g.remove_node(0)
+        g.label = "custom"
+
+        gprime = pickle.loads(pickle.dumps(g))
+        self.assertIs(type(gprime), PickleSubclassGraph)
+        self.assertEqual("custom", gprime.label)
+        self.assertEqual(["B", "C"], gprime.node_payloads())
+        self.assertEqual([(1, 2, "B - C")], gprime.weighted_edge_list())
+        self.assertEqual({"a": 1}, gprime.attrs)
+        self.assertFalse(gprime.multigraph)
+
+    def test_subclass_setstate_legacy_state(self):
+        state = {
+            "nodes": [(0, "A")],
+            "edges": [],
+            "nodes_removed": False,
+            "__dict__": {"label": "legacy"},
+        }
+        g = PickleSubclassGraph.__new__(PickleSubclassGraph)
+        g.__setstate__(state)
+        self.assertEqual("legacy", g.label)
+        self.assertEqual(["A"], g.nodes())
@@ -0,0 +1,62 @@
+# Licensed under the Apache License, Version 2.0 (the "License"); you may
+# not use this file except in compliance with the License. You may obtain
+# a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+# WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+# License for the specific language governing permissions and limitations
+# under the License.
+
+import unittest
+
+import rustworkx
+
+
+class TestRandomChoice(unittest.TestCase):
+    def setUp(self):
+        self.graph = rustworkx.generators.path_graph(10)
+
+    def test_random_node_seed(self):
+        node = self.graph.random_node(seed=42)
+        self.assertIn(node, self.graph.node_indices())
+        self.assertEqual(node, self.graph.random_node(seed=42))
+
+    def test_random_edge_seed(self):
+        edge = self.graph.random_edge(seed=42)
+        self.assertIn(edge, self.graph.edge_indices())
+        self.assertEqual(edge, self.graph.random_edge(seed=42))
+
+    def test_all_indices_sampled(self):
+        nodes = {self.graph.random_node(seed=seed) for seed in range(500)}
+        self.assertEqual(nodes, set(self.graph.node_indices()))
+        edges = {self.graph.random_edge(seed=seed) for seed in range(500)}
+        self.assertEqual(edges, set(self.graph.edge_indices()))
+
+    def test_only_present_indices(self):
+        self.graph.remove_nodes_from([0, 2, 3, 5, 6, 7, 8])
+        self.graph.add_edge(1, 9, None)
+        nodes = {self.graph.random_node(seed=seed) for seed in range(200)}
+        self.assertEqual(nodes, {1, 4, 9})
+        edges = {self.graph.random_edge(seed=seed) for seed in range(200)}
+        self.assertEqual(edges, set(self.graph.edge_indices()))
+        self.graph.remove_node(1)
+        nodes = {self.graph.random_node(seed=seed) for seed in range(200)}
+        self.assertEqual(nodes, {4, 9})
+
+    def test_without_seed(self):
+        self.assertIn(self.graph.random_node(), self.graph.node_indices())
+        self.assertIn(self.graph.random_edge(), self.graph.edge_indices())
+
+    def test_empty_graph(self):
+        graph = rustworkx.PyGraph()
+        with self.assertRaises(IndexError):
+            graph.random_node()
+        with self.assertRaises(IndexError):
+            graph.random_edge(seed=1)
+        graph.add_node(None)
+        self.assertEqual(0, graph.random_node())
+        with self.assertRaises(IndexError):
+            graph.random_edge()
@@ -0,0 +1,68 @@
+# Licensed under the Apache License, Version 2.0 (the "License"); you may
+# not use this file except in compliance with the License. You may obtain
+# a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+# WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+# License for the specific language governing permissions and limitations
+# under the License.
+
+import unittest
+
+import rustworkx
+
+
+class TestSpanningForestEdges(unittest.TestCase):
+    def assertSpanningForest(self, graph, edges):
+        forest = graph.edge_subgraph(edges, by_index=True)
+        self.assertEqual(len(set(edges)), len(edges))
+        self.assertEqual(len(edges), len(graph) - graph.number_connected_components())
+        self.assertTrue(forest.is_forest())
+        # Every node that has a neighbor is covered by the forest
+        non_isolated = [node for node in graph.node_indices() if graph.neighbors(node)]
+        self.assertEqual(len(forest), len(non_isolated))
+
+    def test_empty_graph(self):
+        self.assertEqual([], rustworkx.PyGraph().spanning_forest_edges())
+
+    def test_path_graph(self):
+        graph = rustworkx.generators.path_graph(4)
+        res = graph.spanning_forest_edges()
+        self.assertIsInstance(res, rustworkx.EdgeIndices)
+        self.assertEqual([0, 1, 2], res)
+
+    def test_breadth_first(self):
+        graph = rustworkx.generators.star_graph(4)
+        graph.add_edge(1, 2, None)
+        self.assertEqual({0, 1, 2}, set(graph.spanning_forest_edges()))
+
+    def test_complete_graph(self):
+        graph = rustworkx.generators.complete_graph(6)
+        edges = graph.spanning_forest_edges()
+        self.assertEqual(5, len(edges))
+        self.assertSpanningForest(graph, edges)
+
+    def test_disconnected(self):
+        graph = rustworkx.generators.cycle_graph(4)
+        graph.add_nodes_from([None] * 4)
+        graph.add_edges_from_no_data([(4, 5), (5, 6), (6, 4)])
+        edges = graph.spanning_forest_edges()
+        self.assertEqual(5, len(edges))
+        self.assertSpanningForest(graph, edges)
+
+    def test_self_loops_and_parallel_edges(self):
+        graph = rustworkx.PyGraph()
+        graph.add_nodes_from([None] * 3)
+        graph.add_edges_from_no_data([(0, 0), (0, 1), (1, 0), (1, 2), (2, 2)])
+        edges = graph.spanning_forest_edges()
+        self.assertEqual(2, len(edges))
+        self.assertSpanningForest(graph, edges)
+
+    def test_removed_nodes(self):
+        graph = rustworkx.generators.grid_graph(4, 4)
+        graph.remove_nodes_from([0, 5, 10])
+        edges = graph.spanning_forest_edges()
+        self.assertSpanningForest(graph, edges)
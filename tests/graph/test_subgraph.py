@@ -12,6 +12,8 @@
 
 import unittest
 
+import numpy as np
+
 import rustworkx
 
 
@@ -49,6 +51,19 @@ class TestSubgraph(unittest.TestCase):
         self.assertEqual([], subgraph.weighted_edge_list())
         self.assertEqual(0, len(subgraph))
 
+    def test_subgraph_validate(self):
+        graph = rustworkx.PyGraph()
+        graph.add_nodes_from(["a", "b", "c", "d"])
+        graph.add_edges_from([(0, 1, 1), (0, 2, 2), (0, 3, 3), (1, 3, 4)])
+        graph.remove_node(2)
+        with self.assertRaisesRegex(IndexError, r"\[2, 42\]"):
+            graph.subgraph([0, 42, 1, 2, 42], validate=True)
+        with self.assertRaises(IndexError):
+            graph.subgraph([0, 2], preserve_indices=True, validate=True)
+        subgraph = graph.subgraph([0, 1, 3], validate=True)
+        self.assertEqual([(0, 1, 1), (0, 2, 3), (1, 2, 4)], subgraph.weighted_edge_list())
+        self.assertEqual(0, len(graph.subgraph([], validate=True)))
+
     def test_subgraph_pass_by_reference(self):
         graph = rustworkx.PyGraph()
         graph.add_node({"a": 0})
@@ -75,6 +90,31 @@ class TestSubgraph(unittest.TestCase):
         graph[0] = 4
         self.assertEqual(subgraph[0]["a"], 0)
 
+    def test_subgraph_preserve_indices(self):
+        graph = rustworkx.PyGraph(attrs="My attribute")
+        graph.add_nodes_from(["a", "b", "c", "d", "e", "f"])
+        graph.add_edges_from([(0, 1, 1), (1, 3, 2), (3, 5, 3), (5, 1, 4), (2, 4, 5)])
+        graph.remove_node(0)
+        subgraph = graph.subgraph([5, 1, 3, 0, 42, 3], preserve_indices=True)
+        self.assertEqual([1, 3, 5], subgraph.node_indices())
+        for node in subgraph.node_indices():
+            self.assertIs(graph[node], subgraph[node])
+        self.assertEqual([(1, 3, 2), (3, 5, 3), (5, 1, 4)], subgraph.weighted_edge_list())
+        self.assertIsNone(subgraph.attrs)
+        # New nodes fill the holes in the index space
+        self.assertIn(subgraph.add_node("g"), {0, 2, 4})
+
+    def test_subgraph_preserve_indices_and_attrs(self):
+        graph = rustworkx.PyGraph(multigraph=False, attrs="My attribute")
+        graph.add_nodes_from(["a", "b", "c"])
+        graph.add_edges_from([(0, 1, 1), (1, 2, 2)])
+        subgraph = graph.subgraph([0, 1, 2], preserve_attrs=True, preserve_indices=True)
+        self.assertEqual([0, 1, 2], subgraph.node_indices())
+        self.assertEqual([(0, 1, 1), (1, 2, 2)], subgraph.weighted_edge_list())
+        self.assertEqual("My attribute", subgraph.attrs)
+        self.assertFalse(subgraph.multigraph)
+        self.assertEqual(0, len(graph.subgraph([], preserve_indices=True)))
+
     def test_edge_subgraph(self):
         graph = rustworkx.PyGraph()
         graph.add_node("a")
@@ -103,6 +143,31 @@ class TestSubgraph(unittest.TestCase):
         self.assertEqual([0, 1, 2], subgraph.nodes())
         self.assertEqual([(0, 1, 2), (0, 1, 3), (1, 2, 4)], subgraph.weighted_edge_list())
 
+    def test_edge_subgraph_by_index(self):
+        graph = rustworkx.PyGraph()
+        graph.add_nodes_from(list(range(4)))
+        graph.extend_from_weighted_edge_list(
+            [
+                (0, 1, 2),
+                (0, 1, 3),
+                (0, 2, 2),
+                (1, 2, 4),
+                (0, 3, 5),
+                (2, 3, 6),
+            ]
+        )
+        subgraph = graph.edge_subgraph([1, 3], by_index=True)
+        self.assertEqual([0, 1, 2], subgraph.nodes())
+        self.assertEqual([1, 3], subgraph.edge_indices())
+        self.assertEqual([(0, 1, 3), (1, 2, 4)], subgraph.weighted_edge_list())
+
+    def test_edge_subgraph_by_index_missing_edge(self):
+        graph = rustworkx.generators.path_graph(4)
+        graph.remove_edge_from_index(1)
+        subgraph = graph.edge_subgraph([1, 2, 7], by_index=True)
+        self.assertEqual([2, 3], subgraph.node_indices())
+        self.assertEqual([(2, 3)], subgraph.edge_list())
+
     def test_edge_subgraph_empty_list(self):
         graph = rustworkx.PyGraph()
         graph.add_nodes_from(list(range(4)))
@@ -137,6 +202,72 @@ class TestSubgraph(unittest.TestCase):
         self.assertEqual([0, 1, 2], subgraph.nodes())
         self.assertEqual([(0, 1, 2), (0, 1, 3), (1, 2, 4)], subgraph.weighted_edge_list())
 
+    def test_edge_subgraph_with_nodemap(self):
+        graph = rustworkx.PyGraph(attrs="My attribute")
+        graph.add_nodes_from(["a", "b", "c", "d", "e"])
+        graph.extend_from_weighted_edge_list(
+            [
+                (0, 1, 2),
+                (3, 4, 3),
+                (1, 0, 4),
+                (1, 2, 5),
+                (2, 3, 6),
+            ]
+        )
+        graph.remove_node(2)
+        # (1, 3) isn't an edge and 42 isn't a node in graph
+        subgraph, node_map = graph.edge_subgraph_with_nodemap(
+            [(4, 3), (1, 0), (0, 1), (1, 3), (42, 0)]
+        )
+        self.assertEqual(["a", "b", "d", "e"], subgraph.nodes())
+        self.assertEqual(dict(node_map), {0: 0, 1: 1, 2: 3, 3: 4})
+        self.assertEqual([(0, 1, 2), (2, 3, 3), (1, 0, 4)], subgraph.weighted_edge_list())
+        self.assertIsNone(subgraph.attrs)
+        self.assertTrue(subgraph.multigraph)
+
+    def test_edge_subgraph_with_nodemap_self_loop(self):
+        graph = rustworkx.PyGraph(multigraph=False, attrs="My attribute")
+        graph.add_nodes_from(["a", "b"])
+        graph.add_edges_from([(0, 1, 1), (1, 1, 2)])
+        subgraph, node_map = graph.edge_subgraph_with_nodemap([(1, 1)], preserve_attrs=True)
+        self.assertEqual(["b"], subgraph.nodes())
+        self.assertEqual(dict(node_map), {0: 1})
+        self.assertEqual([(0, 0, 2)], subgraph.weighted_edge_list())
+        self.assertEqual("My attribute", subgraph.attrs)
+        self.assertFalse(subgraph.multigraph)
+
+    def test_edge_subgraph_with_nodemap_empty_list(self):
+        graph = rustworkx.generators.path_graph(3)
+        subgraph, node_map = graph.edge_subgraph_with_nodemap([])
+        self.assertEqual(0, len(subgraph))
+        self.assertEqual(dict(node_map), {})
+
+    def test_edge_subgraph_by_filter(self):
+        graph = rustworkx.PyGraph(attrs="attrs")
+        graph.add_nodes_from(["a", "b", "c", "d", "e"])
+        graph.add_edges_from([(0, 1, 1), (1, 2, 2), (3, 2, 3), (3, 4, 4), (3, 3, 5), (0, 1, 6)])
+        graph.remove_node(1)
+        subgraph = graph.edge_subgraph_by_filter(lambda weight: weight % 2 == 1)
+        self.assertEqual(["c", "d"], subgraph.nodes())
+        self.assertEqual([(1, 0, 3), (1, 1, 5)], subgraph.weighted_edge_list())
+        self.assertIsNone(subgraph.attrs)
+        self.assertIs(subgraph[0], graph[2])
+
+    def test_edge_subgraph_by_filter_preserve_attrs(self):
+        graph = rustworkx.PyGraph(multigraph=False, attrs="attrs")
+        graph.extend_from_weighted_edge_list([(0, 1, "a"), (1, 2, "b")])
+        subgraph = graph.edge_subgraph_by_filter(lambda weight: True, preserve_attrs=True)
+        self.assertEqual([(0, 1, "a"), (1, 2, "b")], subgraph.weighted_edge_list())
+        self.assertEqual("attrs", subgraph.attrs)
+        self.assertFalse(subgraph.multigraph)
+        empty = graph.edge_subgraph_by_filter(lambda weight: False)
+        self.assertEqual(0, len(empty))
+
+    def test_edge_subgraph_by_filter_error(self):
+        graph = rustworkx.generators.path_graph(3)
+        with self.assertRaises(ZeroDivisionError):
+            graph.edge_subgraph_by_filter(lambda weight: 1 / 0)
+
     def test_preserve_attrs(self):
         graph = rustworkx.PyGraph(attrs="My attribute")
         graph.add_node("a")
@@ -208,3 +339,120 @@ class TestSubgraph(unittest.TestCase):
         self.assertEqual([(0, 1, 1), (1, 2, 2)], subgraph.weighted_edge_list())
         self.assertEqual(["a", "b", "c"], subgraph.nodes())
         self.assertEqual(dict(node_map), {0: 0, 1: 1, 2: 2})
+
+    def test_subgraph_with_nodemap_empty_selection(self):
+        graph = rustworkx.PyGraph(multigraph=False, attrs="My attribute")
+        graph.add_nodes_from(["a", "b"])
+        graph.add_edge(0, 1, 1)
+        subgraph, node_map, edge_map = graph.subgraph_with_nodemap(
+            [], preserve_attrs=True, edge_map=True
+        )
+        self.assertEqual(0, len(subgraph))
+        self.assertEqual({}, dict(node_map))
+        self.assertEqual({}, edge_map)
+        self.assertEqual("My attribute", subgraph.attrs)
+        self.assertFalse(subgraph.multigraph)
+        self.assertIsNone(graph.subgraph_with_nodemap([])[0].attrs)
+
+    def test_subgraph_with_nodemap_full_selection(self):
+        graph = rustworkx.PyGraph(attrs="My attribute")
+        graph.add_nodes_from([{"a": 0}, "b", "c"])
+        graph.add_edges_from([(0, 1, 1), (1, 2, 2), (2, 0, 3)])
+        subgraph, node_map, edge_map = graph.subgraph_with_nodemap(
+            [2, 0, 1, 1, 42], preserve_attrs=True, edge_map=True
+        )
+        self.assertEqual([(0, 1, 1), (1, 2, 2), (2, 0, 3)], subgraph.weighted_edge_list())
+        self.assertEqual({0: 0, 1: 1, 2: 2}, dict(node_map))
+        self.assertEqual({0: 0, 1: 1, 2: 2}, edge_map)
+        self.assertEqual("My attribute", subgraph.attrs)
+        # The payloads are shared but the graphs are independent
+        self.assertIs(graph[0], subgraph[0])
+        subgraph.add_edge(0, 0, 4)
+        subgraph.remove_node(1)
+        self.assertEqual(3, len(graph))
+        self.assertEqual([(0, 1, 1), (1, 2, 2), (2, 0, 3)], graph.weighted_edge_list())
+
+    def test_subgraph_with_nodemap_full_selection_with_holes(self):
+        graph = rustworkx.PyGraph()
+        graph.add_nodes_from(["a", "b", "c", "d"])
+        graph.add_edges_from([(0, 1, 1), (1, 2, 2), (2, 3, 3), (3, 0, 4)])
+        graph.remove_node(1)
+        graph.remove_edge_from_index(2)
+        subgraph, node_map, edge_map = graph.subgraph_with_nodemap([0, 2, 3], edge_map=True)
+        self.assertEqual([0, 1, 2], subgraph.node_indices())
+        self.assertEqual(["a", "c", "d"], subgraph.nodes())
+        self.assertEqual([0], subgraph.edge_indices())
+        self.assertEqual([(2, 0, 4)], subgraph.weighted_edge_list())
+        self.assertEqual({0: 0, 1: 2, 2: 3}, dict(node_map))
+        self.assertEqual({3: 0}, edge_map)
+
+    def test_subgraph_with_nodemap_large_selection(self):
+        graph = rustworkx.generators.path_graph(100)
+        graph.remove_node(50)
+        selection = [node for node in range(120) if node % 10 != 0] + [1, 1]
+        subgraph, node_map = graph.subgraph_with_nodemap(selection)
+        expected_nodes = [node for node in graph.node_indices() if node % 10 != 0]
+        self.assertEqual(expected_nodes, list(node_map.values()))
+        self.assertEqual(len(expected_nodes), len(subgraph))
+        expected_edges = {
+            (source, target)
+            for source, target in graph.edge_list()
+            if source % 10 != 0 and target % 10 != 0
+        }
+        self.assertEqual(
+            expected_edges,
+            {(node_map[source], node_map[target]) for source, target in subgraph.edge_list()},
+        )
+
+    def test_subgraph_with_nodemap_edge_map(self):
+        graph = rustworkx.PyGraph()
+        graph.add_nodes_from(["a", "b", "c", "d"])
+        graph.add_edges_from([(0, 1, "x"), (1, 2, "y"), (2, 3, "z"), (1, 3, "w")])
+        graph.remove_edge_from_index(1)
+        subgraph, node_map, edge_map = graph.subgraph_with_nodemap([1, 2, 3], edge_map=True)
+        self.assertEqual({2: 0, 3: 1}, edge_map)
+        for edge, new_edge in edge_map.items():
+            self.assertEqual(
+                graph.get_edge_data_by_index(edge), subgraph.get_edge_data_by_index(new_edge)
+            )
+            source, target = subgraph.get_edge_endpoints_by_index(new_edge)
+            self.assertEqual(
+                graph.get_edge_endpoints_by_index(edge), (node_map[source], node_map[target])
+            )
+
+    def test_subgraph_with_nodemap_edge_map_default(self):
+        graph = rustworkx.generators.path_graph(3)
+        self.assertEqual(2, len(graph.subgraph_with_nodemap([0, 1])))
+
+    def test_subgraph_from_mask(self):
+        graph = rustworkx.PyGraph(attrs="attrs")
+        graph.add_nodes_from(["a", "b", "c", "d", "e"])
+        graph.add_edges_from([(0, 1, 1), (1, 2, 2), (2, 3, 3), (1, 3, 4)])
+        graph.remove_node(4)
+        mask = np.array([False, True, False, True])
+        subgraph, node_map = graph.subgraph_from_mask(mask, preserve_attrs=True)
+        self.assertEqual(["b", "d"], subgraph.nodes())
+        self.assertEqual([(0, 1, 4)], subgraph.weighted_edge_list())
+        self.assertEqual({0: 1, 1: 3}, dict(node_map))
+        self.assertEqual("attrs", subgraph.attrs)
+
+    def test_subgraph_from_mask_matches_subgraph_with_nodemap(self):
+        graph = rustworkx.generators.grid_graph(4, 4)
+        mask = np.zeros(16, dtype=bool)
+        mask[[0, 1, 4, 5, 10]] = True
+        subgraph, node_map = graph.subgraph_from_mask(mask)
+        expected, expected_map = graph.subgraph_with_nodemap([0, 1, 4, 5, 10])
+        self.assertEqual(expected.edge_list(), subgraph.edge_list())
+        self.assertEqual(dict(expected_map), dict(node_map))
+
+    def test_subgraph_from_mask_removed_node(self):
+        graph = rustworkx.generators.path_graph(3)
+        graph.remove_node(1)
+        subgraph, node_map = graph.subgraph_from_mask(np.ones(3, dtype=bool))
+        self.assertEqual([0, 2], subgraph.nodes())
+        self.assertEqual({0: 0, 1: 2}, dict(node_map))
+
+    def test_subgraph_from_mask_wrong_length(self):
+        graph = rustworkx.generators.path_graph(3)
+        with self.assertRaises(ValueError):
+            graph.subgraph_from_mask(np.ones(2, dtype=bool))
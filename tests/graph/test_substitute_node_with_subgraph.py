@@ -139,3 +139,44 @@ class TestSubstituteNodeSubGraph(unittest.TestCase):
         in_graph = rustworkx.generators.grid_graph(5, 5)
         with self.assertRaises(IndexError):
             self.graph.substitute_node_with_subgraph(16, in_graph, lambda *args: None)
+
+    def test_strict_mapping(self):
+        graph = rustworkx.generators.star_graph(5)
+        in_graph = rustworkx.generators.star_graph(3)
+
+        def map_function(_source, target, _weight):
+            if target > 2:
+                return 2
+            return 1
+
+        res = graph.substitute_node_with_subgraph(0, in_graph, map_function, strict=True)
+        self.assertEqual({0: 5, 1: 6, 2: 7}, res)
+        expected = [(5, 6), (5, 7), (7, 4), (7, 3), (6, 2), (6, 1)]
+        self.assertEqual(sorted(expected), sorted(graph.edge_list()))
+
+    def test_strict_reports_all_failures(self):
+        graph = rustworkx.generators.star_graph(4)
+        in_graph = rustworkx.generators.path_graph(2)
+        edges = graph.weighted_edge_list()
+
+        def map_function(_source, target, _weight):
+            return {1: None, 2: 42}.get(target, 0)
+
+        with self.assertRaises(ValueError) as cm:
+            graph.substitute_node_with_subgraph(0, in_graph, map_function, strict=True)
+        message = str(cm.exception)
+        self.assertIn("(0, 1) -> None", message)
+        self.assertIn("(0, 2) -> 42", message)
+        self.assertNotIn("(0, 3)", message)
+        self.assertEqual(edges, graph.weighted_edge_list())
+        self.assertEqual([0, 1, 2, 3], graph.node_indices())
+
+    def test_strict_filtered_node(self):
+        graph = rustworkx.generators.path_graph(2)
+        in_graph = rustworkx.PyGraph()
+        in_graph.add_nodes_from(["a", "b"])
+        with self.assertRaises(ValueError):
+            graph.substitute_node_with_subgraph(
+                0, in_graph, lambda *args: 1, node_filter=lambda node: node == "a", strict=True
+            )
+        self.assertEqual([(0, 1)], graph.edge_list())
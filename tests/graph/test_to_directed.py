@@ -77,3 +77,32 @@ class TestToDirected(unittest.TestCase):
         edge_weight["b"] = 2
         self.assertEqual(digraph.get_edge_data(0, 1), {"a": 1, "b": 2})
         self.assertEqual(graph.get_edge_data(0, 1), {"a": 1, "b": 2})
+
+    def test_single_direction(self):
+        graph = rustworkx.PyGraph()
+        graph.add_nodes_from(["a", "b", "c"])
+        graph.add_edges_from([(1, 0, "ba"), (1, 2, "bc"), (2, 2, "cc")])
+        digraph = graph.to_directed(bidirectional=False)
+        self.assertEqual(
+            digraph.weighted_edge_list(), [(1, 0, "ba"), (1, 2, "bc"), (2, 2, "cc")]
+        )
+        self.assertEqual(graph.multigraph, digraph.multigraph)
+
+    def test_edge_map(self):
+        graph = rustworkx.generators.path_graph(4)
+        graph.remove_edge_from_index(0)
+        digraph, edge_map = graph.to_directed(edge_map=True)
+        self.assertEqual(digraph.edge_list(), [(1, 2), (2, 1), (2, 3), (3, 2)])
+        self.assertEqual(edge_map, {0: 1, 1: 1, 2: 2, 3: 2})
+
+    def test_edge_map_single_direction(self):
+        graph = rustworkx.generators.path_graph(4)
+        graph.remove_edge_from_index(1)
+        digraph, edge_map = graph.to_directed(bidirectional=False, edge_map=True)
+        self.assertEqual(digraph.edge_list(), [(0, 1), (2, 3)])
+        self.assertEqual(edge_map, {0: 0, 1: 2})
+        for new_edge, old_edge in edge_map.items():
+            self.assertEqual(
+                digraph.get_edge_endpoints_by_index(new_edge),
+                graph.get_edge_endpoints_by_index(old_edge),
+            )
@@ -0,0 +1,103 @@
+# Licensed under the Apache License, Version 2.0 (the "License"); you may
+# not use this file except in compliance with the License. You may obtain
+# a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+# WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+# License for the specific language governing permissions and limitations
+# under the License.
+
+import unittest
+
+import rustworkx
+
+
+class TestToSimpleGraph(unittest.TestCase):
+    def setUp(self):
+        self.graph = rustworkx.PyGraph()
+        self.graph.add_nodes_from(["a", "b", "c", "d"])
+        self.graph.add_edges_from(
+            [(0, 1, 1), (1, 0, 2), (1, 2, 3), (2, 2, 4), (2, 2, 5), (0, 1, 6)]
+        )
+
+    def test_collapse_parallel_edges(self):
+        simple = self.graph.to_simple_graph()
+        self.assertFalse(simple.multigraph)
+        self.assertEqual(simple.node_indices(), [0, 1, 2, 3])
+        self.assertEqual(simple.nodes(), ["a", "b", "c", "d"])
+        self.assertEqual(simple.weighted_edge_list(), [(0, 1, 1), (1, 2, 3)])
+        self.assertTrue(self.graph.multigraph)
+        self.assertEqual(self.graph.num_edges(), 6)
+
+    def test_weight_combo_fn(self):
+        simple = self.graph.to_simple_graph(weight_combo_fn=lambda a, b: a + b)
+        self.assertEqual(simple.weighted_edge_list(), [(0, 1, 9), (1, 2, 3)])
+
+    def test_keep_self_loops(self):
+        simple = self.graph.to_simple_graph(lambda a, b: a + b, keep_self_loops=True)
+        self.assertEqual(simple.weighted_edge_list(), [(0, 1, 9), (1, 2, 3), (2, 2, 9)])
+
+    def test_preserves_node_indices_and_payload_references(self):
+        payload = {"a": 1}
+        graph = rustworkx.PyGraph(attrs="attrs")
+        graph.add_nodes_from([payload, None, None])
+        graph.add_edges_from_no_data([(0, 2), (2, 0)])
+        graph.remove_node(1)
+        simple = graph.to_simple_graph()
+        self.assertEqual(simple.node_indices(), [0, 2])
+        self.assertIs(simple[0], payload)
+        self.assertEqual(simple.attrs, "attrs")
+        self.assertEqual(simple.edge_list(), [(0, 2)])
+        simple.add_edge(0, 2, "new")
+        self.assertEqual(simple.num_edges(), 1)
+
+    def test_empty(self):
+        simple = rustworkx.PyGraph().to_simple_graph()
+        self.assertEqual(len(simple), 0)
+        self.assertFalse(simple.multigraph)
+
+    def test_combo_fn_error(self):
+        def combo(_a, _b):
+            raise ValueError("combo")
+
+        with self.assertRaises(ValueError):
+            self.graph.to_simple_graph(weight_combo_fn=combo)
+
+
+class TestMergeParallelEdges(unittest.TestCase):
+    def setUp(self):
+        self.graph = rustworkx.PyGraph()
+        self.graph.add_nodes_from(["a", "b", "c", "d"])
+        self.graph.add_edges_from(
+            [(0, 1, 1), (1, 0, 2), (1, 2, 3), (2, 2, 4), (2, 2, 5), (0, 1, 6)]
+        )
+
+    def test_merge_parallel_edges(self):
+        self.assertIsNone(self.graph.merge_parallel_edges())
+        self.assertTrue(self.graph.multigraph)
+        self.assertEqual(self.graph.edge_indices(), [0, 2, 3, 4])
+        self.assertEqual(
+            self.graph.weighted_edge_list(), [(0, 1, 1), (1, 2, 3), (2, 2, 4), (2, 2, 5)]
+        )
+
+    def test_weight_combo_fn(self):
+        self.graph.merge_parallel_edges(weight_combo_fn=lambda a, b: [a, b])
+        self.assertEqual(self.graph.get_edge_data_by_index(0), [[1, 2], 6])
+        self.assertEqual(self.graph.edge_indices(), [0, 2, 3, 4])
+
+    def test_no_parallel_edges(self):
+        graph = rustworkx.generators.cycle_graph(4)
+        graph.merge_parallel_edges(lambda a, b: 1 / 0)
+        self.assertEqual(graph.edge_list(), [(0, 1), (1, 2), (2, 3), (3, 0)])
+
+    def test_combo_fn_error(self):
+        def combo(_a, _b):
+            raise ValueError("combo")
+
+        with self.assertRaises(ValueError):
+            self.graph.merge_parallel_edges(weight_combo_fn=combo)
+        self.assertEqual(self.graph.num_edges(), 6)
+        self.assertEqual(self.graph.get_edge_data_by_index(0), 1)
@@ -0,0 +1,92 @@
+# Licensed under the Apache License, Version 2.0 (the "License"); you may
+# not use this file except in compliance with the License. You may obtain
+# a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+# WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+# License for the specific language governing permissions and limitations
+# under the License.
+
+import unittest
+
+import rustworkx
+
+
+class TestTrees(unittest.TestCase):
+    def test_empty_graph(self):
+        graph = rustworkx.PyGraph()
+        self.assertTrue(graph.is_forest())
+        self.assertFalse(graph.is_tree())
+
+    def test_single_node(self):
+        graph = rustworkx.PyGraph()
+        graph.add_node(None)
+        self.assertTrue(graph.is_forest())
+        self.assertTrue(graph.is_tree())
+
+    def test_path_graph(self):
+        graph = rustworkx.generators.path_graph(5)
+        self.assertTrue(graph.is_forest())
+        self.assertTrue(graph.is_tree())
+
+    def test_star_graph(self):
+        graph = rustworkx.generators.star_graph(6)
+        self.assertTrue(graph.is_forest())
+        self.assertTrue(graph.is_tree())
+
+    def test_cycle_graph(self):
+        graph = rustworkx.generators.cycle_graph(4)
+        self.assertFalse(graph.is_forest())
+        self.assertFalse(graph.is_tree())
+
+    def test_disconnected_forest(self):
+        graph = rustworkx.generators.path_graph(3)
+        graph.add_nodes_from([None, None])
+        graph.add_edge(3, 4, None)
+        self.assertTrue(graph.is_forest())
+        self.assertFalse(graph.is_tree())
+
+    def test_isolated_nodes(self):
+        graph = rustworkx.PyGraph()
+        graph.add_nodes_from([None, None])
+        self.assertTrue(graph.is_forest())
+        self.assertFalse(graph.is_tree())
+
+    def test_cycle_and_tree_components(self):
+        # Same edge count as a forest on 7 nodes with 2 components, but one
+        # component is a cycle and the other is disconnected
+        graph = rustworkx.generators.cycle_graph(3)
+        graph.add_nodes_from([None] * 4)
+        graph.add_edges_from_no_data([(3, 4), (4, 5)])
+        self.assertEqual(graph.number_connected_components(), 3)
+        self.assertFalse(graph.is_forest())
+        self.assertFalse(graph.is_tree())
+
+    def test_self_loop(self):
+        graph = rustworkx.generators.path_graph(3)
+        graph.add_edge(1, 1, None)
+        self.assertFalse(graph.is_forest())
+        self.assertFalse(graph.is_tree())
+        single = rustworkx.PyGraph()
+        single.add_node(None)
+        single.add_edge(0, 0, None)
+        self.assertFalse(single.is_forest())
+        self.assertFalse(single.is_tree())
+
+    def test_parallel_edges(self):
+        graph = rustworkx.generators.path_graph(3)
+        graph.add_edge(0, 1, None)
+        self.assertFalse(graph.is_forest())
+        self.assertFalse(graph.is_tree())
+
+    def test_removed_nodes(self):
+        graph = rustworkx.generators.cycle_graph(5)
+        graph.remove_node(2)
+        self.assertTrue(graph.is_forest())
+        self.assertTrue(graph.is_tree())
+        graph.remove_node(4)
+        self.assertTrue(graph.is_forest())
+        self.assertFalse(graph.is_tree())
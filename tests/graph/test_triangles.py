@@ -0,0 +1,110 @@
+# Licensed under the Apache License, Version 2.0 (the "License"); you may
+# not use this file except in compliance with the License. You may obtain
+# a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+# WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+# License for the specific language governing permissions and limitations
+# under the License.
+
+
+import itertools
+import unittest
+
+import rustworkx
+
+
+class TestTriangles(unittest.TestCase):
+    def test_empty(self):
+        graph = rustworkx.PyGraph()
+        self.assertEqual(0, graph.triangle_count())
+        self.assertEqual({}, graph.triangles_per_node())
+
+    def test_complete_graph(self):
+        graph = rustworkx.generators.complete_graph(5)
+        self.assertEqual(10, graph.triangle_count())
+        self.assertEqual({node: 6 for node in range(5)}, graph.triangles_per_node())
+
+    def test_no_triangles(self):
+        graph = rustworkx.generators.cycle_graph(4)
+        self.assertEqual(0, graph.triangle_count())
+        self.assertEqual({0: 0, 1: 0, 2: 0, 3: 0}, graph.triangles_per_node())
+
+    def test_parallel_edges_and_self_loops(self):
+        graph = rustworkx.generators.cycle_graph(3)
+        graph.add_edges_from_no_data([(0, 1), (1, 0), (2, 2), (0, 0)])
+        graph.add_node(None)
+        graph.add_edges_from_no_data([(3, 0), (3, 3)])
+        self.assertEqual(1, graph.triangle_count())
+        self.assertEqual({0: 1, 1: 1, 2: 1, 3: 0}, graph.triangles_per_node())
+
+    def test_removed_nodes(self):
+        graph = rustworkx.generators.complete_graph(4)
+        graph.remove_node(1)
+        self.assertEqual(1, graph.triangle_count())
+        self.assertEqual({0: 1, 2: 1, 3: 1}, graph.triangles_per_node())
+
+    def test_random_graph(self):
+        graph = rustworkx.undirected_gnp_random_graph(40, 0.3, seed=1234)
+        triangles = [
+            nodes
+            for nodes in itertools.combinations(graph.node_indices(), 3)
+            if all(graph.has_edge(a, b) for a, b in itertools.combinations(nodes, 2))
+        ]
+        self.assertEqual(len(triangles), graph.triangle_count())
+        per_node = graph.triangles_per_node()
+        for node in graph.node_indices():
+            self.assertEqual(sum(node in nodes for nodes in triangles), per_node[node])
+
+
+class TestClustering(unittest.TestCase):
+    def test_clustering_coefficient(self):
+        graph = rustworkx.generators.complete_graph(4)
+        graph.remove_edge(0, 1)
+        graph.add_node(None)
+        graph.add_edge(3, 4, None)
+        self.assertEqual(
+            {0: 1.0, 1: 1.0, 2: 2 / 3, 3: 1 / 3, 4: 0.0}, graph.clustering_coefficient()
+        )
+        self.assertEqual(2 / 3, graph.clustering_coefficient(2))
+        self.assertEqual(0.0, graph.clustering_coefficient(4))
+
+    def test_clustering_coefficient_isolated_node(self):
+        graph = rustworkx.PyGraph()
+        graph.add_node(None)
+        self.assertEqual(0.0, graph.clustering_coefficient(0))
+        self.assertEqual({0: 0.0}, graph.clustering_coefficient())
+
+    def test_clustering_coefficient_parallel_edges_and_self_loops(self):
+        graph = rustworkx.generators.cycle_graph(3)
+        graph.add_edges_from_no_data([(0, 1), (0, 0), (1, 1)])
+        graph.add_node(None)
+        graph.add_edges_from_no_data([(2, 3), (2, 3)])
+        expected = {0: 1.0, 1: 1.0, 2: 1 / 3, 3: 0.0}
+        self.assertEqual(expected, graph.clustering_coefficient())
+        for node, value in expected.items():
+            self.assertEqual(value, graph.clustering_coefficient(node))
+
+    def test_clustering_coefficient_invalid_node(self):
+        graph = rustworkx.generators.path_graph(3)
+        with self.assertRaises(IndexError):
+            graph.clustering_coefficient(5)
+
+    def test_clustering_coefficient_random_graph(self):
+        graph = rustworkx.undirected_gnp_random_graph(40, 0.3, seed=4321)
+        graph.remove_nodes_from([3, 17])
+        clustering = graph.clustering_coefficient()
+        for node in graph.node_indices():
+            self.assertAlmostEqual(clustering[node], graph.clustering_coefficient(node))
+
+    def test_average_clustering(self):
+        graph = rustworkx.generators.complete_graph(3)
+        graph.add_node(None)
+        self.assertEqual(0.75, graph.average_clustering())
+
+    def test_average_clustering_empty(self):
+        graph = rustworkx.PyGraph()
+        self.assertEqual(0.0, graph.average_clustering())